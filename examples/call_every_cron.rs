@@ -0,0 +1,8 @@
+use zila::{call_every_cron, Cron};
+
+fn main() {
+    let cron = Cron::parse("0 30 9 * * MON-FRI").unwrap();
+    call_every_cron(cron, || {
+        println!("This will be printed every weekday at 09:30.");
+    });
+}
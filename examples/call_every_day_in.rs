@@ -0,0 +1,8 @@
+use zila::call_every_day_in;
+use zila::chrono_tz::America::New_York;
+
+fn main() {
+    call_every_day_in(New_York, || {
+        println!("This will be printed every day at midnight in New York.");
+    });
+}
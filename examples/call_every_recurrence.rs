@@ -0,0 +1,12 @@
+use zila::{call_every_recurrence, Recurrence};
+
+fn main() {
+    let recurrence = Recurrence::parse(
+        "DTSTART:20230509T090000\n\
+         RRULE:FREQ=MONTHLY;BYDAY=2TU",
+    )
+    .unwrap();
+    call_every_recurrence(recurrence, || {
+        println!("This will be printed on the second Tuesday of every month at 09:00.");
+    });
+}
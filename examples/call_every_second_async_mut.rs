@@ -1,5 +1,3 @@
-#![feature(async_closure)]
-
 use std::{cell::Cell, rc::Rc};
 use zila::call_every_second_async_mut;
 
@@ -8,10 +6,10 @@ async fn main() {
     let num = Rc::new(Cell::new(0));
     call_every_second_async_mut(move || {
         let num = num.clone();
-        async move || {
+        async move {
             num.set(num.get() + 1);
-            println!("This is printed {} times.", { num.get() });
+            println!("This is printed {} times.", num.get());
         }
-    }())
+    })
     .await;
 }
@@ -0,0 +1,26 @@
+use std::time::Duration;
+use zila::Scheduler;
+
+fn main() {
+    let scheduler = Scheduler::new();
+
+    scheduler.call_every_minute(|| {
+        println!("This will be printed every minute.");
+    });
+    let interval = scheduler.set_interval(
+        || {
+            println!("This will be printed every two second, for ten seconds.");
+        },
+        Duration::from_secs(2),
+    );
+    scheduler.set_timeout(
+        || {
+            println!("All of these jobs share a single thread.");
+        },
+        Duration::from_secs(1),
+    );
+
+    std::thread::sleep(Duration::from_secs(10));
+    scheduler.remove(interval);
+    std::thread::sleep(Duration::from_secs(60));
+}
@@ -1,5 +1,3 @@
-#![feature(async_closure)]
-
 use std::{cell::Cell, rc::Rc, time::Duration};
 use zila::set_interval_async_mut;
 
@@ -9,11 +7,12 @@ async fn main() {
     set_interval_async_mut(
         move || {
             let num = num.clone();
-            async move || {
+            async move {
                 num.set(num.get() + 1);
                 println!("This message is printed {} times", num.get());
             }
-        }(),
+        },
         Duration::from_secs(2),
-    ).await;
+    )
+    .await;
 }
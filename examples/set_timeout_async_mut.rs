@@ -1,5 +1,3 @@
-#![feature(async_closure)]
-
 use std::{cell::Cell, rc::Rc, time::Duration};
 use zila::set_timeout_async_mut;
 
@@ -9,11 +7,12 @@ async fn main() {
     set_timeout_async_mut(
         move || {
             let is_called = is_called.clone();
-            async move || {
+            async move {
                 is_called.set(true);
                 println!("This function is called: {}", is_called.get());
             }
-        }(),
+        },
         Duration::from_secs(2),
-    ).await;
+    )
+    .await;
 }
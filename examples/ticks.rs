@@ -0,0 +1,18 @@
+use futures_util::StreamExt;
+use std::time::Duration;
+use zila::ticks;
+
+#[tokio::main]
+async fn main() {
+    let mut fast = ticks::interval(Duration::from_secs(1));
+    let mut slow = ticks::interval(Duration::from_secs(3)).take(3);
+    loop {
+        tokio::select! {
+            Some(tick) = fast.next() => println!("fast tick {}", tick.sequence()),
+            tick = slow.next() => match tick {
+                Some(tick) => println!("slow tick {}", tick.sequence()),
+                None => break,
+            },
+        }
+    }
+}
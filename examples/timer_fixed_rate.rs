@@ -0,0 +1,13 @@
+use std::time::{Duration, Instant};
+use zila::Timer;
+
+fn main() {
+    let start = Instant::now();
+    Timer::interval(Duration::from_secs(1))
+        .fixed_rate()
+        .run(move |_| {
+            println!("{:?} since start", start.elapsed());
+            // the time spent here doesn't push the next call back
+            std::thread::sleep(Duration::from_millis(300));
+        });
+}
@@ -0,0 +1,110 @@
+//! Turns schedules expressed in local wall time into instants.
+//!
+//! Calendar schedules, like cron expressions or the day boundary, are easiest
+//! to describe with a function that finds the next matching local date and time
+//! (a `NaiveDateTime`). Mapping those back to instants is where daylight saving
+//! time bites: some local times never happen (the clocks spring forward) and
+//! others happen twice (the clocks fall back). [`next_after`] walks the time zone
+//! one UTC offset at a time so both cases are handled the same way everywhere.
+
+use chrono::{DateTime, FixedOffset, NaiveDateTime, Offset, TimeZone, Timelike};
+
+/// What to do with local times that happen twice because the clocks fell back,
+/// or never because they sprang forward.
+#[allow(dead_code)] // every variant is gated behind its own feature
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Fold {
+    /// Fire again when the local time repeats and not at all when it is
+    /// skipped, used by schedules that follow the passing of time, like
+    /// "every 15 minutes".
+    Repeat,
+    /// Fire only the first time, and at the transition when the local time is
+    /// skipped, used by schedules that name a time of day, like "at 01:30".
+    Skip,
+}
+
+/// Returns the first instant strictly after `time` whose local time is
+/// returned by `next_local`.
+///
+/// `next_local(from)` must return the first matching local time that is equal
+/// to or later than `from`, or `None` if there is none. Local times that are
+/// skipped by a daylight saving time transition resolve to the instant of the
+/// transition, which is the first valid instant after them, with
+/// [`Fold::Skip`] and are dropped with [`Fold::Repeat`].
+pub(crate) fn next_after<Tz, F>(
+    time: &DateTime<Tz>,
+    fold: Fold,
+    mut next_local: F,
+) -> Option<DateTime<Tz>>
+where
+    Tz: TimeZone,
+    F: FnMut(NaiveDateTime) -> Option<NaiveDateTime>,
+{
+    let tz = time.timezone();
+    let second = chrono::Duration::seconds(1);
+    // the instant (in UTC) from which on the search continues, inclusive
+    let mut from = time.naive_utc().with_nanosecond(0)? + second;
+    // local times before this one were already passed, see `Fold::Skip`
+    let mut passed: Option<NaiveDateTime> = None;
+
+    loop {
+        let offset = offset_at(&tz, from);
+        let mut local_from = from + offset;
+        if let Some(passed) = passed {
+            local_from = local_from.max(passed);
+        }
+        let local = next_local(local_from)?;
+        let instant = local - offset;
+
+        let Some(transition) = next_transition(&tz, from, instant, offset) else {
+            return Some(tz.from_utc_datetime(&instant));
+        };
+        let new_offset = offset_at(&tz, transition);
+        if new_offset.local_minus_utc() > offset.local_minus_utc() {
+            // the clocks sprung forward over `local`
+            if local < transition + new_offset && fold == Fold::Skip {
+                return Some(tz.from_utc_datetime(&transition));
+            }
+        } else if fold == Fold::Skip {
+            // the clocks fell back, don't revisit the local times we passed
+            passed = Some(transition + offset);
+        }
+        from = transition;
+    }
+}
+
+fn offset_at<Tz: TimeZone>(tz: &Tz, utc: NaiveDateTime) -> FixedOffset {
+    tz.offset_from_utc_datetime(&utc).fix()
+}
+
+/// Returns the first instant in `(from, until]` at which the UTC offset of
+/// `tz` differs from `offset`, assuming there is at most one transition a day.
+fn next_transition<Tz: TimeZone>(
+    tz: &Tz,
+    from: NaiveDateTime,
+    until: NaiveDateTime,
+    offset: FixedOffset,
+) -> Option<NaiveDateTime> {
+    let day = chrono::Duration::days(1);
+    let mut low = from;
+    while low < until {
+        let high = (low + day).min(until);
+        if offset_at(tz, high) != offset {
+            // the offset changes in (low, high], find the exact second
+            let mut span = (high - low).num_seconds();
+            while span > 1 {
+                let half = span / 2;
+                let middle = low + chrono::Duration::seconds(half);
+                if offset_at(tz, middle) == offset {
+                    low = middle;
+                    span -= half;
+                } else {
+                    span = half;
+                }
+            }
+            return Some(low + chrono::Duration::seconds(1));
+        }
+        low = high;
+    }
+    None
+}
@@ -0,0 +1,237 @@
+//! The clocks timers read and sleep on.
+
+use chrono::{DateTime, Utc};
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::task::{Context, Poll, Waker};
+use std::thread::{self, Thread};
+use std::time::{Duration, Instant};
+
+/// The future returned by [`Clock::sleep_until_async`].
+pub type Sleep = Pin<Box<dyn Future<Output = ()> + Send + 'static>>;
+
+/// A source of time for a [`Timer`](crate::Timer).
+///
+/// A clock has two faces, the wall clock that calendar timers follow and a
+/// monotonic clock that measures intervals. [`SystemClock`] reads the clocks
+/// of the OS, [`ManualClock`] only moves when it is told to, which makes it
+/// possible to test a daily job without waiting for a day.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use std::sync::Arc;
+/// use std::time::Duration;
+/// use zila::{ManualClock, MissedTickBehavior, Timer};
+///
+/// let clock = ManualClock::new(Utc.with_ymd_and_hms(2023, 5, 8, 12, 0, 0).unwrap());
+/// let calls = Arc::new(AtomicUsize::new(0));
+/// let handle = Timer::every_day_in(Utc)
+///     .clock(clock.clone())
+///     .missed_tick_behavior(MissedTickBehavior::Burst)
+///     .spawn({
+///         let calls = calls.clone();
+///         move |_| {
+///             calls.fetch_add(1, Ordering::SeqCst);
+///         }
+///     });
+///
+/// clock.advance(Duration::from_secs(7 * 24 * 60 * 60));
+/// while calls.load(Ordering::SeqCst) < 7 {
+///     std::thread::yield_now();
+/// }
+/// handle.cancel();
+/// assert!(handle.join().unwrap_err().is_cancelled());
+/// assert_eq!(calls.load(Ordering::SeqCst), 7);
+/// ```
+pub trait Clock: Send + Sync + 'static {
+    /// Returns the current wall time.
+    fn now(&self) -> DateTime<Utc>;
+
+    /// Returns the current instant of the monotonic clock.
+    fn instant(&self) -> Instant;
+
+    /// Blocks the current thread until [`instant`](Clock::instant) reaches
+    /// `deadline`.
+    ///
+    /// The sleep may end early, timers check the time again afterwards. It
+    /// should end when the thread is [unparked](std::thread::Thread::unpark),
+    /// that is how a [`Canceller`](crate::Canceller) or a cancelled
+    /// [`TimerHandle`](crate::TimerHandle) wakes its thread.
+    fn sleep_until(&self, deadline: Instant);
+
+    /// Returns a future that completes once [`instant`](Clock::instant)
+    /// reaches `deadline`.
+    ///
+    /// Like [`sleep_until`](Clock::sleep_until), the future may complete
+    /// early.
+    fn sleep_until_async(&self, deadline: Instant) -> Sleep;
+}
+
+/// The clocks of the operating system.
+///
+/// The monotonic clock is read through [`tokio::time::Instant`], so timers
+/// spawned on a tokio runtime whose time is paused follow the runtime clock.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    fn instant(&self) -> Instant {
+        tokio::time::Instant::now().into_std()
+    }
+
+    fn sleep_until(&self, deadline: Instant) {
+        if let Some(timeout) = deadline.checked_duration_since(self.instant()) {
+            thread::park_timeout(timeout);
+        }
+    }
+
+    fn sleep_until_async(&self, deadline: Instant) -> Sleep {
+        Box::pin(tokio::time::sleep_until(deadline.into()))
+    }
+}
+
+/// A clock that only moves when it is told to, for tests.
+///
+/// Clones share the same time. [`advance`](ManualClock::advance) moves both
+/// the wall clock and the monotonic clock and wakes the timers that sleep on
+/// the clock, [`set`](ManualClock::set) steps only the wall clock, like NTP or
+/// an admin would.
+///
+/// Advancing the clock doesn't wait for the timers to run their callbacks,
+/// tests have to wait for the effects they expect. Timers that fall behind
+/// handle the ticks they missed according to their
+/// [`MissedTickBehavior`](crate::MissedTickBehavior).
+///
+/// See [`Clock`] for an example.
+#[derive(Clone)]
+pub struct ManualClock {
+    inner: Arc<Mutex<State>>,
+}
+
+struct State {
+    time: DateTime<Utc>,
+    instant: Instant,
+    /// Counts the moves of the clock.
+    moves: u64,
+    threads: Vec<Thread>,
+    wakers: Vec<Waker>,
+}
+
+impl ManualClock {
+    /// Creates a clock that reads `time` until it is moved.
+    pub fn new(time: DateTime<Utc>) -> ManualClock {
+        ManualClock {
+            inner: Arc::new(Mutex::new(State {
+                time,
+                instant: Instant::now(),
+                moves: 0,
+                threads: Vec::new(),
+                wakers: Vec::new(),
+            })),
+        }
+    }
+
+    /// Moves the wall clock and the monotonic clock forward by `duration`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the wall time goes out of the range of [`DateTime`].
+    pub fn advance(&self, duration: Duration) {
+        let mut state = self.lock();
+        state.instant += duration;
+        state.time = chrono::Duration::from_std(duration)
+            .ok()
+            .and_then(|duration| state.time.checked_add_signed(duration))
+            .expect("time out of range");
+        state.wake();
+    }
+
+    /// Steps the wall clock to `time`, the monotonic clock doesn't move.
+    pub fn set(&self, time: DateTime<Utc>) {
+        let mut state = self.lock();
+        state.time = time;
+        state.wake();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl State {
+    fn wake(&mut self) {
+        self.moves += 1;
+        for thread in self.threads.drain(..) {
+            thread.unpark();
+        }
+        for waker in self.wakers.drain(..) {
+            waker.wake();
+        }
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.lock().time
+    }
+
+    fn instant(&self) -> Instant {
+        self.lock().instant
+    }
+
+    fn sleep_until(&self, deadline: Instant) {
+        let mut state = self.lock();
+        if state.instant >= deadline {
+            return;
+        }
+        state.threads.push(thread::current());
+        drop(state);
+        thread::park();
+    }
+
+    fn sleep_until_async(&self, deadline: Instant) -> Sleep {
+        Box::pin(ManualSleep {
+            clock: self.clone(),
+            deadline,
+            moves: self.lock().moves,
+        })
+    }
+}
+
+impl fmt::Debug for ManualClock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ManualClock")
+            .field("now", &self.now())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Completes when the clock reaches the deadline, or early when the clock is
+/// moved, like a parked thread would.
+struct ManualSleep {
+    clock: ManualClock,
+    deadline: Instant,
+    moves: u64,
+}
+
+impl Future for ManualSleep {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut state = self.clock.lock();
+        if state.instant >= self.deadline || state.moves != self.moves {
+            return Poll::Ready(());
+        }
+        state.wakers.push(cx.waker().clone());
+        Poll::Pending
+    }
+}
@@ -0,0 +1,986 @@
+//! Cron expression schedules.
+
+use crate::calendar::{self, Fold};
+use crate::describe;
+use crate::{ErrorPolicy, Flow, Schedule, Timer, TimerHandle};
+use chrono::{DateTime, Datelike, Local, NaiveDate, NaiveDateTime, TimeZone, Timelike};
+use std::fmt;
+use std::future::Future;
+use std::str::FromStr;
+use tokio::time::Duration;
+
+/// A parsed cron expression.
+///
+/// Both the standard 5 field syntax and the 6 field syntax with a leading
+/// seconds field are supported:
+///
+/// ```text
+/// ┌───────────── second (0-59, optional)
+/// │ ┌─────────── minute (0-59)
+/// │ │ ┌───────── hour (0-23)
+/// │ │ │ ┌─────── day of month (1-31)
+/// │ │ │ │ ┌───── month (1-12 or JAN-DEC)
+/// │ │ │ │ │ ┌─── day of week (0-7 or SUN-SAT, 0 and 7 are Sunday)
+/// │ │ │ │ │ │
+/// * * * * * *
+/// ```
+///
+/// Every field accepts `*`, single values, ranges (`MON-FRI`), steps (`*/15`,
+/// `10-50/20`, `5/10`) and comma separated lists of those. `?` is accepted as
+/// an alias of `*`. As in Vixie cron, a date matches if either the day of month
+/// or the day of week matches when both of them are restricted. The macros
+/// `@yearly`, `@annually`, `@monthly`, `@weekly`, `@daily`, `@midnight` and
+/// `@hourly` are accepted too.
+///
+/// Cron expressions are evaluated in local time. Local times that are skipped
+/// when the clocks spring forward fire at the first instant after the jump.
+/// When the clocks fall back, expressions with a fixed hour fire only once,
+/// while expressions with `*` as hour keep firing through the repeated hour.
+///
+/// # Example
+///
+/// ```rust
+/// use zila::Cron;
+///
+/// let cron: Cron = "30 9 * * MON-FRI".parse().unwrap();
+/// assert_eq!(cron.to_string(), "30 9 * * MON-FRI");
+///
+/// let error = "30 9 * * MON-FRU".parse::<Cron>().unwrap_err();
+/// assert_eq!(error.position(), 13);
+/// ```
+///
+/// *This type requires the following crate features to be activated: `cron`*
+#[derive(Clone, PartialEq, Eq)]
+pub struct Cron {
+    source: String,
+    seconds: u64,
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    /// `false` if the field starts with `*` or `?`
+    hours_restricted: bool,
+    days_of_month_restricted: bool,
+    days_of_week_restricted: bool,
+}
+
+impl Cron {
+    /// Parses a cron expression, same as [`str::parse`].
+    pub fn parse(expression: &str) -> Result<Cron, CronError> {
+        expression.parse()
+    }
+
+    /// Returns the first instant strictly after `time` that matches the
+    /// expression, `None` if the expression never matches again.
+    ///
+    /// The expression is evaluated in the time zone of `time`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use zila::Cron;
+    ///
+    /// let cron = Cron::parse("0 30 9 * * MON-FRI").unwrap();
+    /// // a Saturday
+    /// let time = Utc.with_ymd_and_hms(2023, 5, 13, 12, 0, 0).unwrap();
+    /// let next = Utc.with_ymd_and_hms(2023, 5, 15, 9, 30, 0).unwrap();
+    /// assert_eq!(cron.next_after(&time), Some(next));
+    /// ```
+    pub fn next_after<Tz: TimeZone>(&self, time: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        let fold = if self.hours_restricted {
+            Fold::Skip
+        } else {
+            Fold::Repeat
+        };
+        calendar::next_after(time, fold, |from| self.next_local(from))
+    }
+
+    /// Returns the first local time equal to or after `from` that matches.
+    fn next_local(&self, from: NaiveDateTime) -> Option<NaiveDateTime> {
+        if !self.is_satisfiable() {
+            return None;
+        }
+        // a satisfiable expression matches at least once every 28 years
+        let limit = from.year() + 28;
+        let mut time = from.with_nanosecond(0)?;
+        loop {
+            let date = time.date();
+            if date.year() > limit {
+                return None;
+            }
+            if !contains(self.months, date.month()) {
+                time = first_of_next_month(date)?.and_hms_opt(0, 0, 0)?;
+            } else if !self.matches_day(date) {
+                time = date.succ_opt()?.and_hms_opt(0, 0, 0)?;
+            } else if !contains(self.hours, time.hour()) {
+                time = date.and_hms_opt(time.hour(), 0, 0)? + chrono::Duration::hours(1);
+            } else if !contains(self.minutes, time.minute()) {
+                time =
+                    date.and_hms_opt(time.hour(), time.minute(), 0)? + chrono::Duration::minutes(1);
+            } else if !contains(self.seconds, time.second()) {
+                time += chrono::Duration::seconds(1);
+            } else {
+                return Some(time);
+            }
+        }
+    }
+
+    fn matches_day(&self, date: NaiveDate) -> bool {
+        let day_of_month = contains(self.days_of_month, date.day());
+        let day_of_week = contains(self.days_of_week, date.weekday().num_days_from_sunday());
+        match (self.days_of_month_restricted, self.days_of_week_restricted) {
+            (true, true) => day_of_month || day_of_week,
+            (true, false) => day_of_month,
+            (false, true) => day_of_week,
+            (false, false) => true,
+        }
+    }
+
+    /// Returns `false` for expressions like `0 0 30 2 *` that name days that
+    /// don't exist in any of the selected months.
+    fn is_satisfiable(&self) -> bool {
+        if self.days_of_week_restricted || !self.days_of_month_restricted {
+            return true;
+        }
+        const LONGEST: [u32; 12] = [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+        (1..=12).any(|month| {
+            contains(self.months, month)
+                && (1..=LONGEST[month as usize - 1]).any(|day| contains(self.days_of_month, day))
+        })
+    }
+}
+
+fn contains(bits: u64, value: u32) -> bool {
+    bits & (1 << value) != 0
+}
+
+fn first_of_next_month(date: NaiveDate) -> Option<NaiveDate> {
+    match date.month() {
+        12 => NaiveDate::from_ymd_opt(date.year() + 1, 1, 1),
+        month => NaiveDate::from_ymd_opt(date.year(), month + 1, 1),
+    }
+}
+
+impl Schedule for Cron {
+    fn next_after<Tz: TimeZone>(&self, time: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        Cron::next_after(self, time)
+    }
+
+    fn describe(&self) -> String {
+        // bit 0 is Sunday, the descriptions start the week on Monday
+        let weekdays = (self.days_of_week >> 1 | (self.days_of_week & 1) << 6) as u8;
+        describe::Fields {
+            seconds: self.seconds,
+            minutes: self.minutes,
+            hours: self.hours,
+            days: self.days_of_month_restricted.then_some(self.days_of_month),
+            days_from_end: false,
+            weekdays: self.days_of_week_restricted.then_some(weekdays),
+            either: true,
+            months: self.months,
+            years: Vec::new(),
+        }
+        .describe()
+    }
+}
+
+impl FromStr for Cron {
+    type Err = CronError;
+
+    fn from_str(source: &str) -> Result<Cron, CronError> {
+        let trimmed = source.trim();
+        let expression = match trimmed {
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            "@monthly" => "0 0 1 * *",
+            "@weekly" => "0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@hourly" => "0 * * * *",
+            _ if trimmed.starts_with('@') => {
+                let position = source.len() - source.trim_start().len();
+                return Err(CronError::new(position, CronErrorKind::UnknownMacro));
+            }
+            _ => source,
+        };
+        let fields = split_fields(expression);
+        let fields = match fields.len() {
+            6 => fields,
+            5 => [vec![(0, "0")], fields].concat(),
+            count => {
+                return Err(CronError::new(0, CronErrorKind::FieldCount(count)));
+            }
+        };
+
+        let mut values = [0; 6];
+        let mut restricted = [false; 6];
+        for (index, &(position, field)) in fields.iter().enumerate() {
+            values[index] = parse_field(field, position, &FIELDS[index])?;
+            restricted[index] = !field.starts_with(['*', '?']);
+        }
+        // Sunday can be written as both 0 and 7
+        if values[5] & (1 << 7) != 0 {
+            values[5] = (values[5] | 1) & !(1 << 7);
+        }
+
+        Ok(Cron {
+            source: trimmed.to_owned(),
+            seconds: values[0],
+            minutes: values[1],
+            hours: values[2],
+            days_of_month: values[3],
+            months: values[4],
+            days_of_week: values[5],
+            hours_restricted: restricted[2],
+            days_of_month_restricted: restricted[3],
+            days_of_week_restricted: restricted[5],
+        })
+    }
+}
+
+impl fmt::Display for Cron {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+impl fmt::Debug for Cron {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Cron").field(&self.source).finish()
+    }
+}
+
+struct Field {
+    name: &'static str,
+    min: u32,
+    max: u32,
+    names: &'static [&'static str],
+    /// the value of the first entry of `names`
+    first_name: u32,
+}
+
+const MONTHS: &[&str] = &[
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+const WEEKDAYS: &[&str] = &["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+const FIELDS: [Field; 6] = [
+    Field {
+        name: "second",
+        min: 0,
+        max: 59,
+        names: &[],
+        first_name: 0,
+    },
+    Field {
+        name: "minute",
+        min: 0,
+        max: 59,
+        names: &[],
+        first_name: 0,
+    },
+    Field {
+        name: "hour",
+        min: 0,
+        max: 23,
+        names: &[],
+        first_name: 0,
+    },
+    Field {
+        name: "day of month",
+        min: 1,
+        max: 31,
+        names: &[],
+        first_name: 0,
+    },
+    Field {
+        name: "month",
+        min: 1,
+        max: 12,
+        names: MONTHS,
+        first_name: 1,
+    },
+    Field {
+        name: "day of week",
+        min: 0,
+        max: 7,
+        names: WEEKDAYS,
+        first_name: 0,
+    },
+];
+
+/// Splits `expression` at whitespace, keeping the byte position of every field.
+fn split_fields(expression: &str) -> Vec<(usize, &str)> {
+    let mut fields = Vec::new();
+    let mut start = None;
+    for (index, char) in expression.char_indices() {
+        match (char.is_whitespace(), start) {
+            (true, Some(from)) => {
+                fields.push((from, &expression[from..index]));
+                start = None;
+            }
+            (false, None) => start = Some(index),
+            _ => {}
+        }
+    }
+    if let Some(from) = start {
+        fields.push((from, &expression[from..]));
+    }
+    fields
+}
+
+fn parse_field(field: &str, position: usize, spec: &Field) -> Result<u64, CronError> {
+    let mut bits = 0;
+    let mut offset = 0;
+    for item in field.split(',') {
+        bits |= parse_item(item, position + offset, spec)?;
+        offset += item.len() + 1;
+    }
+    Ok(bits)
+}
+
+fn parse_item(item: &str, position: usize, spec: &Field) -> Result<u64, CronError> {
+    let (range, step) = match item.split_once('/') {
+        Some((range, step)) => {
+            let step_position = position + range.len() + 1;
+            let step = step
+                .parse::<u32>()
+                .ok()
+                .filter(|&step| step > 0)
+                .ok_or_else(|| {
+                    CronError::new(step_position, CronErrorKind::InvalidStep(step.to_owned()))
+                })?;
+            (range, Some(step))
+        }
+        None => (item, None),
+    };
+
+    let (start, end) = if range == "*" || range == "?" {
+        (spec.min, spec.max)
+    } else if let Some((start, end)) = range.split_once('-') {
+        let end_position = position + start.len() + 1;
+        let (start, end) = (
+            parse_value(start, position, spec)?,
+            parse_value(end, end_position, spec)?,
+        );
+        if start > end {
+            return Err(CronError::new(
+                position,
+                CronErrorKind::InvalidRange(range.to_owned()),
+            ));
+        }
+        (start, end)
+    } else {
+        let value = parse_value(range, position, spec)?;
+        // `5/10` means every 10th value starting at 5
+        (value, if step.is_some() { spec.max } else { value })
+    };
+
+    let step = step.unwrap_or(1) as usize;
+    Ok((start..=end)
+        .step_by(step)
+        .fold(0, |bits, value| bits | 1 << value))
+}
+
+fn parse_value(value: &str, position: usize, spec: &Field) -> Result<u32, CronError> {
+    let parsed = match value.parse::<u32>() {
+        Ok(number) => Some(number),
+        Err(_) => spec
+            .names
+            .iter()
+            .position(|name| name.eq_ignore_ascii_case(value))
+            .map(|index| index as u32 + spec.first_name),
+    };
+    match parsed {
+        Some(number) if (spec.min..=spec.max).contains(&number) => Ok(number),
+        Some(_) => Err(CronError::new(
+            position,
+            CronErrorKind::OutOfRange {
+                field: spec.name,
+                value: value.to_owned(),
+                min: spec.min,
+                max: spec.max,
+            },
+        )),
+        None => Err(CronError::new(
+            position,
+            CronErrorKind::InvalidValue {
+                field: spec.name,
+                value: value.to_owned(),
+            },
+        )),
+    }
+}
+
+/// An error returned when parsing a [`Cron`] expression fails.
+///
+/// *This type requires the following crate features to be activated: `cron`*
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CronError {
+    position: usize,
+    kind: CronErrorKind,
+}
+
+/// The reason a [`Cron`] expression failed to parse.
+///
+/// *This type requires the following crate features to be activated: `cron`*
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CronErrorKind {
+    /// The expression has this many fields instead of 5 or 6.
+    FieldCount(usize),
+    /// The value is neither a number nor a known name.
+    InvalidValue {
+        /// The name of the field, like `"minute"`.
+        field: &'static str,
+        /// The value as written.
+        value: String,
+    },
+    /// The value is outside of the range of the field.
+    OutOfRange {
+        /// The name of the field, like `"minute"`.
+        field: &'static str,
+        /// The value as written.
+        value: String,
+        /// The smallest value of the field.
+        min: u32,
+        /// The largest value of the field.
+        max: u32,
+    },
+    /// The start of the range is after its end.
+    InvalidRange(String),
+    /// The step is not a positive number.
+    InvalidStep(String),
+    /// The expression starts with `@` but is not a known macro.
+    UnknownMacro,
+}
+
+impl CronError {
+    fn new(position: usize, kind: CronErrorKind) -> CronError {
+        CronError { position, kind }
+    }
+
+    /// Returns the byte offset into the expression at which the error was found.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the reason the expression failed to parse.
+    pub fn kind(&self) -> &CronErrorKind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for CronError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid cron expression at position {}: ", self.position)?;
+        match &self.kind {
+            CronErrorKind::FieldCount(count) => {
+                write!(f, "expected 5 or 6 fields, found {}", count)
+            }
+            CronErrorKind::InvalidValue { field, value } => {
+                write!(f, "invalid {} `{}`", field, value)
+            }
+            CronErrorKind::OutOfRange {
+                field,
+                value,
+                min,
+                max,
+            } => write!(f, "{} `{}` is not in {}-{}", field, value, min, max),
+            CronErrorKind::InvalidRange(range) => write!(f, "invalid range `{}`", range),
+            CronErrorKind::InvalidStep(step) => write!(f, "invalid step `{}`", step),
+            CronErrorKind::UnknownMacro => f.write_str("unknown macro"),
+        }
+    }
+}
+
+impl std::error::Error for CronError {}
+
+/// Returns the duration to the next time the cron expression matches, `None`
+/// if it never matches again.
+///
+/// # Example
+///
+/// ```rust
+/// use zila::{duration_to_next_cron, Cron};
+///
+/// let cron = Cron::parse("*/5 * * * * *").unwrap();
+/// let duration = duration_to_next_cron(&cron).unwrap();
+/// assert!(duration.as_secs() < 5);
+/// ```
+///
+/// *This function requires the following crate features to be activated: `cron`*
+pub fn duration_to_next_cron(cron: &Cron) -> Option<Duration> {
+    duration_to_next_cron_in(cron, &Local)
+}
+
+/// Returns the duration to the next time the cron expression matches in the
+/// given time zone, `None` if it never matches again.
+///
+/// # Example
+///
+/// ```rust
+/// use zila::chrono_tz::America::New_York;
+/// use zila::{duration_to_next_cron_in, Cron};
+///
+/// let cron = Cron::parse("0 9 * * *").unwrap();
+/// let duration = duration_to_next_cron_in(&cron, &New_York).unwrap();
+/// assert!(duration.as_secs() <= 25 * 60 * 60);
+/// ```
+///
+/// *This function requires the following crate features to be activated: `cron`*
+pub fn duration_to_next_cron_in<Tz: TimeZone>(cron: &Cron, tz: &Tz) -> Option<Duration> {
+    crate::duration_to_next_in(cron, tz)
+}
+
+/// calls the given function every time the cron expression matches
+///
+/// The timer finishes once the expression never matches again.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::{call_every_cron, Cron};
+///
+/// let cron = Cron::parse("30 9 * * MON-FRI").unwrap();
+/// call_every_cron(cron, || {
+///     println!("Good morning");
+/// });
+/// ```
+///
+/// *This function requires the following crate features to be activated: `cron`*
+pub fn call_every_cron<F, R>(cron: Cron, callback: F) -> R::Output
+where
+    F: Fn() -> R,
+    R: Flow,
+{
+    call_every_cron_mut(cron, callback)
+}
+
+/// calls the given function every time the cron expression matches, takes a
+/// `FnMut` as the argument
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use zila::{call_every_cron_mut, Cron};
+///
+/// let num = Rc::new(Cell::new(0));
+/// call_every_cron_mut(Cron::parse("@hourly").unwrap(), {
+///     let num = num.clone();
+///     move || {
+///         num.set(num.get() + 1);
+///     }
+/// });
+/// ```
+///
+/// *This function requires the following crate features to be activated: `cron`*
+pub fn call_every_cron_mut<F, R>(cron: Cron, callback: F) -> R::Output
+where
+    F: FnMut() -> R,
+    R: Flow,
+{
+    call_every_cron_in_mut(cron, Local, callback)
+}
+
+/// calls the given async function every time the cron expression matches
+///
+/// The timer finishes once the expression never matches again.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::{call_every_cron_async, Cron};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let cron = Cron::parse("30 9 * * MON-FRI").unwrap();
+/// call_every_cron_async(cron, || async {
+///     println!("Good morning");
+/// })
+/// .await;
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `cron`*
+pub async fn call_every_cron_async<F, Fut, R>(cron: Cron, callback: F) -> R::Output
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = R>,
+    R: Flow,
+{
+    call_every_cron_async_mut(cron, callback).await
+}
+
+/// calls the given async function every time the cron expression matches,
+/// takes a `FnMut` as the argument
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use zila::{call_every_cron_async_mut, Cron};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let num = Rc::new(Cell::new(0));
+/// call_every_cron_async_mut(Cron::parse("@hourly").unwrap(), move || {
+///     let num = num.clone();
+///     async move {
+///         num.set(num.get() + 1);
+///         println!("This is printed {} times.", num.get());
+///     }
+/// })
+/// .await;
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `cron`*
+pub async fn call_every_cron_async_mut<F, Fut, R>(cron: Cron, callback: F) -> R::Output
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = R>,
+    R: Flow,
+{
+    call_every_cron_in_async_mut(cron, Local, callback).await
+}
+
+/// calls the given function every time the cron expression matches in the
+/// given time zone
+///
+/// Like [`call_every_cron`], but the expression is matched against the wall
+/// time of `tz` instead of the local time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::{call_every_cron_in, Cron};
+///
+/// let cron = Cron::parse("0 0 * * *").unwrap();
+/// call_every_cron_in(cron, Berlin, || {
+///     println!("Midnight in Berlin");
+/// });
+/// ```
+///
+/// *This function requires the following crate features to be activated: `cron`*
+pub fn call_every_cron_in<Tz, F, R>(cron: Cron, tz: Tz, callback: F) -> R::Output
+where
+    Tz: TimeZone + Send + 'static,
+    F: Fn() -> R,
+    R: Flow,
+{
+    call_every_cron_in_mut(cron, tz, callback)
+}
+
+/// calls the given function every time the cron expression matches in the
+/// given time zone, takes a `FnMut` as the argument
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::{call_every_cron_in_mut, Cron};
+///
+/// let num = Rc::new(Cell::new(0));
+/// call_every_cron_in_mut(Cron::parse("@hourly").unwrap(), Berlin, {
+///     let num = num.clone();
+///     move || {
+///         num.set(num.get() + 1);
+///     }
+/// });
+/// ```
+///
+/// *This function requires the following crate features to be activated: `cron`*
+pub fn call_every_cron_in_mut<Tz, F, R>(cron: Cron, tz: Tz, mut callback: F) -> R::Output
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> R,
+    R: Flow,
+{
+    Timer::cron_in(cron, tz).run(move |_| callback())
+}
+
+/// calls the given async function every time the cron expression matches in
+/// the given time zone
+///
+/// Like [`call_every_cron_async`], but the expression is matched against the
+/// wall time of `tz` instead of the local time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::{call_every_cron_in_async, Cron};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let cron = Cron::parse("0 0 * * *").unwrap();
+/// call_every_cron_in_async(cron, Berlin, || async {
+///     println!("Midnight in Berlin");
+/// })
+/// .await;
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `cron`*
+pub async fn call_every_cron_in_async<Tz, F, Fut, R>(cron: Cron, tz: Tz, callback: F) -> R::Output
+where
+    Tz: TimeZone + Send + 'static,
+    F: Fn() -> Fut,
+    Fut: Future<Output = R>,
+    R: Flow,
+{
+    call_every_cron_in_async_mut(cron, tz, callback).await
+}
+
+/// calls the given async function every time the cron expression matches in
+/// the given time zone, takes a `FnMut` as the argument
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::{call_every_cron_in_async_mut, Cron};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let num = Rc::new(Cell::new(0));
+/// call_every_cron_in_async_mut(Cron::parse("@hourly").unwrap(), Berlin, move || {
+///     let num = num.clone();
+///     async move {
+///         num.set(num.get() + 1);
+///         println!("This is printed {} times.", num.get());
+///     }
+/// })
+/// .await;
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `cron`*
+pub async fn call_every_cron_in_async_mut<Tz, F, Fut, R>(
+    cron: Cron,
+    tz: Tz,
+    mut callback: F,
+) -> R::Output
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = R>,
+    R: Flow,
+{
+    Timer::cron_in(cron, tz)
+        .run_async(move |_| callback())
+        .await
+}
+
+/// calls the given fallible function every time the cron expression matches
+///
+/// Like [`call_every_cron_mut`], but the function returns a `Result`. What
+/// happens to an error is decided by `policy`, see [`ErrorPolicy`]. When the
+/// policy stops the timer, the function returns the error.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::{try_call_every_cron, Cron, ErrorPolicy};
+///
+/// fn rotate_logs() -> std::io::Result<()> {
+///     std::fs::rename("app.log", "app.log.1")
+/// }
+///
+/// let cron = Cron::parse("@daily").unwrap();
+/// let result = try_call_every_cron(cron, rotate_logs, ErrorPolicy::Stop);
+/// if let Err(error) = result {
+///     eprintln!("rotate_logs failed: {}", error);
+/// }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `cron`*
+pub fn try_call_every_cron<F, E>(cron: Cron, callback: F, policy: ErrorPolicy<E>) -> Result<(), E>
+where
+    F: FnMut() -> Result<(), E>,
+    E: fmt::Display + Send + 'static,
+{
+    try_call_every_cron_in(cron, Local, callback, policy)
+}
+
+/// calls the given fallible async function every time the cron expression matches
+///
+/// Like [`call_every_cron_async_mut`], but the future returns a `Result`.
+/// What happens to an error is decided by `policy`, see [`ErrorPolicy`]. When
+/// the policy stops the timer, the function returns the error.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::{try_call_every_cron_async, Cron, ErrorPolicy};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let cron = Cron::parse("@daily").unwrap();
+/// try_call_every_cron_async(cron, || async {
+///     std::fs::rename("app.log", "app.log.1")
+/// }, ErrorPolicy::Log)
+/// .await
+/// .unwrap();
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `cron`*
+pub async fn try_call_every_cron_async<F, Fut, E>(
+    cron: Cron,
+    callback: F,
+    policy: ErrorPolicy<E>,
+) -> Result<(), E>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<(), E>>,
+    E: fmt::Display + Send + 'static,
+{
+    try_call_every_cron_in_async(cron, Local, callback, policy).await
+}
+
+/// calls the given fallible function every time the cron expression matches
+/// in the given time zone
+///
+/// Like [`try_call_every_cron`], but the expression is matched against the
+/// wall time of `tz` instead of the local time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::{try_call_every_cron_in, Cron, ErrorPolicy};
+///
+/// let policy = ErrorPolicy::on_error(|error: &std::io::Error| {
+///     eprintln!("rotate_logs failed: {}", error);
+/// });
+/// let cron = Cron::parse("@daily").unwrap();
+/// try_call_every_cron_in(cron, Berlin, || std::fs::rename("app.log", "app.log.1"), policy)
+///     .unwrap();
+/// ```
+///
+/// *This function requires the following crate features to be activated: `cron`*
+pub fn try_call_every_cron_in<Tz, F, E>(
+    cron: Cron,
+    tz: Tz,
+    mut callback: F,
+    policy: ErrorPolicy<E>,
+) -> Result<(), E>
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> Result<(), E>,
+    E: fmt::Display + Send + 'static,
+{
+    Timer::cron_in(cron, tz).try_run(move |_| callback(), policy)
+}
+
+/// calls the given fallible async function every time the cron expression matches
+/// in the given time zone
+///
+/// Like [`try_call_every_cron_async`], but the expression is matched against the
+/// wall time of `tz` instead of the local time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::{try_call_every_cron_in_async, Cron, ErrorPolicy};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let cron = Cron::parse("@daily").unwrap();
+/// try_call_every_cron_in_async(cron, Berlin, || async {
+///     std::fs::rename("app.log", "app.log.1")
+/// }, ErrorPolicy::Stop)
+/// .await
+/// .unwrap();
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `cron`*
+pub async fn try_call_every_cron_in_async<Tz, F, Fut, E>(
+    cron: Cron,
+    tz: Tz,
+    mut callback: F,
+    policy: ErrorPolicy<E>,
+) -> Result<(), E>
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<(), E>>,
+    E: fmt::Display + Send + 'static,
+{
+    Timer::cron_in(cron, tz)
+        .try_run_async(move |_| callback(), policy)
+        .await
+}
+
+/// calls the given function every time the cron expression matches on a
+/// background thread
+///
+/// Like [`call_every_cron_mut`], but the timer runs on a thread named
+/// `zila-call-every-cron` and the function returns right away with a
+/// [`TimerHandle`] that cancels the timer or waits for it to finish.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::{spawn_call_every_cron, Cron};
+///
+/// let cron = Cron::parse("30 9 * * MON-FRI").unwrap();
+/// let handle = spawn_call_every_cron(cron, || {
+///     println!("Hi");
+/// });
+///
+/// // stop the timer on shutdown
+/// handle.cancel();
+/// ```
+///
+/// *This function requires the following crate features to be activated: `cron`*
+pub fn spawn_call_every_cron<F, R>(cron: Cron, callback: F) -> TimerHandle<R::Output>
+where
+    F: FnMut() -> R + Send + 'static,
+    R: Flow,
+{
+    spawn_call_every_cron_in(cron, Local, callback)
+}
+
+/// calls the given function every time the cron expression matches in the
+/// given time zone on a background thread
+///
+/// Like [`spawn_call_every_cron`], but the cron follows `tz` instead of the
+/// local time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::{spawn_call_every_cron_in, Cron};
+///
+/// let cron = Cron::parse("30 9 * * MON-FRI").unwrap();
+/// let handle = spawn_call_every_cron_in(cron, Berlin, || {
+///     println!("Hi");
+/// });
+///
+/// // stop the timer on shutdown
+/// handle.cancel();
+/// ```
+///
+/// *This function requires the following crate features to be activated: `cron`*
+pub fn spawn_call_every_cron_in<Tz, F, R>(
+    cron: Cron,
+    tz: Tz,
+    mut callback: F,
+) -> TimerHandle<R::Output>
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> R + Send + 'static,
+    R: Flow,
+{
+    Timer::cron_in(cron, tz).spawn(move |_| callback())
+}
@@ -0,0 +1,412 @@
+//! Plain English descriptions of schedules, used by `Schedule::describe`.
+
+use std::time::Duration;
+
+#[cfg(any(
+    feature = "week",
+    feature = "cron",
+    feature = "rrule",
+    feature = "systemd",
+    feature = "human"
+))]
+pub(crate) const WEEKDAYS: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+#[cfg(any(feature = "cron", feature = "rrule", feature = "systemd"))]
+pub(crate) const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// Joins items like `a, b and c`.
+#[cfg(any(
+    feature = "day",
+    feature = "hour",
+    feature = "cron",
+    feature = "rrule",
+    feature = "systemd",
+    feature = "human"
+))]
+pub(crate) fn join<I>(items: I) -> String
+where
+    I: IntoIterator,
+    I::Item: ToString,
+{
+    let mut items: Vec<String> = items.into_iter().map(|item| item.to_string()).collect();
+    match items.pop() {
+        None => String::new(),
+        Some(last) if items.is_empty() => last,
+        Some(last) => format!("{} and {}", items.join(", "), last),
+    }
+}
+
+/// Returns `one` or `many` depending on `count`.
+#[cfg(any(
+    feature = "hour",
+    feature = "cron",
+    feature = "rrule",
+    feature = "systemd",
+    feature = "human"
+))]
+pub(crate) fn plural<'a>(count: usize, one: &'a str, many: &'a str) -> &'a str {
+    if count == 1 {
+        one
+    } else {
+        many
+    }
+}
+
+/// Formats an offset into the day like `09:30`, or `09:30:15` with seconds.
+#[cfg(any(
+    feature = "day",
+    feature = "cron",
+    feature = "rrule",
+    feature = "systemd",
+    feature = "human"
+))]
+pub(crate) fn clock(seconds: u32) -> String {
+    let (hour, minute, second) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    if second == 0 {
+        format!("{:02}:{:02}", hour, minute)
+    } else {
+        format!("{:02}:{:02}:{:02}", hour, minute, second)
+    }
+}
+
+/// Formats a number like `1st`, `2nd` or `-1` like `last`.
+#[cfg(any(feature = "cron", feature = "rrule", feature = "systemd"))]
+pub(crate) fn ordinal(number: i32) -> String {
+    match number {
+        -1 => "last".to_owned(),
+        -2 => "second to last".to_owned(),
+        number if number < 0 => format!("{} to last", ordinal(-number)),
+        number => {
+            let suffix = match (number % 10, number % 100) {
+                (_, 11..=13) => "th",
+                (1, _) => "st",
+                (2, _) => "nd",
+                (3, _) => "rd",
+                _ => "th",
+            };
+            format!("{}{}", number, suffix)
+        }
+    }
+}
+
+/// Formats a duration with its `units` most significant units, like `1h 30m`
+/// or `250ms`, the rest is cut off.
+pub(crate) fn duration(duration: Duration, units: usize) -> String {
+    const UNITS: [(&str, u128); 7] = [
+        ("d", 24 * 60 * 60 * 1_000_000_000),
+        ("h", 60 * 60 * 1_000_000_000),
+        ("m", 60 * 1_000_000_000),
+        ("s", 1_000_000_000),
+        ("ms", 1_000_000),
+        ("us", 1_000),
+        ("ns", 1),
+    ];
+    let mut nanos = duration.as_nanos();
+    let mut parts = Vec::new();
+    for (name, length) in UNITS {
+        if parts.len() == units {
+            break;
+        }
+        let count = nanos / length;
+        nanos %= length;
+        if count > 0 {
+            parts.push(format!("{}{}", count, name));
+        } else if !parts.is_empty() {
+            // `1h 5s` would be misread as more precise than it is
+            break;
+        }
+    }
+    if parts.is_empty() {
+        return "0s".to_owned();
+    }
+    parts.join(" ")
+}
+
+/// Describes offsets into the hour in seconds, like `every hour at minute 15`.
+#[cfg(any(feature = "hour", feature = "rrule", feature = "human"))]
+pub(crate) fn hourly(offsets: &[u32]) -> String {
+    if offsets.iter().all(|offset| offset % 60 == 0) {
+        let minutes = offsets.iter().map(|offset| offset / 60);
+        return format!(
+            "every hour at {} {}",
+            plural(offsets.len(), "minute", "minutes"),
+            join(minutes)
+        );
+    }
+    let offsets = offsets
+        .iter()
+        .map(|offset| format!("{:02}:{:02}", offset / 60, offset % 60));
+    format!("every hour at {} past the hour", join(offsets))
+}
+
+/// Describes times of day in seconds, like `at 08:00 and 18:00`.
+#[cfg(any(feature = "day", feature = "rrule", feature = "human"))]
+pub(crate) fn times(offsets: &[u32]) -> String {
+    format!("at {}", join(offsets.iter().map(|&offset| clock(offset))))
+}
+
+/// Returns the values between `min` and `max` whose bits are set.
+#[cfg(any(
+    feature = "cron",
+    feature = "rrule",
+    feature = "systemd",
+    feature = "human"
+))]
+fn values(bits: u64, min: u32, max: u32) -> Vec<u32> {
+    (min..=max)
+        .filter(|value| bits & (1 << value) != 0)
+        .collect()
+}
+
+/// Lists values, runs of three or more as ranges, like `1 to 5 and 10`.
+#[cfg(any(
+    feature = "cron",
+    feature = "rrule",
+    feature = "systemd",
+    feature = "human"
+))]
+pub(crate) fn ranges(values: &[u32], name: impl Fn(u32) -> String) -> String {
+    let mut parts = Vec::new();
+    let mut index = 0;
+    while index < values.len() {
+        let mut end = index;
+        while end + 1 < values.len() && values[end + 1] == values[end] + 1 {
+            end += 1;
+        }
+        if end - index >= 2 {
+            parts.push(format!("{} to {}", name(values[index]), name(values[end])));
+        } else {
+            parts.extend(values[index..=end].iter().map(|&value| name(value)));
+        }
+        index = end + 1;
+    }
+    join(parts)
+}
+
+/// Describes days of the week, bit 0 is Monday, like `weekdays` or `Monday and
+/// Friday`.
+#[cfg(any(
+    feature = "cron",
+    feature = "rrule",
+    feature = "systemd",
+    feature = "human"
+))]
+pub(crate) fn weekdays(bits: u8) -> String {
+    match bits & 0b111_1111 {
+        0b001_1111 => "weekdays".to_owned(),
+        0b110_0000 => "weekends".to_owned(),
+        bits => ranges(&values(bits.into(), 0, 6), |day| {
+            WEEKDAYS[day as usize].to_owned()
+        }),
+    }
+}
+
+/// A component of a calendar time.
+#[cfg(any(feature = "cron", feature = "systemd"))]
+enum Field {
+    All,
+    /// every `n`th value from the first one
+    Step(u32),
+    Values(Vec<u32>),
+}
+
+#[cfg(any(feature = "cron", feature = "systemd"))]
+impl Field {
+    fn new(bits: u64, max: u32) -> Field {
+        let values = values(bits, 0, max);
+        if values.len() == max as usize + 1 {
+            return Field::All;
+        }
+        if let [0, step, ..] = values[..] {
+            let every = (0..=max).step_by(step as usize);
+            if step > 1 && values.len() > 2 && every.eq(values.iter().copied()) {
+                return Field::Step(step);
+            }
+        }
+        Field::Values(values)
+    }
+}
+
+/// The components of a calendar event, like a cron expression, the values are
+/// bits.
+#[cfg(any(feature = "cron", feature = "systemd"))]
+pub(crate) struct Fields {
+    pub(crate) seconds: u64,
+    pub(crate) minutes: u64,
+    pub(crate) hours: u64,
+    /// `None` for every day, bit 1 is the first day
+    pub(crate) days: Option<u64>,
+    /// `days` counts from the end of the month, the last day is 1
+    pub(crate) days_from_end: bool,
+    /// `None` for every day, bit 0 is Monday
+    pub(crate) weekdays: Option<u8>,
+    /// a day matches if it matches either `days` or `weekdays`, like in cron
+    pub(crate) either: bool,
+    /// bit 1 is January
+    pub(crate) months: u64,
+    /// every year if empty
+    pub(crate) years: Vec<i32>,
+}
+
+#[cfg(any(feature = "cron", feature = "systemd"))]
+impl Fields {
+    pub(crate) fn describe(&self) -> String {
+        let (time, at) = self.time();
+        let date = self.date();
+        match (date.is_empty(), at) {
+            (true, true) => format!("every day {}", time),
+            (true, false) => time,
+            (false, _) => format!("{} {}", time, date),
+        }
+    }
+
+    /// Describes the time of day, `true` if it's a list of times.
+    fn time(&self) -> (String, bool) {
+        let seconds = Field::new(self.seconds, 59);
+        let minutes = Field::new(self.minutes, 59);
+        let hours = Field::new(self.hours, 23);
+        if let (Field::Values(seconds), Field::Values(minutes), Field::Values(hours)) =
+            (&seconds, &minutes, &hours)
+        {
+            if let [second] = seconds[..] {
+                if minutes.len() * hours.len() <= 6 {
+                    let times = hours.iter().flat_map(|hour| {
+                        minutes
+                            .iter()
+                            .map(move |minute| clock(hour * 3600 + minute * 60 + second))
+                    });
+                    return (format!("at {}", join(times)), true);
+                }
+            }
+        }
+        let second = match &seconds {
+            Field::Values(values) if values[..] == [0] => String::new(),
+            Field::Values(values) => format!(
+                " at {} {}",
+                plural(values.len(), "second", "seconds"),
+                ranges(values, |value| value.to_string())
+            ),
+            _ => String::new(),
+        };
+        let time = match (&seconds, &minutes) {
+            (Field::All, Field::All) => "every second".to_owned(),
+            (Field::Step(step), Field::All) => format!("every {} seconds", step),
+            (Field::All | Field::Step(_), _) => {
+                format!(
+                    "{} during {}",
+                    seconds.every("second"),
+                    minutes.during("minute")
+                )
+            }
+            (Field::Values(_), Field::All) => format!("every minute{}", second),
+            (Field::Values(_), Field::Step(step)) => format!("every {} minutes{}", step, second),
+            (Field::Values(_), Field::Values(values)) => {
+                let minute = format!(
+                    "{} {}{}",
+                    plural(values.len(), "minute", "minutes"),
+                    ranges(values, |value| value.to_string()),
+                    second
+                );
+                match (&hours, second.is_empty(), &values[..]) {
+                    (Field::Step(step), true, [0]) => {
+                        return (format!("every {} hours", step), false)
+                    }
+                    (Field::All, ..) => return (format!("every hour at {}", minute), false),
+                    _ => format!("at {}", minute),
+                }
+            }
+        };
+        match hours {
+            Field::All => (time, false),
+            hours => (format!("{} during {}", time, hours.during("hour")), false),
+        }
+    }
+
+    fn date(&self) -> String {
+        let mut parts = Vec::new();
+        let days = self.days.map(|bits| {
+            let days = values(bits, 1, 31);
+            if self.days_from_end {
+                let days = ranges(&days, |day| match day {
+                    1 => "last".to_owned(),
+                    day => format!("{} to last", ordinal(day as i32)),
+                });
+                format!("on the {} day of the month", days)
+            } else {
+                format!(
+                    "on {} {} of the month",
+                    plural(days.len(), "day", "days"),
+                    ranges(&days, |day| day.to_string())
+                )
+            }
+        });
+        let weekdays = self.weekdays.map(|bits| format!("on {}", weekdays(bits)));
+        match (days, weekdays) {
+            (Some(days), Some(weekdays)) if self.either => {
+                parts.push(format!("{} or {}", days, weekdays))
+            }
+            (days, weekdays) => parts.extend(days.into_iter().chain(weekdays)),
+        }
+        let months = values(self.months, 1, 12);
+        if months.len() < 12 {
+            parts.push(format!(
+                "in {}",
+                ranges(&months, |month| MONTHS[month as usize - 1].to_owned())
+            ));
+        }
+        if !self.years.is_empty() {
+            parts.push(format!("in {}", join(&self.years)));
+        }
+        parts.join(" ")
+    }
+}
+
+#[cfg(any(feature = "cron", feature = "systemd"))]
+impl Field {
+    /// Describes the field as the main part of a time, like `every 5 seconds`.
+    fn every(&self, unit: &str) -> String {
+        match self {
+            Field::All => format!("every {}", unit),
+            Field::Step(step) => format!("every {} {}s", step, unit),
+            Field::Values(values) => format!(
+                "at {} {}",
+                plural(values.len(), unit, &format!("{}s", unit)),
+                ranges(values, |value| value.to_string())
+            ),
+        }
+    }
+
+    /// Describes the field as a restriction, like `hours 9 to 17`.
+    fn during(&self, unit: &str) -> String {
+        match self {
+            Field::All => format!("every {}", unit),
+            Field::Step(step) => format!("every {} {}", ordinal(*step as i32), unit),
+            Field::Values(values) => format!(
+                "{} {}",
+                plural(values.len(), unit, &format!("{}s", unit)),
+                ranges(values, |value| value.to_string())
+            ),
+        }
+    }
+}
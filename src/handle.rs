@@ -0,0 +1,328 @@
+//! Handles to timers running in the background.
+
+use std::any::Any;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock, PoisonError};
+use std::task::{Context, Poll};
+use std::thread::{self, JoinHandle, Thread};
+
+/// An owned permission to cancel and join a timer running on a background
+/// thread.
+///
+/// Returned by the `spawn_` functions, like
+/// [`spawn_set_interval`](crate::spawn_set_interval), and by
+/// [`Timer::spawn`](crate::Timer::spawn). Dropping a `TimerHandle` detaches
+/// the timer, it keeps running until the process exits.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use zila::spawn_set_interval;
+///
+/// let handle = spawn_set_interval(|| {
+///     println!("Hi");
+/// }, Duration::from_millis(10));
+///
+/// assert_eq!(handle.thread().name(), Some("zila-set-interval"));
+///
+/// std::thread::sleep(Duration::from_millis(50));
+/// handle.cancel();
+/// assert!(handle.join().unwrap_err().is_cancelled());
+/// ```
+#[must_use = "dropping a `TimerHandle` detaches the timer, call `join` to wait for it"]
+pub struct TimerHandle<T = ()> {
+    token: Token,
+    thread: JoinHandle<Option<T>>,
+}
+
+impl<T> TimerHandle<T> {
+    /// Cancels the timer.
+    ///
+    /// A callback that is already running is not interrupted, the timer stops
+    /// before its next invocation.
+    pub fn cancel(&self) {
+        self.token.cancel();
+    }
+
+    /// Returns `true` if the timer has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.token.is_cancelled()
+    }
+
+    /// Returns `true` if the timer thread has stopped running.
+    pub fn is_finished(&self) -> bool {
+        self.thread.is_finished()
+    }
+
+    /// Returns the thread the timer runs on.
+    ///
+    /// Every timer gets a dedicated thread named after the function that
+    /// started it, like `zila-set-interval`.
+    pub fn thread(&self) -> &Thread {
+        self.thread.thread()
+    }
+
+    /// Waits for the timer to finish.
+    ///
+    /// Returns [`JoinError::Cancelled`] if the timer was cancelled before it
+    /// finished and [`JoinError::Panic`] if the callback panicked.
+    pub fn join(self) -> Result<T, JoinError> {
+        match self.thread.join() {
+            Ok(Some(value)) => Ok(value),
+            Ok(None) => Err(JoinError::Cancelled),
+            Err(payload) => Err(JoinError::Panic(payload)),
+        }
+    }
+}
+
+impl<T> fmt::Debug for TimerHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TimerHandle")
+            .field("thread", &self.thread().name())
+            .field("cancelled", &self.is_cancelled())
+            .field("finished", &self.is_finished())
+            .finish()
+    }
+}
+
+/// An owned permission to cancel and join a timer running on the tokio runtime.
+///
+/// Returned by [`Timer::spawn_async`](crate::Timer::spawn_async). Awaiting a
+/// `TaskHandle` waits for the timer to finish, just like [`TaskHandle::join`].
+/// Dropping a `TaskHandle` detaches the timer.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use zila::Timer;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let timer = Timer::interval(Duration::from_millis(10));
+/// let handle = timer.spawn_async(|_| async {
+///     println!("Hi");
+/// });
+///
+/// tokio::time::sleep(Duration::from_millis(50)).await;
+/// handle.cancel();
+/// assert!(handle.await.unwrap_err().is_cancelled());
+/// # }
+/// ```
+#[must_use = "dropping a `TaskHandle` detaches the timer, `.await` it to wait for it"]
+pub struct TaskHandle<T = ()> {
+    task: tokio::task::JoinHandle<T>,
+}
+
+impl<T> TaskHandle<T> {
+    /// Cancels the timer.
+    ///
+    /// Unlike [`TimerHandle::cancel`], a callback that is currently running is
+    /// dropped at its next `.await` point.
+    pub fn cancel(&self) {
+        self.task.abort();
+    }
+
+    /// Returns `true` if the timer task has stopped running.
+    pub fn is_finished(&self) -> bool {
+        self.task.is_finished()
+    }
+
+    /// Waits for the timer to finish.
+    ///
+    /// Returns [`JoinError::Cancelled`] if the timer was cancelled before it
+    /// finished and [`JoinError::Panic`] if the callback panicked.
+    pub async fn join(self) -> Result<T, JoinError> {
+        self.await
+    }
+}
+
+impl<T> Future for TaskHandle<T> {
+    type Output = Result<T, JoinError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.task).poll(cx).map_err(JoinError::from)
+    }
+}
+
+impl<T> fmt::Debug for TaskHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TaskHandle")
+            .field("finished", &self.is_finished())
+            .finish()
+    }
+}
+
+/// Cancels a timer that runs on another thread.
+///
+/// Returned by [`Timer::canceller`](crate::Timer::canceller) before the timer
+/// starts, so the thread that blocks in [`Timer::run`](crate::Timer::run) can
+/// be stopped from the outside. A `Canceller` can be cloned and sent to other
+/// threads.
+#[derive(Clone)]
+pub struct Canceller {
+    token: Token,
+}
+
+impl Canceller {
+    pub(crate) fn new(token: Token) -> Canceller {
+        Canceller { token }
+    }
+
+    /// Cancels the timer.
+    ///
+    /// A callback that is already running is not interrupted, the timer stops
+    /// before its next invocation.
+    pub fn cancel(&self) {
+        self.token.cancel();
+    }
+
+    /// Returns `true` if the timer has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.token.is_cancelled()
+    }
+}
+
+impl fmt::Debug for Canceller {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Canceller")
+            .field("cancelled", &self.is_cancelled())
+            .finish()
+    }
+}
+
+/// The error returned when joining a timer that did not finish normally.
+pub enum JoinError {
+    /// The timer was cancelled before it finished.
+    Cancelled,
+    /// The callback panicked, holds the panic payload.
+    Panic(Box<dyn Any + Send + 'static>),
+}
+
+impl JoinError {
+    /// Returns `true` if the timer was cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        matches!(self, JoinError::Cancelled)
+    }
+
+    /// Returns `true` if the callback panicked.
+    pub fn is_panic(&self) -> bool {
+        matches!(self, JoinError::Panic(_))
+    }
+}
+
+impl From<tokio::task::JoinError> for JoinError {
+    fn from(error: tokio::task::JoinError) -> Self {
+        if error.is_panic() {
+            JoinError::Panic(error.into_panic())
+        } else {
+            JoinError::Cancelled
+        }
+    }
+}
+
+impl fmt::Debug for JoinError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JoinError::Cancelled => f.write_str("Cancelled"),
+            JoinError::Panic(_) => f.debug_tuple("Panic").finish_non_exhaustive(),
+        }
+    }
+}
+
+impl fmt::Display for JoinError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JoinError::Cancelled => f.write_str("timer was cancelled"),
+            JoinError::Panic(_) => f.write_str("timer callback panicked"),
+        }
+    }
+}
+
+impl std::error::Error for JoinError {}
+
+/// Shared cancellation state between a timer and its [`TimerHandle`] or
+/// [`Canceller`].
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Token {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    cancelled: Mutex<bool>,
+    /// The thread the timer runs on, unparked on cancellation.
+    thread: OnceLock<Thread>,
+}
+
+impl Token {
+    fn lock(&self) -> MutexGuard<'_, bool> {
+        self.inner
+            .cancelled
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub(crate) fn cancel(&self) {
+        let mut cancelled = self.lock();
+        *cancelled = true;
+        // wakes timers that sleep on a `Clock`
+        if let Some(thread) = self.inner.thread.get() {
+            thread.unpark();
+        }
+    }
+
+    /// Remembers the thread the timer runs on, to wake it up on cancellation.
+    pub(crate) fn bind(&self, thread: Thread) {
+        // under the lock, a concurrent `cancel` sees the thread or the flag
+        let cancelled = self.lock();
+        if *cancelled {
+            thread.unpark();
+        }
+        let _ = self.inner.thread.set(thread);
+    }
+
+    pub(crate) fn is_cancelled(&self) -> bool {
+        *self.lock()
+    }
+}
+
+/// Runs `f` on a new thread called `name` that `token` cancels, `f` returns
+/// `None` when it stops because of a cancellation.
+///
+/// # Panics
+///
+/// Panics if the OS fails to create a thread, just like [`std::thread::spawn`].
+pub(crate) fn spawn<T, F>(name: &str, token: Token, f: F) -> TimerHandle<T>
+where
+    F: FnOnce(&Token) -> Option<T> + Send + 'static,
+    T: Send + 'static,
+{
+    let thread = thread::Builder::new()
+        .name(name.to_owned())
+        .spawn({
+            let token = token.clone();
+            move || f(&token)
+        })
+        .expect("failed to spawn thread");
+    token.bind(thread.thread().clone());
+    TimerHandle { token, thread }
+}
+
+/// Runs `future` on the current tokio runtime.
+///
+/// # Panics
+///
+/// Panics if called outside of a tokio runtime.
+pub(crate) fn spawn_async<T, F>(future: F) -> TaskHandle<T>
+where
+    F: Future<Output = T> + Send + 'static,
+    T: Send + 'static,
+{
+    TaskHandle {
+        task: tokio::spawn(future),
+    }
+}
@@ -0,0 +1,760 @@
+//! Durations and schedules written in plain English.
+
+use crate::calendar::{self, Fold};
+use crate::describe;
+use crate::Schedule;
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike};
+use std::fmt;
+use std::str::FromStr;
+use tokio::time::Duration;
+
+/// Parses a human readable duration, like `1h30m`, `250ms` or
+/// `2 hours and 15 minutes`.
+///
+/// A duration is a list of numbers, each followed by a unit, optionally
+/// separated by spaces, commas or `and`. The units are `ns`, `us` (or `µs`),
+/// `ms`, `s`, `m`, `h`, `d` and `w`, their long names like `minutes`, and the
+/// usual abbreviations like `sec`, `min` or `hr`. Months and years are not
+/// accepted because their length varies.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use zila::parse_duration;
+///
+/// assert_eq!(parse_duration("1h30m"), Ok(Duration::from_secs(90 * 60)));
+/// assert_eq!(parse_duration("250ms"), Ok(Duration::from_millis(250)));
+/// assert_eq!(parse_duration("1 day, 2 hours"), Ok(Duration::from_secs(26 * 60 * 60)));
+///
+/// let error = parse_duration("5 fortnights").unwrap_err();
+/// assert_eq!(error.position(), 2);
+/// ```
+///
+/// *This function requires the following crate features to be activated: `human`*
+pub fn parse_duration(text: &str) -> Result<Duration, HumanError> {
+    let (duration, end) = parse_duration_at(text, 0)?;
+    match end {
+        Some((position, token)) => Err(HumanError::new(
+            position,
+            HumanErrorKind::Unexpected {
+                found: token.to_owned(),
+                expected: "a number",
+            },
+        )),
+        None => Ok(duration),
+    }
+}
+
+/// Parses the duration at the start of `text`, which starts at `offset` in
+/// the whole text, returns it along with the first word that's not a part of
+/// it, if any.
+fn parse_duration_at(
+    text: &str,
+    offset: usize,
+) -> Result<(Duration, Option<Word<'_>>), HumanError> {
+    let mut nanos: u128 = 0;
+    let mut rest = text;
+    let mut parts = 0;
+    loop {
+        let trimmed = rest.trim_start_matches(|char: char| char.is_whitespace() || char == ',');
+        let trimmed = match trimmed.strip_prefix("and") {
+            Some(after) if parts > 0 && after.starts_with(char::is_whitespace) => {
+                after.trim_start()
+            }
+            _ => trimmed,
+        };
+        let position = offset + text.len() - trimmed.len();
+        if trimmed.is_empty() {
+            if parts == 0 {
+                return Err(HumanError::new(position, HumanErrorKind::Empty));
+            }
+            break;
+        }
+        let digits = trimmed.len()
+            - trimmed
+                .trim_start_matches(|char: char| char.is_ascii_digit())
+                .len();
+        if digits == 0 {
+            if parts == 0 {
+                return Err(HumanError::new(
+                    position,
+                    HumanErrorKind::Unexpected {
+                        found: first_word(trimmed).to_owned(),
+                        expected: "a number",
+                    },
+                ));
+            }
+            return Ok((
+                to_duration(nanos, offset)?,
+                Some((position, first_word(trimmed))),
+            ));
+        }
+        let number: u128 = trimmed[..digits]
+            .parse()
+            .map_err(|_| HumanError::new(position, HumanErrorKind::Overflow))?;
+        let after_number = trimmed[digits..].trim_start();
+        let unit_position = offset + text.len() - after_number.len();
+        let unit_len = after_number.len()
+            - after_number
+                .trim_start_matches(|char: char| char.is_alphabetic())
+                .len();
+        let unit = &after_number[..unit_len];
+        if unit.is_empty() {
+            return Err(HumanError::new(unit_position, HumanErrorKind::MissingUnit));
+        }
+        let scale = unit_nanos(unit).ok_or_else(|| {
+            HumanError::new(unit_position, HumanErrorKind::UnknownUnit(unit.to_owned()))
+        })?;
+        nanos = number
+            .checked_mul(scale)
+            .and_then(|part| nanos.checked_add(part))
+            .ok_or_else(|| HumanError::new(position, HumanErrorKind::Overflow))?;
+        parts += 1;
+        rest = &after_number[unit_len..];
+    }
+    Ok((to_duration(nanos, offset)?, None))
+}
+
+/// A word and its position.
+type Word<'a> = (usize, &'a str);
+
+fn to_duration(nanos: u128, position: usize) -> Result<Duration, HumanError> {
+    let seconds = u64::try_from(nanos / 1_000_000_000)
+        .map_err(|_| HumanError::new(position, HumanErrorKind::Overflow))?;
+    Ok(Duration::new(seconds, (nanos % 1_000_000_000) as u32))
+}
+
+fn first_word(text: &str) -> &str {
+    text.split(|char: char| char.is_whitespace() || char == ',')
+        .next()
+        .unwrap_or_default()
+}
+
+/// Returns the length of the unit in nanoseconds.
+fn unit_nanos(unit: &str) -> Option<u128> {
+    const SECOND: u128 = 1_000_000_000;
+    let nanos = match unit.to_lowercase().as_str() {
+        "ns" | "nsec" | "nsecs" | "nanosecond" | "nanoseconds" => 1,
+        "us" | "µs" | "usec" | "usecs" | "microsecond" | "microseconds" => 1_000,
+        "ms" | "msec" | "msecs" | "millis" | "millisecond" | "milliseconds" => 1_000_000,
+        "s" | "sec" | "secs" | "second" | "seconds" => SECOND,
+        "m" | "min" | "mins" | "minute" | "minutes" => 60 * SECOND,
+        "h" | "hr" | "hrs" | "hour" | "hours" => 60 * 60 * SECOND,
+        "d" | "day" | "days" => 24 * 60 * 60 * SECOND,
+        "w" | "wk" | "wks" | "week" | "weeks" => 7 * 24 * 60 * 60 * SECOND,
+        _ => return None,
+    };
+    Some(nanos)
+}
+
+/// A schedule written as an English phrase, like `every 15 minutes` or
+/// `every monday at 9am`.
+///
+/// The accepted phrases are:
+///
+/// - `every` and a duration, like `every 15 minutes` or `every 1h30m`, fires
+///   one period after the timer starts and every period after that, like an
+///   [`Interval`](crate::Interval), see [`parse_duration`] for the syntax
+/// - `every second`, `every minute`, `every hour`, `every day`, `every week`,
+///   `every month`, `every quarter` and `every year` fire at the start of every
+///   one of them, weeks start on Monday
+/// - `every hour at` and minutes past the hour, like `every hour at :15` or
+///   `every hour at :00 and :30`
+/// - `every day`, `every weekday`, `every weekend` or days of the week, like
+///   `every monday` or `every tue and thu`, followed by `at` and times of
+///   day, like `every day at 03:00`, `every monday at 9am` or
+///   `every weekday at 9:30am and 5pm`, at midnight if no time is given
+///
+/// Times are either on a 24 hour clock, like `17:30`, or on a 12 hour clock,
+/// like `5:30pm`, `noon` and `midnight` work too. Times of day are evaluated in
+/// the time zone of the timer, daylight saving time is handled like in
+/// [`Daily`](crate::Daily) schedules.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use zila::{Every, Schedule};
+///
+/// let every: Every = "every monday at 9am".parse().unwrap();
+/// // a Saturday
+/// let time = Utc.with_ymd_and_hms(2023, 5, 13, 12, 0, 0).unwrap();
+/// let next = Utc.with_ymd_and_hms(2023, 5, 15, 9, 0, 0).unwrap();
+/// assert_eq!(every.next_after(&time), Some(next));
+///
+/// let error = "every monday at 9xm".parse::<Every>().unwrap_err();
+/// assert_eq!(
+///     error.to_string(),
+///     "invalid schedule at position 16: expected a time like 09:00 or 9am, found `9xm`"
+/// );
+/// ```
+///
+/// *This type requires the following crate features to be activated: `human`*
+#[derive(Clone, PartialEq, Eq)]
+pub struct Every {
+    source: String,
+    kind: Kind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Kind {
+    Period(Duration),
+    Boundary(Unit),
+    /// seconds into the hour, sorted
+    Hourly(Vec<u32>),
+    Daily {
+        /// bit 0 is Monday
+        weekdays: u8,
+        /// sorted
+        times: Vec<NaiveTime>,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Unit {
+    Second,
+    Minute,
+    Hour,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+impl Every {
+    /// Parses a phrase, same as [`str::parse`].
+    pub fn parse(phrase: &str) -> Result<Every, HumanError> {
+        phrase.parse()
+    }
+
+    /// Returns the first local time equal to or after `from` that matches.
+    fn next_local(&self, from: NaiveDateTime) -> Option<NaiveDateTime> {
+        match &self.kind {
+            Kind::Period(_) => None,
+            Kind::Boundary(unit) => unit.next_local(from),
+            Kind::Hourly(offsets) => {
+                let hour = from.date().and_hms_opt(from.hour(), 0, 0)?;
+                [hour, hour + chrono::Duration::hours(1)]
+                    .into_iter()
+                    .flat_map(|hour| {
+                        offsets
+                            .iter()
+                            .map(move |&offset| hour + chrono::Duration::seconds(offset.into()))
+                    })
+                    .find(|&time| time >= from)
+            }
+            Kind::Daily { weekdays, times } => from
+                .date()
+                .iter_days()
+                .take(8)
+                .filter(|date| weekdays & (1 << date.weekday().num_days_from_monday()) != 0)
+                .flat_map(|date| times.iter().map(move |&time| date.and_time(time)))
+                .find(|&time| time >= from),
+        }
+    }
+}
+
+impl Unit {
+    /// Returns `from` if it's the start of a unit, the start of the next one
+    /// otherwise.
+    fn next_local(self, from: NaiveDateTime) -> Option<NaiveDateTime> {
+        let date = from.date();
+        let midnight = date.and_time(NaiveTime::MIN);
+        let start = match self {
+            Unit::Second => return Some(from),
+            Unit::Minute => date.and_hms_opt(from.hour(), from.minute(), 0)?,
+            Unit::Hour => date.and_hms_opt(from.hour(), 0, 0)?,
+            Unit::Week => {
+                midnight - chrono::Duration::days(date.weekday().num_days_from_monday().into())
+            }
+            Unit::Month => date.with_day(1)?.and_time(NaiveTime::MIN),
+            Unit::Quarter => NaiveDate::from_ymd_opt(date.year(), date.month0() / 3 * 3 + 1, 1)?
+                .and_time(NaiveTime::MIN),
+            Unit::Year => date.with_ordinal(1)?.and_time(NaiveTime::MIN),
+        };
+        if start == from {
+            return Some(from);
+        }
+        match self {
+            Unit::Second => Some(from),
+            Unit::Minute => Some(start + chrono::Duration::minutes(1)),
+            Unit::Hour => Some(start + chrono::Duration::hours(1)),
+            Unit::Week => Some(start + chrono::Duration::weeks(1)),
+            Unit::Month => add_months(start.date(), 1),
+            Unit::Quarter => add_months(start.date(), 3),
+            Unit::Year => add_months(start.date(), 12),
+        }
+    }
+}
+
+fn add_months(date: NaiveDate, months: u32) -> Option<NaiveDateTime> {
+    let month = date.month0() + months;
+    NaiveDate::from_ymd_opt(date.year() + (month / 12) as i32, month % 12 + 1, 1)
+        .map(|date| date.and_time(NaiveTime::MIN))
+}
+
+impl Schedule for Every {
+    fn next_after<Tz: TimeZone>(&self, time: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        let fold = match &self.kind {
+            Kind::Period(period) => {
+                let period = chrono::Duration::from_std(*period).ok()?;
+                return time.clone().checked_add_signed(period);
+            }
+            Kind::Boundary(Unit::Second | Unit::Minute | Unit::Hour) | Kind::Hourly(_) => {
+                Fold::Repeat
+            }
+            Kind::Boundary(_) | Kind::Daily { .. } => Fold::Skip,
+        };
+        calendar::next_after(time, fold, |from| self.next_local(from))
+    }
+
+    fn describe(&self) -> String {
+        match &self.kind {
+            Kind::Period(period) => format!("every {}", describe::duration(*period, 7)),
+            Kind::Boundary(unit) => match unit {
+                Unit::Second => "every second",
+                Unit::Minute => "every minute",
+                Unit::Hour => "every hour",
+                Unit::Week => "every Monday at midnight",
+                Unit::Month => "at midnight on the first day of every month",
+                Unit::Quarter => "at midnight on the first day of every quarter",
+                Unit::Year => "at midnight on the first of January",
+            }
+            .to_owned(),
+            Kind::Hourly(offsets) => describe::hourly(offsets),
+            Kind::Daily { weekdays, times } => {
+                let times: Vec<_> = times
+                    .iter()
+                    .map(|time| time.num_seconds_from_midnight())
+                    .collect();
+                match weekdays {
+                    0b111_1111 => format!("every day {}", describe::times(&times)),
+                    _ => format!(
+                        "{} on {}",
+                        describe::times(&times),
+                        describe::weekdays(*weekdays)
+                    ),
+                }
+            }
+        }
+    }
+}
+
+impl FromStr for Every {
+    type Err = HumanError;
+
+    fn from_str(phrase: &str) -> Result<Every, HumanError> {
+        let mut words = Words::new(phrase);
+        words.expect("every", "`every`")?;
+        let Some((position, word)) = words.peek() else {
+            return Err(words.end("a duration, a unit or days of the week"));
+        };
+        let kind = if word.starts_with(|char: char| char.is_ascii_digit()) {
+            let (period, rest) = parse_duration_at(&phrase[position..], position)?;
+            if let Some((position, word)) = rest {
+                return Err(HumanError::new(
+                    position,
+                    HumanErrorKind::Unexpected {
+                        found: word.to_owned(),
+                        expected: "a number",
+                    },
+                ));
+            }
+            if period.is_zero() {
+                return Err(HumanError::new(position, HumanErrorKind::ZeroPeriod));
+            }
+            return Ok(Every {
+                source: phrase.trim().to_owned(),
+                kind: Kind::Period(period),
+            });
+        } else {
+            words.next();
+            match word.to_lowercase().as_str() {
+                "second" => Kind::Boundary(Unit::Second),
+                "minute" => Kind::Boundary(Unit::Minute),
+                "hour" if words.next_if("at") => Kind::Hourly(words.offsets()?),
+                "hour" => Kind::Boundary(Unit::Hour),
+                "week" => Kind::Boundary(Unit::Week),
+                "month" => Kind::Boundary(Unit::Month),
+                "quarter" => Kind::Boundary(Unit::Quarter),
+                "year" => Kind::Boundary(Unit::Year),
+                _ => {
+                    let weekdays = words.weekdays(position, word)?;
+                    let times = if words.next_if("at") {
+                        words.times()?
+                    } else {
+                        vec![NaiveTime::MIN]
+                    };
+                    Kind::Daily { weekdays, times }
+                }
+            }
+        };
+        if let Some((position, word)) = words.next() {
+            return Err(HumanError::new(
+                position,
+                HumanErrorKind::Unexpected {
+                    found: word.to_owned(),
+                    expected: "the end of the phrase",
+                },
+            ));
+        }
+        Ok(Every {
+            source: phrase.trim().to_owned(),
+            kind,
+        })
+    }
+}
+
+impl fmt::Display for Every {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+impl fmt::Debug for Every {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Every").field(&self.source).finish()
+    }
+}
+
+/// The words of a phrase along with their positions, commas separate words
+/// too.
+struct Words<'a> {
+    text: &'a str,
+    words: std::iter::Peekable<std::vec::IntoIter<Word<'a>>>,
+}
+
+impl<'a> Words<'a> {
+    fn new(text: &'a str) -> Words<'a> {
+        let mut words = Vec::new();
+        let mut start = None;
+        for (index, char) in text.char_indices() {
+            match (char.is_whitespace() || char == ',', start) {
+                (true, Some(from)) => {
+                    words.push((from, &text[from..index]));
+                    start = None;
+                }
+                (false, None) => start = Some(index),
+                _ => {}
+            }
+        }
+        if let Some(from) = start {
+            words.push((from, &text[from..]));
+        }
+        Words {
+            text,
+            words: words.into_iter().peekable(),
+        }
+    }
+
+    fn peek(&mut self) -> Option<Word<'a>> {
+        self.words.peek().copied()
+    }
+
+    fn next(&mut self) -> Option<Word<'a>> {
+        self.words.next()
+    }
+
+    /// Skips the next word if it is `word`.
+    fn next_if(&mut self, word: &str) -> bool {
+        self.words
+            .next_if(|(_, next)| next.eq_ignore_ascii_case(word))
+            .is_some()
+    }
+
+    fn expect(&mut self, word: &str, expected: &'static str) -> Result<(), HumanError> {
+        match self.next() {
+            Some((_, next)) if next.eq_ignore_ascii_case(word) => Ok(()),
+            Some((position, next)) => Err(HumanError::new(
+                position,
+                HumanErrorKind::Unexpected {
+                    found: next.to_owned(),
+                    expected,
+                },
+            )),
+            None => Err(self.end(expected)),
+        }
+    }
+
+    fn end(&self, expected: &'static str) -> HumanError {
+        let kind = if self.text.trim().is_empty() {
+            HumanErrorKind::Empty
+        } else {
+            HumanErrorKind::UnexpectedEnd { expected }
+        };
+        HumanError::new(self.text.trim_end().len(), kind)
+    }
+
+    /// Parses the rest of a list, like `a, b and c`, `item` is called with
+    /// every item.
+    fn list<T>(
+        &mut self,
+        expected: &'static str,
+        mut item: impl FnMut(&'a str) -> Option<T>,
+    ) -> Result<Vec<T>, HumanError> {
+        let mut items = Vec::new();
+        loop {
+            let Some((position, word)) = self.next() else {
+                return Err(self.end(expected));
+            };
+            match item(word) {
+                Some(value) => items.push(value),
+                None => {
+                    return Err(HumanError::new(
+                        position,
+                        HumanErrorKind::Unexpected {
+                            found: word.to_owned(),
+                            expected,
+                        },
+                    ))
+                }
+            }
+            if !self.more() {
+                return Ok(items);
+            }
+        }
+    }
+
+    /// Parses the days after `every`, the first word was already taken.
+    fn weekdays(&mut self, position: usize, first: &'a str) -> Result<u8, HumanError> {
+        const EXPECTED: &str = "`day`, `weekday`, `weekend` or days of the week";
+        let mut words = vec![(position, first)];
+        while self.more() {
+            match self.next() {
+                Some(word) => words.push(word),
+                None => return Err(self.end(EXPECTED)),
+            }
+        }
+        let mut bits = 0;
+        for (position, word) in words {
+            bits |= match word.to_lowercase().as_str() {
+                "day" => 0b111_1111,
+                "weekday" | "weekdays" => 0b001_1111,
+                "weekend" | "weekends" => 0b110_0000,
+                name => match weekday(name) {
+                    Some(day) => 1 << day,
+                    None => {
+                        return Err(HumanError::new(
+                            position,
+                            HumanErrorKind::Unexpected {
+                                found: word.to_owned(),
+                                expected: EXPECTED,
+                            },
+                        ))
+                    }
+                },
+            };
+        }
+        Ok(bits)
+    }
+
+    /// Parses times of day, like `9am and 17:30`.
+    fn times(&mut self) -> Result<Vec<NaiveTime>, HumanError> {
+        let mut times = Vec::new();
+        loop {
+            let Some((position, word)) = self.next() else {
+                return Err(self.end(EXPECTED_TIME));
+            };
+            // `9 am`
+            let suffix = match self.peek() {
+                Some((_, next)) if matches!(next.to_lowercase().as_str(), "am" | "pm") => {
+                    self.next().map(|(_, next)| next)
+                }
+                _ => None,
+            };
+            let time = parse_time(word, suffix).ok_or_else(|| {
+                HumanError::new(
+                    position,
+                    HumanErrorKind::Unexpected {
+                        found: word.to_owned(),
+                        expected: EXPECTED_TIME,
+                    },
+                )
+            })?;
+            times.push(time);
+            if !self.more() {
+                break;
+            }
+        }
+        times.sort_unstable();
+        times.dedup();
+        Ok(times)
+    }
+
+    /// Parses minutes past the hour, like `:15 and :45`.
+    fn offsets(&mut self) -> Result<Vec<u32>, HumanError> {
+        let mut offsets = self.list(EXPECTED_OFFSET, parse_offset)?;
+        offsets.sort_unstable();
+        offsets.dedup();
+        Ok(offsets)
+    }
+
+    /// Returns whether the list goes on, skips the `and` if there is one.
+    fn more(&mut self) -> bool {
+        self.next_if("and")
+            || matches!(self.peek(), Some((position, _)) if self.text[..position].trim_end().ends_with(','))
+    }
+}
+
+const EXPECTED_TIME: &str = "a time like 09:00 or 9am";
+const EXPECTED_OFFSET: &str = "minutes past the hour like :15";
+
+/// Returns the day of the week named `name`, Monday is 0.
+fn weekday(name: &str) -> Option<u32> {
+    const NAMES: [&[&str]; 7] = [
+        &["mon", "monday", "mondays"],
+        &["tue", "tues", "tuesday", "tuesdays"],
+        &["wed", "weds", "wednesday", "wednesdays"],
+        &["thu", "thur", "thurs", "thursday", "thursdays"],
+        &["fri", "friday", "fridays"],
+        &["sat", "saturday", "saturdays"],
+        &["sun", "sunday", "sundays"],
+    ];
+    NAMES
+        .iter()
+        .position(|names| names.contains(&name))
+        .map(|day| day as u32)
+}
+
+/// Parses `17:30`, `17:30:15`, `5pm`, `5:30pm`, `noon` or `midnight`, `suffix`
+/// is a separate `am` or `pm`.
+fn parse_time(word: &str, suffix: Option<&str>) -> Option<NaiveTime> {
+    let word = word.to_lowercase();
+    match word.as_str() {
+        "noon" if suffix.is_none() => return NaiveTime::from_hms_opt(12, 0, 0),
+        "midnight" if suffix.is_none() => return Some(NaiveTime::MIN),
+        _ => {}
+    }
+    let (clock, pm) = match suffix.map(str::to_lowercase).as_deref() {
+        Some("am") => (word.as_str(), Some(false)),
+        Some("pm") => (word.as_str(), Some(true)),
+        _ => match (word.strip_suffix("am"), word.strip_suffix("pm")) {
+            (Some(clock), _) => (clock, Some(false)),
+            (_, Some(clock)) => (clock, Some(true)),
+            _ => (word.as_str(), None),
+        },
+    };
+    let mut parts = clock.split(':');
+    let mut number = |required: bool| match parts.next() {
+        Some(part)
+            if !part.is_empty()
+                && part.len() <= 2
+                && part.bytes().all(|byte| byte.is_ascii_digit()) =>
+        {
+            part.parse::<u32>().ok()
+        }
+        None if !required => Some(0),
+        _ => None,
+    };
+    let hour = number(true)?;
+    // a bare number is only a time with `am` or `pm`
+    let minute = number(pm.is_none())?;
+    let second = number(false)?;
+    if parts.next().is_some() {
+        return None;
+    }
+    let hour = match pm {
+        Some(pm) if (1..=12).contains(&hour) => hour % 12 + if pm { 12 } else { 0 },
+        Some(_) => return None,
+        None => hour,
+    };
+    NaiveTime::from_hms_opt(hour, minute, second)
+}
+
+/// Parses minutes past the hour, `:15`, `:15:30` or `15`, returns the offset
+/// in seconds.
+fn parse_offset(word: &str) -> Option<u32> {
+    let word = word.strip_prefix(':').unwrap_or(word);
+    let (minute, second) = word.split_once(':').unwrap_or((word, "0"));
+    let parse = |part: &str| {
+        Some(part)
+            .filter(|part| !part.is_empty() && part.len() <= 2)
+            .and_then(|part| part.parse::<u32>().ok())
+            .filter(|&value| value < 60)
+    };
+    Some(parse(minute)? * 60 + parse(second)?)
+}
+
+/// An error returned when parsing a duration or an [`Every`] phrase fails.
+///
+/// *This type requires the following crate features to be activated: `human`*
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HumanError {
+    position: usize,
+    kind: HumanErrorKind,
+}
+
+/// The reason a duration or an [`Every`] phrase failed to parse.
+///
+/// *This type requires the following crate features to be activated: `human`*
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum HumanErrorKind {
+    /// The text is empty.
+    Empty,
+    /// A number is not followed by a unit.
+    MissingUnit,
+    /// The unit is not one of `ns`, `us`, `ms`, `s`, `m`, `h`, `d` and `w` or
+    /// one of their longer names.
+    UnknownUnit(String),
+    /// The duration doesn't fit into a [`Duration`].
+    Overflow,
+    /// The period of an `every` phrase is zero.
+    ZeroPeriod,
+    /// The word is not what was expected.
+    Unexpected {
+        /// The word as written.
+        found: String,
+        /// A description of what was expected instead.
+        expected: &'static str,
+    },
+    /// The text ends where more was expected.
+    UnexpectedEnd {
+        /// A description of what was expected.
+        expected: &'static str,
+    },
+}
+
+impl HumanError {
+    fn new(position: usize, kind: HumanErrorKind) -> HumanError {
+        HumanError { position, kind }
+    }
+
+    /// Returns the byte offset into the text at which the error was found.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the reason the text failed to parse.
+    pub fn kind(&self) -> &HumanErrorKind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for HumanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid schedule at position {}: ", self.position)?;
+        match &self.kind {
+            HumanErrorKind::Empty => f.write_str("empty"),
+            HumanErrorKind::MissingUnit => {
+                f.write_str("expected a unit like `ms`, `s`, `m` or `h` after the number")
+            }
+            HumanErrorKind::UnknownUnit(unit) => write!(
+                f,
+                "unknown unit `{}`, expected one of `ns`, `us`, `ms`, `s`, `m`, `h`, `d` and `w`",
+                unit
+            ),
+            HumanErrorKind::Overflow => f.write_str("the duration is too long"),
+            HumanErrorKind::ZeroPeriod => f.write_str("the period must not be zero"),
+            HumanErrorKind::Unexpected { found, expected } => {
+                write!(f, "expected {}, found `{}`", expected, found)
+            }
+            HumanErrorKind::UnexpectedEnd { expected } => {
+                write!(f, "expected {}, found the end", expected)
+            }
+        }
+    }
+}
+
+impl std::error::Error for HumanError {}
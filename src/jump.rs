@@ -0,0 +1,85 @@
+//! Detects discontinuities of the wall clock.
+//!
+//! Calendar timers wait for a wall time but sleep on a monotonic clock. When
+//! the wall clock is stepped (by NTP or an admin) or the machine is suspended,
+//! the two clocks disagree. [`Detector`] notices by comparing how far both of
+//! them advanced between two checks.
+
+use chrono::{DateTime, Utc};
+use std::time::{Duration, Instant};
+
+/// The longest a calendar timer sleeps without checking the wall clock.
+pub(crate) const CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Differences between the clocks up to this much are not reported, reading
+/// both clocks is not atomic.
+const TOLERANCE: Duration = Duration::from_secs(1);
+
+/// A discontinuity of the wall clock, detected by a calendar timer.
+///
+/// The wall clock jumps forward when it is stepped ahead or when the machine
+/// wakes up from suspend, and backward when it is stepped back.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::Timer;
+///
+/// Timer::every_hour()
+///     .on_clock_jump(|jump| {
+///         println!("the clock jumped by {}s", jump.offset().num_seconds());
+///     })
+///     .run(|_| println!("Hi"));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClockJump {
+    offset: chrono::Duration,
+    time: DateTime<Utc>,
+}
+
+impl ClockJump {
+    /// Returns how far the wall clock jumped, positive if it jumped forward.
+    pub fn offset(&self) -> chrono::Duration {
+        self.offset
+    }
+
+    /// Returns the wall time right after the jump.
+    pub fn time(&self) -> DateTime<Utc> {
+        self.time
+    }
+
+    /// Returns `true` if the wall clock jumped forward.
+    pub fn is_forward(&self) -> bool {
+        self.offset > chrono::Duration::zero()
+    }
+
+    /// Returns `true` if the wall clock jumped backward.
+    pub fn is_backward(&self) -> bool {
+        self.offset < chrono::Duration::zero()
+    }
+}
+
+/// Compares the wall clock with the monotonic clock.
+#[derive(Debug)]
+pub(crate) struct Detector {
+    instant: Instant,
+    time: DateTime<Utc>,
+}
+
+impl Detector {
+    pub(crate) fn new(instant: Instant, time: DateTime<Utc>) -> Detector {
+        Detector { instant, time }
+    }
+
+    /// Returns the jump of the wall clock since the last check, if any, given
+    /// the current readings of both clocks.
+    pub(crate) fn check(&mut self, instant: Instant, time: DateTime<Utc>) -> Option<ClockJump> {
+        let elapsed =
+            chrono::Duration::from_std(instant.saturating_duration_since(self.instant)).ok()?;
+        let offset = time - (self.time + elapsed);
+        self.instant = instant;
+        self.time = time;
+        let tolerance = chrono::Duration::from_std(TOLERANCE).ok()?;
+        (offset.abs() > tolerance).then_some(ClockJump { offset, time })
+    }
+}
@@ -26,6 +26,87 @@
 //! going with `full` to ensure that you don't run into any road blocks while you're
 //! building your application.
 //!
+//! ### Calendar schedules
+//!
+//! Besides the fixed second, minute, hour, day, week, month, quarter and year
+//! boundaries, jobs can follow a [`Cron`] expression, like `30 9 * * MON-FRI`,
+//! with the `cron` feature, or an iCalendar [`Recurrence`] rule, like
+//! `FREQ=MONTHLY;BYDAY=2TU`, with the `rrule` feature, or a systemd
+//! [`OnCalendar`] event, like `Mon..Fri *-*-* 09:00:00`, with the `systemd`
+//! feature. [`Hourly`] and [`Daily`] fire at offsets into the hour or the day,
+//! like every hour at `:15` or every day at 02:30. With the `human` feature,
+//! schedules can be written as English phrases, like `every 15 minutes` or
+//! `every monday at 9am`, see [`Every`], and durations like `1h30m` parsed
+//! with [`parse_duration`], so they can be kept in configuration files.
+//! Every schedule implements the [`Schedule`] trait, which can list the
+//! upcoming fire times and can be implemented for schedules of your own, then
+//! [`run`] calls a function every time any schedule fires.
+//! [`Schedule::describe`] tells what a schedule does in plain English and
+//! [`describe_next_run`] when it fires next, for logs and admin pages.
+//!
+//! ### Fine tuning
+//!
+//! The free functions pick sensible defaults. A [`Timer`] starts the same
+//! timers but can be configured first, for example to run an interval at a
+//! fixed rate instead of waiting the interval after every call, to choose
+//! what happens to the ticks a slow callback missed with a
+//! [`MissedTickBehavior`], to let a slow async callback overlap the next
+//! ticks with an [`OverlapPolicy`], or to give every call a deadline with
+//! [`Timer::execution_timeout`]. Tests can drive a [`Timer`] with a
+//! [`ManualClock`] instead of waiting for it, see [`Clock`].
+//!
+//! ### Streams
+//!
+//! With the `ticks` feature, the [`ticks`] module yields the ticks of a
+//! schedule as a `Stream` instead of calling a function, like
+//! [`ticks::every_hour`] or [`ticks::interval`], and [`Timer::stream`] turns
+//! any timer into one. Async code can then await them in a `tokio::select!`
+//! or combine them with stream combinators inside its own event loop.
+//!
+//! ### Time zones
+//!
+//! Calendar boundaries follow the local time zone of the machine. Every
+//! `duration_to_next_*` and `call_every_*` function has an `_in` variant, like
+//! [`call_every_day_in`], that takes any [`chrono::TimeZone`] instead. Enable
+//! the `chrono-tz` feature to use IANA time zones like `Europe/Berlin` through
+//! the re-exported `chrono_tz` crate.
+//!
+//! ### Many jobs
+//!
+//! Every free function takes over the thread or the task that calls it. The
+//! `spawn_` variants, like [`spawn_call_every_hour`], [`spawn_set_timeout`]
+//! or [`spawn_set_interval`], run the timer on a background thread named after
+//! it and return right away. Applications with many periodic jobs can run all
+//! of them on a single thread with a [`Scheduler`], enabled with the
+//! `scheduler` feature.
+//!
+//! ### Cancellation
+//!
+//! The synchronous functions block the calling thread, the asynchronous ones
+//! run on the calling task. A [`Timer`] started with [`Timer::run`] can be
+//! stopped from another thread with the [`Canceller`] returned by
+//! [`Timer::canceller`], and async timers stop when their future is dropped.
+//! The `spawn_` functions and [`Timer::spawn`] start a timer on a background
+//! thread and return a [`TimerHandle`], [`Timer::spawn_async`] starts it on
+//! the tokio runtime and returns a [`TaskHandle`], both can be used to cancel
+//! the timer or to wait for it to finish, much like `clearTimeout` and
+//! `clearInterval` in JavaScript.
+//!
+//! A callback can also stop its own timer by returning
+//! [`std::ops::ControlFlow::Break`], the value is returned by the function
+//! that runs the timer, see [`Flow`]. This fits polling until something is
+//! ready or retrying until an operation succeeds.
+//!
+//! Callbacks that can fail go to the `try_` functions, like
+//! [`try_set_interval`] or [`try_call_every_hour`], they return a `Result` and
+//! an [`ErrorPolicy`] decides whether an error is logged, passed to a hook or
+//! stops the timer and is returned.
+//!
+//! A panicking callback unwinds its timer by default. A [`PanicPolicy`] set
+//! with [`Timer::panic_policy`] or [`Scheduler::panic_policy`] catches the
+//! panic instead and restarts or stops the job, after passing the panic to a
+//! hook, so a bug in one job doesn't silently take down the others.
+//!
 //! #### Example
 //!
 //! A basic logger with zila.
@@ -36,116 +117,369 @@
 //! zila = { version = "0.1.8", features = ["second"] }
 //! ```
 //! on your main.rs:
-//! ```rust
+//! ```rust,no_run
+//! # #[cfg(feature = "second")]
 //! use zila::call_every_second;
 //!
+//! # #[cfg(feature = "second")]
 //! fn main() {
 //!     call_every_second(|| {
 //!         println!("Hi");
 //!     });
 //! }
+//! # #[cfg(not(feature = "second"))]
+//! # fn main() {}
 //! ```
 //!
 //! More examples can be found [here](https://github.com/a-rustacean/zila/tree/master/examples)
 
-use chrono::{Local, Timelike};
+#[cfg(feature = "timers")]
+mod handle;
+
+#[cfg(feature = "timers")]
+pub use handle::{Canceller, JoinError, TaskHandle, TimerHandle};
+
+#[cfg(any(feature = "timers", feature = "scheduler"))]
+mod policy;
+
+#[cfg(feature = "timers")]
+pub use policy::ErrorPolicy;
+
+#[cfg(any(feature = "timers", feature = "scheduler"))]
+pub use policy::{CaughtPanic, PanicPolicy};
+
+#[cfg(feature = "timers")]
+#[allow(dead_code)] // only used by the features that enable `timers`
+mod calendar;
+
+#[cfg(feature = "cron")]
+mod cron;
+
+#[cfg(feature = "cron")]
+pub use cron::{
+    call_every_cron, call_every_cron_async, call_every_cron_async_mut, call_every_cron_in,
+    call_every_cron_in_async, call_every_cron_in_async_mut, call_every_cron_in_mut,
+    call_every_cron_mut, duration_to_next_cron, duration_to_next_cron_in, spawn_call_every_cron,
+    spawn_call_every_cron_in, try_call_every_cron, try_call_every_cron_async,
+    try_call_every_cron_in, try_call_every_cron_in_async, Cron, CronError, CronErrorKind,
+};
+
+#[cfg(feature = "rrule")]
+mod rrule;
+
+#[cfg(feature = "rrule")]
+pub use rrule::{
+    call_every_recurrence, call_every_recurrence_async, call_every_recurrence_async_mut,
+    call_every_recurrence_in, call_every_recurrence_in_async, call_every_recurrence_in_async_mut,
+    call_every_recurrence_in_mut, call_every_recurrence_mut, duration_to_next_recurrence,
+    duration_to_next_recurrence_in, spawn_call_every_recurrence, spawn_call_every_recurrence_in,
+    try_call_every_recurrence, try_call_every_recurrence_async, try_call_every_recurrence_in,
+    try_call_every_recurrence_in_async, Recurrence, RecurrenceError, RecurrenceErrorKind,
+};
+
+#[cfg(feature = "systemd")]
+mod on_calendar;
+
+#[cfg(feature = "systemd")]
+pub use on_calendar::{OnCalendar, OnCalendarError, OnCalendarErrorKind};
+
+#[cfg(feature = "human")]
+mod human;
+
+#[cfg(feature = "human")]
+pub use human::{parse_duration, Every, HumanError, HumanErrorKind};
+
+#[cfg(feature = "chrono-tz")]
+pub use chrono_tz;
+
+#[cfg(any(feature = "timers", feature = "scheduler"))]
+mod jump;
+
+#[cfg(any(feature = "timers", feature = "scheduler"))]
+pub use jump::ClockJump;
+
+#[cfg(any(feature = "timers", feature = "scheduler"))]
+mod clock;
+
+#[cfg(any(feature = "timers", feature = "scheduler"))]
+pub use clock::{Clock, ManualClock, Sleep, SystemClock};
+
+#[cfg(feature = "timers")]
+mod describe;
+
+#[cfg(feature = "timers")]
+mod schedule;
+
+#[cfg(feature = "timers")]
+pub use schedule::{
+    describe_next_run, describe_next_run_in, duration_to_next, duration_to_next_in,
+    format_duration, run, run_async, run_async_mut, run_in, run_in_async, run_in_async_mut,
+    run_in_mut, run_mut, Once, Schedule, Upcoming,
+};
+
+#[cfg(feature = "day")]
+pub use schedule::Daily;
+
+#[cfg(feature = "hour")]
+pub use schedule::Hourly;
+
+#[cfg(any(feature = "day", feature = "hour"))]
+pub use schedule::OffsetError;
+
+#[cfg(feature = "day")]
+pub use schedule::EveryDay;
+
+#[cfg(feature = "hour")]
+pub use schedule::EveryHour;
+
+#[cfg(feature = "minute")]
+pub use schedule::EveryMinute;
+
+#[cfg(feature = "second")]
+pub use schedule::EverySecond;
+
+#[cfg(feature = "week")]
+pub use schedule::EveryWeek;
+
+#[cfg(feature = "month")]
+pub use schedule::EveryMonth;
+
+#[cfg(feature = "quarter")]
+pub use schedule::EveryQuarter;
+
+#[cfg(feature = "year")]
+pub use schedule::EveryYear;
+
+#[cfg(feature = "interval")]
+pub use schedule::{Aligned, Interval};
+
+#[cfg(feature = "timers")]
+mod timer;
+
+#[cfg(feature = "timers")]
+pub use timer::{
+    CancellationToken, Flow, MissedTickBehavior, OverlapPolicy, Tick, TimedOut, Timer,
+};
+
+#[cfg(all(feature = "ticks", feature = "timers"))]
+pub use timer::Ticks;
+
+#[cfg(all(
+    feature = "ticks",
+    any(
+        feature = "day",
+        feature = "hour",
+        feature = "interval",
+        feature = "cron"
+    )
+))]
+pub mod ticks;
+
+#[cfg(feature = "scheduler")]
+mod scheduler;
+
+#[cfg(feature = "scheduler")]
+pub use scheduler::{JobId, Scheduler};
+
+#[cfg(feature = "day")]
+use chrono::DateTime;
+#[cfg(feature = "interval")]
+use chrono::Utc;
+#[cfg(feature = "week")]
+use chrono::Weekday;
+#[cfg(any(
+    feature = "day",
+    feature = "hour",
+    feature = "minute",
+    feature = "second",
+    feature = "week",
+    feature = "month",
+    feature = "quarter",
+    feature = "year"
+))]
+use chrono::{Local, TimeZone};
+#[cfg(any(
+    feature = "day",
+    feature = "hour",
+    feature = "minute",
+    feature = "second",
+    feature = "week",
+    feature = "month",
+    feature = "quarter",
+    feature = "year",
+    feature = "timeout",
+    feature = "interval"
+))]
+use std::fmt;
+#[cfg(any(
+    feature = "day",
+    feature = "hour",
+    feature = "minute",
+    feature = "second",
+    feature = "week",
+    feature = "month",
+    feature = "quarter",
+    feature = "year",
+    feature = "timeout",
+    feature = "interval"
+))]
 use std::future::Future;
-use tokio::time::{sleep, Duration};
+#[cfg(any(
+    feature = "day",
+    feature = "hour",
+    feature = "minute",
+    feature = "second",
+    feature = "week",
+    feature = "month",
+    feature = "quarter",
+    feature = "year",
+    feature = "timeout",
+    feature = "interval"
+))]
+use tokio::time::Duration;
 
-/// Returns the duration to next day (00:00:00.000.000.000)
+/// Returns the first instant of the local day after the day of `time`
+///
+/// Days are not always 24 hours long, the day on which the clocks spring forward
+/// lasts 23 hours and the one on which they fall back lasts 25 hours. This
+/// function goes through the time zone of `time`, so it always lands on the
+/// next local midnight. In the rare time zones that switch to daylight saving
+/// time at midnight, a day has no midnight at all and its first instant, like
+/// 01:00:00, is returned instead.
 ///
 /// # Example
 ///
 /// ```rust
+/// use chrono::{FixedOffset, TimeZone};
+/// use zila::start_of_next_day;
+///
+/// let tz = FixedOffset::east_opt(2 * 60 * 60).unwrap();
+/// let time = tz.with_ymd_and_hms(2023, 5, 9, 13, 45, 0).unwrap();
+/// let next = tz.with_ymd_and_hms(2023, 5, 10, 0, 0, 0).unwrap();
+/// assert_eq!(start_of_next_day(&time), next);
+/// ```
+///
+/// *This function requires the following crate features to be activated: `day`*
+#[cfg(feature = "day")]
+pub fn start_of_next_day<Tz: TimeZone>(time: &DateTime<Tz>) -> DateTime<Tz> {
+    EveryDay.next_after(time).expect("date out of range")
+}
+
+/// Returns the duration to next day (00:00:00.000.000.000)
+///
+/// The duration follows the local time zone, on the days the clocks change it
+/// is an hour shorter or longer than usual, see [`start_of_next_day`].
+///
+/// # Example
+///
+/// ```rust,no_run
 /// use zila::duration_to_next_day;
-/// use chrono::{Local, TimeLike};
+/// use chrono::{Local, Timelike};
 ///
 /// let duration = duration_to_next_day();
 /// std::thread::sleep(duration);
-/// let time = Local::new().time();
+/// let time = Local::now().time();
 /// println!("{}h {}m {}s", time.hour(), time.minute(), time.second()); // 0h 0m 0s
 /// ```
 ///
 /// *This function requires the following crate features to be activated: `day`*
 #[cfg(feature = "day")]
 pub fn duration_to_next_day() -> Duration {
-    let time = Local::now();
-    if time.hour() == 0
-        && time.minute() == 0
-        && time.second() == 0
-        && time.timestamp_subsec_nanos() == 0
-    {
-        Duration::ZERO
-    } else {
-        Duration::from_nanos(
-            (24 * 60 * 60 * 1000 * 1000 * 1000)
-                - (time.hour() as u64 * 60 * 60 * 1000 * 1000 * 1000
-                    + time.minute() as u64 * 60 * 1000 * 1000 * 1000
-                    + time.second() as u64 * 1000 * 1000 * 1000
-                    + time.timestamp_subsec_nanos() as u64),
-        )
-    }
+    duration_to_next_day_in(&Local)
 }
 
-/// Returns the duration to next hour (\_\_:00:00.000.000.000)
+/// Returns the duration to next day (00:00:00.000.000.000) in the given time zone
 ///
 /// # Example
 ///
 /// ```rust
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::duration_to_next_day_in;
+///
+/// let duration = duration_to_next_day_in(&Berlin);
+/// assert!(duration.as_secs() <= 25 * 60 * 60);
+/// ```
+///
+/// *This function requires the following crate features to be activated: `day`*
+#[cfg(feature = "day")]
+pub fn duration_to_next_day_in<Tz: TimeZone>(tz: &Tz) -> Duration {
+    duration_to_next_in(&EveryDay, tz).expect("date out of range")
+}
+
+/// Returns the duration to next hour (\_\_:00:00.000.000.000)
+///
+/// # Example
+///
+/// ```rust,no_run
 /// use zila::duration_to_next_hour;
-/// use chrono::{Local, TimeLike};
+/// use chrono::{Local, Timelike};
 ///
 /// let duration = duration_to_next_hour();
-/// std::thred::sleep(duration).await;
-/// let time = Local::new().time();
+/// std::thread::sleep(duration);
+/// let time = Local::now().time();
 /// println!("{}m {}s", time.minute(), time.second()); // 0m 0s
 /// ```
 ///
 /// *This function requires the following crate features to be activated: `hour`*
 #[cfg(feature = "hour")]
 pub fn duration_to_next_hour() -> Duration {
-    let time = Local::now();
-
-    if time.minute() == 0 && time.second() == 0 && time.timestamp_subsec_nanos() == 0 {
-        Duration::ZERO
-    } else {
-        Duration::from_nanos(
-            (60 * 60 * 1000 * 1000 * 1000)
-                - (time.minute() as u64 * 60 * 1000 * 1000 * 1000
-                    + time.second() as u64 * 1000 * 1000 * 1000
-                    + time.timestamp_subsec_nanos() as u64),
-        )
-    }
+    duration_to_next_hour_in(&Local)
 }
 
-/// Returns the duration to next minute (\_\_:\_\_:00.000.000.000)
+/// Returns the duration to next hour (\_\_:00:00.000.000.000) in the given time zone
 ///
 /// # Example
 ///
 /// ```rust
+/// use zila::chrono_tz::Asia::Kolkata;
+/// use zila::duration_to_next_hour_in;
+///
+/// let duration = duration_to_next_hour_in(&Kolkata);
+/// assert!(duration.as_secs() <= 60 * 60);
+/// ```
+///
+/// *This function requires the following crate features to be activated: `hour`*
+#[cfg(feature = "hour")]
+pub fn duration_to_next_hour_in<Tz: TimeZone>(tz: &Tz) -> Duration {
+    duration_to_next_in(&EveryHour, tz).expect("date out of range")
+}
+
+/// Returns the duration to next minute (\_\_:\_\_:00.000.000.000)
+///
+/// # Example
+///
+/// ```rust,no_run
 /// use zila::duration_to_next_minute;
-/// use chrono::{Local, TimeLike};
+/// use chrono::{Local, Timelike};
 ///
 /// let duration = duration_to_next_minute();
-/// sleep(duration).await;
-/// let time = Local::new().time();
+/// std::thread::sleep(duration);
+/// let time = Local::now().time();
 /// println!("{}s", time.second()); // 0s
 /// ```
 ///
 /// *This function requires the following crate features to be activated: `minute`*
 #[cfg(feature = "minute")]
 pub fn duration_to_next_minute() -> Duration {
-    let time = Local::now();
-
-    if time.second() == 0 && time.timestamp_subsec_nanos() == 0 {
-        Duration::ZERO
-    } else {
-        Duration::from_nanos(
-            (60 * 1000 * 1000 * 1000)
-                - (time.second() as u64 * 1000 * 1000 * 1000
-                    + time.timestamp_subsec_nanos() as u64),
-        )
-    }
+    duration_to_next_minute_in(&Local)
+}
+
+/// Returns the duration to next minute (\_\_:\_\_:00.000.000.000) in the given time zone
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::Utc;
+/// use zila::duration_to_next_minute_in;
+///
+/// let duration = duration_to_next_minute_in(&Utc);
+/// assert!(duration.as_secs() <= 60);
+/// ```
+///
+/// *This function requires the following crate features to be activated: `minute`*
+#[cfg(feature = "minute")]
+pub fn duration_to_next_minute_in<Tz: TimeZone>(tz: &Tz) -> Duration {
+    duration_to_next_in(&EveryMinute, tz).expect("date out of range")
 }
 
 /// Returns the duration to next second (\_\_:\_\_:\_\_.000.000.000)
@@ -154,24 +488,190 @@ pub fn duration_to_next_minute() -> Duration {
 ///
 /// ```rust
 /// use zila::duration_to_next_second;
-/// use chrono::{Local, TimeLike};
+/// use chrono::Local;
 ///
 /// let duration = duration_to_next_second();
-/// sleep(duration).await;
-/// let time = Local::new();
+/// std::thread::sleep(duration);
+/// let time = Local::now();
 /// println!("{}ms", time.timestamp_subsec_millis()); // 0ms
 /// ```
 ///
 /// *This function requires the following crate features to be activated: `second`*
 #[cfg(feature = "second")]
 pub fn duration_to_next_second() -> Duration {
-    let time = Local::now();
+    duration_to_next_second_in(&Local)
+}
+
+/// Returns the duration to next second (\_\_:\_\_:\_\_.000.000.000) in the given time zone
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::Utc;
+/// use zila::duration_to_next_second_in;
+///
+/// let duration = duration_to_next_second_in(&Utc);
+/// assert!(duration.as_secs() <= 1);
+/// ```
+///
+/// *This function requires the following crate features to be activated: `second`*
+#[cfg(feature = "second")]
+pub fn duration_to_next_second_in<Tz: TimeZone>(tz: &Tz) -> Duration {
+    duration_to_next_in(&EverySecond, tz).expect("date out of range")
+}
+
+/// Returns the duration to the next week, at midnight on `start`
+///
+/// The duration follows the local time zone and the length of the week, see
+/// [`EveryWeek`].
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::Weekday;
+/// use zila::duration_to_next_week;
+///
+/// let duration = duration_to_next_week(Weekday::Mon);
+/// assert!(duration.as_secs() <= (7 * 24 + 1) * 60 * 60);
+/// ```
+///
+/// *This function requires the following crate features to be activated: `week`*
+#[cfg(feature = "week")]
+pub fn duration_to_next_week(start: Weekday) -> Duration {
+    duration_to_next_week_in(start, &Local)
+}
+
+/// Returns the duration to the next week in the given time zone
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::Weekday;
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::duration_to_next_week_in;
+///
+/// let duration = duration_to_next_week_in(Weekday::Mon, &Berlin);
+/// assert!(duration.as_secs() <= (7 * 24 + 1) * 60 * 60);
+/// ```
+///
+/// *This function requires the following crate features to be activated: `week`*
+#[cfg(feature = "week")]
+pub fn duration_to_next_week_in<Tz: TimeZone>(start: Weekday, tz: &Tz) -> Duration {
+    duration_to_next_in(&EveryWeek::starting(start), tz).expect("date out of range")
+}
+
+/// Returns the duration to the next month, at midnight on the first day of the month
+///
+/// The duration follows the local time zone and the length of the month, see
+/// [`EveryMonth`].
+///
+/// # Example
+///
+/// ```rust
+/// use zila::duration_to_next_month;
+///
+/// let duration = duration_to_next_month();
+/// assert!(duration.as_secs() <= (31 * 24 + 1) * 60 * 60);
+/// ```
+///
+/// *This function requires the following crate features to be activated: `month`*
+#[cfg(feature = "month")]
+pub fn duration_to_next_month() -> Duration {
+    duration_to_next_month_in(&Local)
+}
+
+/// Returns the duration to the next month in the given time zone
+///
+/// # Example
+///
+/// ```rust
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::duration_to_next_month_in;
+///
+/// let duration = duration_to_next_month_in(&Berlin);
+/// assert!(duration.as_secs() <= (31 * 24 + 1) * 60 * 60);
+/// ```
+///
+/// *This function requires the following crate features to be activated: `month`*
+#[cfg(feature = "month")]
+pub fn duration_to_next_month_in<Tz: TimeZone>(tz: &Tz) -> Duration {
+    duration_to_next_in(&EveryMonth, tz).expect("date out of range")
+}
+
+/// Returns the duration to the next quarter, at midnight on the first day of January, April, July or October
+///
+/// The duration follows the local time zone and the length of the quarter, see
+/// [`EveryQuarter`].
+///
+/// # Example
+///
+/// ```rust
+/// use zila::duration_to_next_quarter;
+///
+/// let duration = duration_to_next_quarter();
+/// assert!(duration.as_secs() <= (92 * 24 + 1) * 60 * 60);
+/// ```
+///
+/// *This function requires the following crate features to be activated: `quarter`*
+#[cfg(feature = "quarter")]
+pub fn duration_to_next_quarter() -> Duration {
+    duration_to_next_quarter_in(&Local)
+}
+
+/// Returns the duration to the next quarter in the given time zone
+///
+/// # Example
+///
+/// ```rust
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::duration_to_next_quarter_in;
+///
+/// let duration = duration_to_next_quarter_in(&Berlin);
+/// assert!(duration.as_secs() <= (92 * 24 + 1) * 60 * 60);
+/// ```
+///
+/// *This function requires the following crate features to be activated: `quarter`*
+#[cfg(feature = "quarter")]
+pub fn duration_to_next_quarter_in<Tz: TimeZone>(tz: &Tz) -> Duration {
+    duration_to_next_in(&EveryQuarter, tz).expect("date out of range")
+}
+
+/// Returns the duration to the next year, at midnight on the first of January
+///
+/// The duration follows the local time zone and the length of the year, see
+/// [`EveryYear`].
+///
+/// # Example
+///
+/// ```rust
+/// use zila::duration_to_next_year;
+///
+/// let duration = duration_to_next_year();
+/// assert!(duration.as_secs() <= (366 * 24 + 1) * 60 * 60);
+/// ```
+///
+/// *This function requires the following crate features to be activated: `year`*
+#[cfg(feature = "year")]
+pub fn duration_to_next_year() -> Duration {
+    duration_to_next_year_in(&Local)
+}
 
-    if time.timestamp_subsec_nanos() == 0 {
-        Duration::ZERO
-    } else {
-        Duration::from_nanos(1000 * 1000 * 1000 - time.timestamp_subsec_nanos() as u64)
-    }
+/// Returns the duration to the next year in the given time zone
+///
+/// # Example
+///
+/// ```rust
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::duration_to_next_year_in;
+///
+/// let duration = duration_to_next_year_in(&Berlin);
+/// assert!(duration.as_secs() <= (366 * 24 + 1) * 60 * 60);
+/// ```
+///
+/// *This function requires the following crate features to be activated: `year`*
+#[cfg(feature = "year")]
+pub fn duration_to_next_year_in<Tz: TimeZone>(tz: &Tz) -> Duration {
+    duration_to_next_in(&EveryYear, tz).expect("date out of range")
 }
 
 /// calls the given function every day
@@ -180,7 +680,7 @@ pub fn duration_to_next_second() -> Duration {
 ///
 /// using a closure:
 ///
-/// ```rust
+/// ```rust,no_run
 /// use zila::call_every_day;
 ///
 /// call_every_day(|| {
@@ -190,7 +690,7 @@ pub fn duration_to_next_second() -> Duration {
 ///
 /// using a function:
 ///
-/// ```rust
+/// ```rust,no_run
 /// use zila::call_every_day;
 ///
 /// fn callback() {
@@ -202,22 +702,20 @@ pub fn duration_to_next_second() -> Duration {
 ///
 /// *This function requires the following crate features to be activated: `day`*
 #[cfg(feature = "day")]
-pub fn call_every_day<F>(callback: F)
+pub fn call_every_day<F, R>(callback: F) -> R::Output
 where
-    F: Fn(),
+    F: Fn() -> R,
+    R: Flow,
 {
-    loop {
-        let duration = duration_to_next_day();
-        std::thread::sleep(duration);
-        callback();
-    }
+    call_every_day_in(Local, callback)
 }
 
 /// calls the given function every day, takes a `FnMut` as the argument
 ///
 /// # Example
 ///
-/// ```rust
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
 /// use zila::call_every_day_mut;
 ///
 /// let num = Rc::new(Cell::new(0));
@@ -231,15 +729,12 @@ where
 ///
 /// *This function requires the following crate features to be activated: `day`*
 #[cfg(feature = "day")]
-pub fn call_every_day_mut<F>(mut callback: F)
+pub fn call_every_day_mut<F, R>(callback: F) -> R::Output
 where
-    F: FnMut(),
+    F: FnMut() -> R,
+    R: Flow,
 {
-    loop {
-        let duration = duration_to_next_day();
-        std::thread::sleep(duration);
-        callback();
-    }
+    call_every_day_in_mut(Local, callback)
 }
 
 /// calls the given async function every day
@@ -248,120 +743,116 @@ where
 ///
 /// using a closure:
 ///
-/// ```rust
+/// ```rust,no_run
 /// use zila::call_every_day_async;
 ///
-/// // async closures are unstable
-/// call_every_day_async(async || {
+/// # #[tokio::main]
+/// # async fn main() {
+/// call_every_day_async(|| async {
 ///     println!("Hi");
-/// }).await;
+/// })
+/// .await;
+/// # }
 /// ```
 ///
 /// using a function:
 ///
-/// ```rust
+/// ```rust,no_run
 /// use zila::call_every_day_async;
 ///
 /// async fn callback() {
 ///     println!("Hi");
 /// }
 ///
+/// # #[tokio::main]
+/// # async fn main() {
 /// call_every_day_async(callback).await;
+/// # }
 /// ```
 ///
 /// *This function requires the following crate features to be activated: `day`*
 #[cfg(feature = "day")]
-pub async fn call_every_day_async<F, Fut>(callback: F)
+pub async fn call_every_day_async<F, Fut, R>(callback: F) -> R::Output
 where
     F: Fn() -> Fut,
-    Fut: Future<Output = ()>,
+    Fut: Future<Output = R>,
+    R: Flow,
 {
-    loop {
-        let duration = duration_to_next_day();
-        sleep(duration).await;
-        callback().await;
-    }
+    call_every_day_in_async(Local, callback).await
 }
 
 /// calls the given async function every day, takes a `FnMut` as the argument
 ///
 /// # Example
 ///
-/// ```rust
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
 /// use zila::call_every_day_async_mut;
 ///
+/// # #[tokio::main]
+/// # async fn main() {
 /// let num = Rc::new(Cell::new(0));
 /// call_every_day_async_mut(move || {
 ///     let num = num.clone();
-///     // async closures are unstable
-///     async move || {
+///     async move {
 ///         num.set(num.get() + 1);
+///         println!("This is printed {} times.", num.get());
 ///     }
-/// }()).await;
+/// })
+/// .await;
+/// # }
 /// ```
 ///
 /// *This function requires the following crate features to be activated: `day`*
 #[cfg(feature = "day")]
-pub async fn call_every_day_async_mut<F, Fut>(mut callback: F)
+pub async fn call_every_day_async_mut<F, Fut, R>(callback: F) -> R::Output
 where
     F: FnMut() -> Fut,
-    Fut: Future<Output = ()>,
+    Fut: Future<Output = R>,
+    R: Flow,
 {
-    loop {
-        let duration = duration_to_next_day();
-        sleep(duration).await;
-        callback().await;
-    }
+    call_every_day_in_async_mut(Local, callback).await
 }
 
-/// calls the given function every hour
+/// calls the given function every day at midnight in the given time zone
 ///
-/// # Example
+/// Like [`call_every_day`], but the days follow `tz` instead of the local time
+/// zone of the machine.
 ///
-/// using a closure:
+/// # Example
 ///
-/// ```rust
-/// use zila::call_every_hour;
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::call_every_day_in;
 ///
-/// call_every_hour(|| {
+/// call_every_day_in(Berlin, || {
 ///     println!("Hi");
 /// });
 /// ```
 ///
-/// using a function:
-///
-/// ```rust
-/// use zila::call_every_hour;
-///
-/// fn callback() {
-///     println!("Hi");
-/// }
-///
-/// call_every_hour(callback);
-/// ```
-///
-/// *This function requires the following crate features to be activated: `hour`*
-#[cfg(feature = "hour")]
-pub fn call_every_hour<F>(callback: F)
+/// *This function requires the following crate features to be activated: `day`*
+#[cfg(feature = "day")]
+pub fn call_every_day_in<Tz, F, R>(tz: Tz, callback: F) -> R::Output
 where
-    F: Fn(),
+    Tz: TimeZone + Send + 'static,
+    F: Fn() -> R,
+    R: Flow,
 {
-    loop {
-        let duration = duration_to_next_hour();
-        std::thread::sleep(duration);
-        callback();
-    }
+    call_every_day_in_mut(tz, callback)
 }
 
-/// calls the given function every hour, takes a `FnMut` as the argument
+/// calls the given function every day at midnight in the given time zone, takes a `FnMut` as
+/// the argument
 ///
 /// # Example
 ///
-/// ```rust
-/// use zila::call_every_hour_mut;
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::call_every_day_in_mut;
 ///
 /// let num = Rc::new(Cell::new(0));
-/// call_every_hour_mut({
+/// call_every_day_in_mut(Berlin, {
 ///     let num = num.clone();
 ///     move || {
 ///         num.set(num.get() + 1);
@@ -369,279 +860,328 @@ where
 /// });
 /// ```
 ///
-/// *This function requires the following crate features to be activated: `hour`*
-#[cfg(feature = "hour")]
-pub fn call_every_hour_mut<F>(mut callback: F)
+/// *This function requires the following crate features to be activated: `day`*
+#[cfg(feature = "day")]
+pub fn call_every_day_in_mut<Tz, F, R>(tz: Tz, mut callback: F) -> R::Output
 where
-    F: FnMut(),
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> R,
+    R: Flow,
 {
-    loop {
-        let duration = duration_to_next_hour();
-        std::thread::sleep(duration);
-        callback();
-    }
+    Timer::every_day_in(tz).run(move |_| callback())
 }
 
-/// calls the given async function every hour
+/// calls the given async function every day at midnight in the given time zone
 ///
-/// # Example
+/// Like [`call_every_day_async`], but the days follow `tz` instead of the local
+/// time zone of the machine.
 ///
-/// using a closure:
+/// # Example
 ///
-/// ```rust
-/// use zila::call_every_hour_async;
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::call_every_day_in_async;
 ///
-/// // async closures are unstable
-/// call_every_hour_async(async || {
+/// # #[tokio::main]
+/// # async fn main() {
+/// call_every_day_in_async(Berlin, || async {
 ///     println!("Hi");
-/// }).await;
+/// })
+/// .await;
+/// # }
 /// ```
 ///
-/// using a function:
-///
-/// ```rust
-/// use zila::call_every_hour_async;
-///
-/// async fn callback() {
-///     println!("Hi");
-/// }
-///
-/// call_every_hour_async(callback).await;
-/// ```
-///
-/// *This function requires the following crate features to be activated: `hour`*
-#[cfg(feature = "hour")]
-pub async fn call_every_hour_async<F, Fut>(callback: F)
+/// *This function requires the following crate features to be activated: `day`*
+#[cfg(feature = "day")]
+pub async fn call_every_day_in_async<Tz, F, Fut, R>(tz: Tz, callback: F) -> R::Output
 where
+    Tz: TimeZone + Send + 'static,
     F: Fn() -> Fut,
-    Fut: Future<Output = ()>,
+    Fut: Future<Output = R>,
+    R: Flow,
 {
-    loop {
-        let duration = duration_to_next_hour();
-        sleep(duration).await;
-        callback().await;
-    }
+    call_every_day_in_async_mut(tz, callback).await
 }
 
-/// calls the given async function every hour, takes a `FnMut` as the argument
+/// calls the given async function every day at midnight in the given time zone, takes a
+/// `FnMut` as the argument
 ///
 /// # Example
 ///
-/// ```rust
-/// use zila::call_every_hour_async_mut;
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::call_every_day_in_async_mut;
 ///
+/// # #[tokio::main]
+/// # async fn main() {
 /// let num = Rc::new(Cell::new(0));
-/// call_every_hour_async_mut(move || {
+/// call_every_day_in_async_mut(Berlin, move || {
 ///     let num = num.clone();
-///     // async closures are unstable
-///     async move || {
+///     async move {
 ///         num.set(num.get() + 1);
+///         println!("This is printed {} times.", num.get());
 ///     }
-/// }()).await;
+/// })
+/// .await;
+/// # }
 /// ```
 ///
-/// *This function requires the following crate features to be activated: `hour`*
-#[cfg(feature = "hour")]
-pub async fn call_every_hour_async_mut<F, Fut>(mut callback: F)
+/// *This function requires the following crate features to be activated: `day`*
+#[cfg(feature = "day")]
+pub async fn call_every_day_in_async_mut<Tz, F, Fut, R>(tz: Tz, mut callback: F) -> R::Output
 where
+    Tz: TimeZone + Send + 'static,
     F: FnMut() -> Fut,
-    Fut: Future<Output = ()>,
+    Fut: Future<Output = R>,
+    R: Flow,
 {
-    loop {
-        let duration = duration_to_next_hour();
-        sleep(duration).await;
-        callback().await;
-    }
+    Timer::every_day_in(tz).run_async(move |_| callback()).await
 }
 
-/// calls the given function every minute
+/// calls the given fallible function every day at midnight
+///
+/// Like [`call_every_day_mut`], but the function returns a `Result`. What
+/// happens to an error is decided by `policy`, see [`ErrorPolicy`]. When the
+/// policy stops the timer, the function returns the error.
 ///
 /// # Example
 ///
-/// using a closure:
+/// ```rust,no_run
+/// use zila::{try_call_every_day, ErrorPolicy};
 ///
-/// ```rust
-/// use zila::call_every_minute;
+/// fn rotate_logs() -> std::io::Result<()> {
+///     std::fs::rename("app.log", "app.log.1")
+/// }
 ///
-/// call_every_minute(|| {
-///     println!("Hi");
-/// });
+/// let result = try_call_every_day(rotate_logs, ErrorPolicy::Stop);
+/// if let Err(error) = result {
+///     eprintln!("rotate_logs failed: {}", error);
+/// }
 /// ```
 ///
-/// using a function:
+/// *This function requires the following crate features to be activated: `day`*
+#[cfg(feature = "day")]
+pub fn try_call_every_day<F, E>(callback: F, policy: ErrorPolicy<E>) -> Result<(), E>
+where
+    F: FnMut() -> Result<(), E>,
+    E: fmt::Display + Send + 'static,
+{
+    try_call_every_day_in(Local, callback, policy)
+}
+
+/// calls the given fallible async function every day at midnight
 ///
-/// ```rust
-/// use zila::call_every_minute;
+/// Like [`call_every_day_async_mut`], but the future returns a `Result`. What
+/// happens to an error is decided by `policy`, see [`ErrorPolicy`]. When the
+/// policy stops the timer, the function returns the error.
 ///
-/// fn callback() {
-///     println!("Hi");
-/// }
+/// # Example
 ///
-/// call_every_minute(callback);
+/// ```rust,no_run
+/// use zila::{try_call_every_day_async, ErrorPolicy};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// try_call_every_day_async(|| async {
+///     std::fs::rename("app.log", "app.log.1")
+/// }, ErrorPolicy::Log)
+/// .await
+/// .unwrap();
+/// # }
 /// ```
 ///
-/// *This function requires the following crate features to be activated: `minute`*
-#[cfg(feature = "minute")]
-pub fn call_every_minute<F>(callback: F)
+/// *This function requires the following crate features to be activated: `day`*
+#[cfg(feature = "day")]
+pub async fn try_call_every_day_async<F, Fut, E>(
+    callback: F,
+    policy: ErrorPolicy<E>,
+) -> Result<(), E>
 where
-    F: Fn(),
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<(), E>>,
+    E: fmt::Display + Send + 'static,
 {
-    loop {
-        let duration = duration_to_next_minute();
-        std::thread::sleep(duration);
-        callback();
-    }
+    try_call_every_day_in_async(Local, callback, policy).await
 }
 
-/// calls the given function every minute, takes a `FnMut` as the argument
+/// calls the given fallible function every day at midnight in the given time zone
+///
+/// Like [`try_call_every_day`], but the days follow `tz` instead of the local
+/// time zone of the machine.
 ///
 /// # Example
 ///
-/// ```rust
-/// use zila::call_every_minute_mut;
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::{try_call_every_day_in, ErrorPolicy};
 ///
-/// let num = Rc::new(Cell::new(0));
-/// call_every_minute_mut({
-///     let num = num.clone();
-///     move || {
-///         num.set(num.get() + 1);
-///     }
+/// let policy = ErrorPolicy::on_error(|error: &std::io::Error| {
+///     eprintln!("rotate_logs failed: {}", error);
 /// });
+/// try_call_every_day_in(Berlin, || std::fs::rename("app.log", "app.log.1"), policy)
+///     .unwrap();
 /// ```
 ///
-/// *This function requires the following crate features to be activated: `minute`*
-#[cfg(feature = "minute")]
-pub fn call_every_minute_mut<F>(mut callback: F)
+/// *This function requires the following crate features to be activated: `day`*
+#[cfg(feature = "day")]
+pub fn try_call_every_day_in<Tz, F, E>(
+    tz: Tz,
+    mut callback: F,
+    policy: ErrorPolicy<E>,
+) -> Result<(), E>
 where
-    F: FnMut(),
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> Result<(), E>,
+    E: fmt::Display + Send + 'static,
 {
-    loop {
-        let duration = duration_to_next_minute();
-        std::thread::sleep(duration);
-        callback();
-    }
+    Timer::every_day_in(tz).try_run(move |_| callback(), policy)
 }
 
-/// calls the given async function every minute
+/// calls the given fallible async function every day at midnight in the given time zone
+///
+/// Like [`try_call_every_day_async`], but the days follow `tz` instead of the
+/// local time zone of the machine.
 ///
 /// # Example
 ///
-/// using a closure:
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::{try_call_every_day_in_async, ErrorPolicy};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// try_call_every_day_in_async(Berlin, || async {
+///     std::fs::rename("app.log", "app.log.1")
+/// }, ErrorPolicy::Stop)
+/// .await
+/// .unwrap();
+/// # }
+/// ```
 ///
-/// ```rust
-/// use zila::call_every_minute_async;
+/// *This function requires the following crate features to be activated: `day`*
+#[cfg(feature = "day")]
+pub async fn try_call_every_day_in_async<Tz, F, Fut, E>(
+    tz: Tz,
+    mut callback: F,
+    policy: ErrorPolicy<E>,
+) -> Result<(), E>
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<(), E>>,
+    E: fmt::Display + Send + 'static,
+{
+    Timer::every_day_in(tz)
+        .try_run_async(move |_| callback(), policy)
+        .await
+}
+
+/// calls the given function every day on a background thread
 ///
-/// // async closures are unstable
-/// call_every_minute_async(async || {
-///     println!("Hi");
-/// }).await;
-/// ```
+/// Like [`call_every_day_mut`], but the timer runs on a thread named
+/// `zila-call-every-day` and the function returns right away with a
+/// [`TimerHandle`] that cancels the timer or waits for it to finish.
 ///
-/// using a function:
+/// # Example
 ///
-/// ```rust
-/// use zila::call_every_minute_async;
+/// ```rust,no_run
+/// use zila::spawn_call_every_day;
 ///
-/// async fn callback() {
+/// let handle = spawn_call_every_day(|| {
 ///     println!("Hi");
-/// }
+/// });
 ///
-/// call_every_minute_async(callback).await;
+/// // stop the timer on shutdown
+/// handle.cancel();
 /// ```
 ///
-/// *This function requires the following crate features to be activated: `minute`*
-#[cfg(feature = "minute")]
-pub async fn call_every_minute_async<F, Fut>(callback: F)
+/// *This function requires the following crate features to be activated: `day`*
+#[cfg(feature = "day")]
+pub fn spawn_call_every_day<F, R>(callback: F) -> TimerHandle<R::Output>
 where
-    F: Fn() -> Fut,
-    Fut: Future<Output = ()>,
+    F: FnMut() -> R + Send + 'static,
+    R: Flow,
 {
-    loop {
-        let duration = duration_to_next_minute();
-        sleep(duration).await;
-        callback().await;
-    }
+    spawn_call_every_day_in(Local, callback)
 }
 
-/// calls the given async function every minute, takes a `FnMut` as the argument
+/// calls the given function every day in the given time zone on a background thread
+///
+/// Like [`spawn_call_every_day`], but the days follow `tz` instead of the
+/// local time zone of the machine.
 ///
 /// # Example
 ///
-/// ```rust
-/// use zila::call_every_minute_async_mut;
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::spawn_call_every_day_in;
 ///
-/// let num = Rc::new(Cell::new(0));
-/// call_every_minute_async_mut(move || {
-///     let num = num.clone();
-///     // async closures are unstable
-///     async move || {
-///         num.set(num.get() + 1);
-///     }
-/// }()).await;
+/// let handle = spawn_call_every_day_in(Berlin, || {
+///     println!("Hi");
+/// });
+///
+/// // stop the timer on shutdown
+/// handle.cancel();
 /// ```
 ///
-/// *This function requires the following crate features to be activated: `minute`*
-#[cfg(feature = "minute")]
-pub async fn call_every_minute_async_mut<F, Fut>(mut callback: F)
+/// *This function requires the following crate features to be activated: `day`*
+#[cfg(feature = "day")]
+pub fn spawn_call_every_day_in<Tz, F, R>(tz: Tz, mut callback: F) -> TimerHandle<R::Output>
 where
-    F: FnMut() -> Fut,
-    Fut: Future<Output = ()>,
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> R + Send + 'static,
+    R: Flow,
 {
-    loop {
-        let duration = duration_to_next_minute();
-        sleep(duration).await;
-        callback().await;
-    }
+    Timer::every_day_in(tz).spawn(move |_| callback())
 }
 
-/// calls the given function every second
+/// calls the given function every hour
 ///
 /// # Example
 ///
 /// using a closure:
 ///
-/// ```rust
-/// use zila::call_every_second;
+/// ```rust,no_run
+/// use zila::call_every_hour;
 ///
-/// call_every_second(|| {
+/// call_every_hour(|| {
 ///     println!("Hi");
 /// });
 /// ```
 ///
 /// using a function:
 ///
-/// ```rust
-/// use zila::call_every_second;
+/// ```rust,no_run
+/// use zila::call_every_hour;
 ///
 /// fn callback() {
 ///     println!("Hi");
 /// }
 ///
-/// call_every_second(callback);
+/// call_every_hour(callback);
 /// ```
 ///
-/// *This function requires the following crate features to be activated: `second`*
-#[cfg(feature = "second")]
-pub fn call_every_second<F>(callback: F)
+/// *This function requires the following crate features to be activated: `hour`*
+#[cfg(feature = "hour")]
+pub fn call_every_hour<F, R>(callback: F) -> R::Output
 where
-    F: Fn(),
+    F: Fn() -> R,
+    R: Flow,
 {
-    loop {
-        let duration = duration_to_next_second();
-        std::thread::sleep(duration);
-        callback();
-    }
+    call_every_hour_in(Local, callback)
 }
 
-/// calls the given function every second, takes a `FnMut` as the argument
+/// calls the given function every hour, takes a `FnMut` as the argument
 ///
 /// # Example
 ///
-/// ```rust
-/// use zila::call_every_second_mut;
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use zila::call_every_hour_mut;
 ///
 /// let num = Rc::new(Cell::new(0));
-/// call_every_second_mut({
+/// call_every_hour_mut({
 ///     let num = num.clone();
 ///     move || {
 ///         num.set(num.get() + 1);
@@ -649,237 +1189,3529 @@ where
 /// });
 /// ```
 ///
-/// *This function requires the following crate features to be activated: `second`*
-#[cfg(feature = "second")]
-pub fn call_every_second_mut<F>(mut callback: F)
+/// *This function requires the following crate features to be activated: `hour`*
+#[cfg(feature = "hour")]
+pub fn call_every_hour_mut<F, R>(callback: F) -> R::Output
 where
-    F: FnMut(),
+    F: FnMut() -> R,
+    R: Flow,
 {
-    loop {
-        let duration = duration_to_next_second();
-        std::thread::sleep(duration);
-        callback();
-    }
+    call_every_hour_in_mut(Local, callback)
 }
 
-/// calls the given async function every second
+/// calls the given async function every hour
 ///
 /// # Example
 ///
 /// using a closure:
 ///
-/// ```rust
-/// use zila::call_every_second_async;
+/// ```rust,no_run
+/// use zila::call_every_hour_async;
 ///
-/// // async closures are unstable
-/// call_every_second_async(async || {
+/// # #[tokio::main]
+/// # async fn main() {
+/// call_every_hour_async(|| async {
 ///     println!("Hi");
-/// }).await;
+/// })
+/// .await;
+/// # }
 /// ```
 ///
 /// using a function:
 ///
-/// ```rust
-/// use zila::call_every_second_async;
+/// ```rust,no_run
+/// use zila::call_every_hour_async;
 ///
 /// async fn callback() {
 ///     println!("Hi");
 /// }
 ///
-/// call_every_second_async(callback).await;
+/// # #[tokio::main]
+/// # async fn main() {
+/// call_every_hour_async(callback).await;
+/// # }
 /// ```
 ///
-/// *This function requires the following crate features to be activated: `second`*
-#[cfg(feature = "second")]
-pub async fn call_every_second_async<F, Fut>(callback: F)
+/// *This function requires the following crate features to be activated: `hour`*
+#[cfg(feature = "hour")]
+pub async fn call_every_hour_async<F, Fut, R>(callback: F) -> R::Output
 where
     F: Fn() -> Fut,
-    Fut: Future<Output = ()>,
+    Fut: Future<Output = R>,
+    R: Flow,
 {
-    loop {
-        let duration = duration_to_next_second();
-        sleep(duration).await;
-        callback().await;
-    }
+    call_every_hour_in_async(Local, callback).await
 }
 
-/// calls the given async function every second, takes a `FnMut` as the argument
+/// calls the given async function every hour, takes a `FnMut` as the argument
 ///
 /// # Example
 ///
-/// ```rust
-/// use zila::call_every_second_async_mut;
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use zila::call_every_hour_async_mut;
 ///
+/// # #[tokio::main]
+/// # async fn main() {
 /// let num = Rc::new(Cell::new(0));
-/// call_every_second_async_mut(move || {
+/// call_every_hour_async_mut(move || {
 ///     let num = num.clone();
-///     // async closures are unstable
-///     async move || {
+///     async move {
 ///         num.set(num.get() + 1);
+///         println!("This is printed {} times.", num.get());
 ///     }
-/// }()).await;
+/// })
+/// .await;
+/// # }
 /// ```
 ///
-/// *This function requires the following crate features to be activated: `second`*
-#[cfg(feature = "second")]
-pub async fn call_every_second_async_mut<F, Fut>(mut callback: F)
+/// *This function requires the following crate features to be activated: `hour`*
+#[cfg(feature = "hour")]
+pub async fn call_every_hour_async_mut<F, Fut, R>(callback: F) -> R::Output
 where
     F: FnMut() -> Fut,
-    Fut: Future<Output = ()>,
+    Fut: Future<Output = R>,
+    R: Flow,
 {
-    loop {
-        let duration = duration_to_next_second();
-        sleep(duration).await;
-        callback().await;
-    }
+    call_every_hour_in_async_mut(Local, callback).await
 }
 
-/// calls the function after the specified duration
-///
-/// # Example
-///
-/// using a closure:
-///
-/// ```rust
-/// use zila::set_timeout;
+/// calls the given function every hour in the given time zone
 ///
-/// set_timeout(|| {
-///     println!("Hi");
-/// }, Duration::from_secs(1));
-/// ```
+/// Like [`call_every_hour`], but the hours follow `tz` instead of the local
+/// time zone of the machine.
 ///
-/// using a function:
+/// # Example
 ///
-/// ```rust
-/// use zila::set_timeout;
+/// ```rust,no_run
+/// use zila::chrono_tz::Asia::Kolkata;
+/// use zila::call_every_hour_in;
 ///
-/// fn callback() {
+/// call_every_hour_in(Kolkata, || {
 ///     println!("Hi");
-/// }
-///
-/// set_timeout(callback, Duration::from_secs(1));
+/// });
 /// ```
 ///
-/// *This function requires the following crate features to be activated: `timeout`*
-#[cfg(feature = "timeout")]
-pub fn set_timeout<F>(callback: F, duration: Duration)
+/// *This function requires the following crate features to be activated: `hour`*
+#[cfg(feature = "hour")]
+pub fn call_every_hour_in<Tz, F, R>(tz: Tz, callback: F) -> R::Output
 where
-    F: Fn(),
+    Tz: TimeZone + Send + 'static,
+    F: Fn() -> R,
+    R: Flow,
 {
-    std::thread::sleep(duration);
-    callback();
+    call_every_hour_in_mut(tz, callback)
 }
 
-/// calls the function after the specified duration, takes `FnMut` as the first argument
+/// calls the given function every hour in the given time zone, takes a `FnMut` as
+/// the argument
 ///
 /// # Example
 ///
-/// ```rust
-/// use zila::set_timeout_mut;
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use zila::chrono_tz::Asia::Kolkata;
+/// use zila::call_every_hour_in_mut;
 ///
 /// let num = Rc::new(Cell::new(0));
-/// set_timeout_mut({
+/// call_every_hour_in_mut(Kolkata, {
 ///     let num = num.clone();
 ///     move || {
 ///         num.set(num.get() + 1);
 ///     }
-/// }, Duration::from_secs(1));
+/// });
 /// ```
 ///
-/// *This function requires the following crate features to be activated: `timeout`*
-#[cfg(feature = "timeout")]
-pub fn set_timeout_mut<F>(mut callback: F, duration: Duration)
+/// *This function requires the following crate features to be activated: `hour`*
+#[cfg(feature = "hour")]
+pub fn call_every_hour_in_mut<Tz, F, R>(tz: Tz, mut callback: F) -> R::Output
 where
-    F: FnMut(),
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> R,
+    R: Flow,
 {
-    std::thread::sleep(duration);
-    callback();
+    Timer::every_hour_in(tz).run(move |_| callback())
 }
 
-/// calls the async function after the specified duration
-///
-/// # Example
-///
-/// using a closure:
-///
-/// ```rust
-/// use zila::set_timeout_async;
+/// calls the given async function every hour in the given time zone
 ///
-/// // async closures are unstable
-/// set_timeout_async(async || {
-///     println!("Hi");
-/// }, Duration::from_secs(1)).await;
-/// ```
+/// Like [`call_every_hour_async`], but the hours follow `tz` instead of the local
+/// time zone of the machine.
 ///
-/// using a function:
+/// # Example
 ///
-/// ```rust
-/// use zila::set_timeout_async;
+/// ```rust,no_run
+/// use zila::chrono_tz::Asia::Kolkata;
+/// use zila::call_every_hour_in_async;
 ///
-/// async fn callback() {
+/// # #[tokio::main]
+/// # async fn main() {
+/// call_every_hour_in_async(Kolkata, || async {
 ///     println!("Hi");
-/// }
-///
-/// set_timeout_async(callback, Duration::from_secs(1)).await;
+/// })
+/// .await;
+/// # }
 /// ```
 ///
-/// *This function requires the following crate features to be activated: `timeout`*
-#[cfg(feature = "timeout")]
-pub async fn set_timeout_async<F, Fut>(callback: F, duration: Duration)
+/// *This function requires the following crate features to be activated: `hour`*
+#[cfg(feature = "hour")]
+pub async fn call_every_hour_in_async<Tz, F, Fut, R>(tz: Tz, callback: F) -> R::Output
 where
+    Tz: TimeZone + Send + 'static,
     F: Fn() -> Fut,
-    Fut: Future<Output = ()>,
+    Fut: Future<Output = R>,
+    R: Flow,
 {
-    sleep(duration).await;
-    callback().await;
+    call_every_hour_in_async_mut(tz, callback).await
 }
 
-/// calls the async function after the specified duration, takes `FnMut` as the first argument
+/// calls the given async function every hour in the given time zone, takes a
+/// `FnMut` as the argument
 ///
 /// # Example
 ///
-/// ```rust
-/// use zila::set_timeout_async_mut;
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use zila::chrono_tz::Asia::Kolkata;
+/// use zila::call_every_hour_in_async_mut;
 ///
+/// # #[tokio::main]
+/// # async fn main() {
 /// let num = Rc::new(Cell::new(0));
-/// set_timeout_async_mut(move || {
+/// call_every_hour_in_async_mut(Kolkata, move || {
 ///     let num = num.clone();
-///     // async closures are unstable
-///     async move || {
+///     async move {
 ///         num.set(num.get() + 1);
+///         println!("This is printed {} times.", num.get());
 ///     }
-/// }(), Duration::from_secs(1)).await;
+/// })
+/// .await;
+/// # }
 /// ```
 ///
-/// *This function requires the following crate features to be activated: `timeout`*
-#[cfg(feature = "timeout")]
-pub async fn set_timeout_async_mut<F, Fut>(mut callback: F, duration: Duration)
+/// *This function requires the following crate features to be activated: `hour`*
+#[cfg(feature = "hour")]
+pub async fn call_every_hour_in_async_mut<Tz, F, Fut, R>(tz: Tz, mut callback: F) -> R::Output
 where
+    Tz: TimeZone + Send + 'static,
     F: FnMut() -> Fut,
-    Fut: Future<Output = ()>,
+    Fut: Future<Output = R>,
+    R: Flow,
 {
-    sleep(duration).await;
-    callback().await;
+    Timer::every_hour_in(tz)
+        .run_async(move |_| callback())
+        .await
 }
 
-/// calls the function in the specified intervals
+/// calls the given fallible function every hour
+///
+/// Like [`call_every_hour_mut`], but the function returns a `Result`. What
+/// happens to an error is decided by `policy`, see [`ErrorPolicy`]. When the
+/// policy stops the timer, the function returns the error.
 ///
 /// # Example
 ///
-/// using a closure:
+/// ```rust,no_run
+/// use zila::{try_call_every_hour, ErrorPolicy};
 ///
-/// ```rust
-/// use zila::set_interval;
+/// fn clean_up() -> std::io::Result<()> {
+///     std::fs::remove_dir_all("tmp")
+/// }
 ///
-/// set_interval(|| {
-///     println!("Hi");
-/// }, Duration::from_secs(1));
+/// let result = try_call_every_hour(clean_up, ErrorPolicy::Stop);
+/// if let Err(error) = result {
+///     eprintln!("clean_up failed: {}", error);
+/// }
 /// ```
 ///
-/// using a function:
-///
-/// ```rust
-/// use zila::set_interval;
+/// *This function requires the following crate features to be activated: `hour`*
+#[cfg(feature = "hour")]
+pub fn try_call_every_hour<F, E>(callback: F, policy: ErrorPolicy<E>) -> Result<(), E>
+where
+    F: FnMut() -> Result<(), E>,
+    E: fmt::Display + Send + 'static,
+{
+    try_call_every_hour_in(Local, callback, policy)
+}
+
+/// calls the given fallible async function every hour
+///
+/// Like [`call_every_hour_async_mut`], but the future returns a `Result`. What
+/// happens to an error is decided by `policy`, see [`ErrorPolicy`]. When the
+/// policy stops the timer, the function returns the error.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::{try_call_every_hour_async, ErrorPolicy};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// try_call_every_hour_async(|| async {
+///     std::fs::remove_dir_all("tmp")
+/// }, ErrorPolicy::Log)
+/// .await
+/// .unwrap();
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `hour`*
+#[cfg(feature = "hour")]
+pub async fn try_call_every_hour_async<F, Fut, E>(
+    callback: F,
+    policy: ErrorPolicy<E>,
+) -> Result<(), E>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<(), E>>,
+    E: fmt::Display + Send + 'static,
+{
+    try_call_every_hour_in_async(Local, callback, policy).await
+}
+
+/// calls the given fallible function every hour in the given time zone
+///
+/// Like [`try_call_every_hour`], but the hours follow `tz` instead of the local
+/// time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::{try_call_every_hour_in, ErrorPolicy};
+///
+/// let policy = ErrorPolicy::on_error(|error: &std::io::Error| {
+///     eprintln!("clean_up failed: {}", error);
+/// });
+/// try_call_every_hour_in(Berlin, || std::fs::remove_dir_all("tmp"), policy)
+///     .unwrap();
+/// ```
+///
+/// *This function requires the following crate features to be activated: `hour`*
+#[cfg(feature = "hour")]
+pub fn try_call_every_hour_in<Tz, F, E>(
+    tz: Tz,
+    mut callback: F,
+    policy: ErrorPolicy<E>,
+) -> Result<(), E>
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> Result<(), E>,
+    E: fmt::Display + Send + 'static,
+{
+    Timer::every_hour_in(tz).try_run(move |_| callback(), policy)
+}
+
+/// calls the given fallible async function every hour in the given time zone
+///
+/// Like [`try_call_every_hour_async`], but the hours follow `tz` instead of the
+/// local time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::{try_call_every_hour_in_async, ErrorPolicy};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// try_call_every_hour_in_async(Berlin, || async {
+///     std::fs::remove_dir_all("tmp")
+/// }, ErrorPolicy::Stop)
+/// .await
+/// .unwrap();
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `hour`*
+#[cfg(feature = "hour")]
+pub async fn try_call_every_hour_in_async<Tz, F, Fut, E>(
+    tz: Tz,
+    mut callback: F,
+    policy: ErrorPolicy<E>,
+) -> Result<(), E>
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<(), E>>,
+    E: fmt::Display + Send + 'static,
+{
+    Timer::every_hour_in(tz)
+        .try_run_async(move |_| callback(), policy)
+        .await
+}
+
+/// calls the given function every hour on a background thread
+///
+/// Like [`call_every_hour_mut`], but the timer runs on a thread named
+/// `zila-call-every-hour` and the function returns right away with a
+/// [`TimerHandle`] that cancels the timer or waits for it to finish.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::spawn_call_every_hour;
+///
+/// let handle = spawn_call_every_hour(|| {
+///     println!("Hi");
+/// });
+///
+/// // stop the timer on shutdown
+/// handle.cancel();
+/// ```
+///
+/// *This function requires the following crate features to be activated: `hour`*
+#[cfg(feature = "hour")]
+pub fn spawn_call_every_hour<F, R>(callback: F) -> TimerHandle<R::Output>
+where
+    F: FnMut() -> R + Send + 'static,
+    R: Flow,
+{
+    spawn_call_every_hour_in(Local, callback)
+}
+
+/// calls the given function every hour in the given time zone on a background thread
+///
+/// Like [`spawn_call_every_hour`], but the hours follow `tz` instead of the
+/// local time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::spawn_call_every_hour_in;
+///
+/// let handle = spawn_call_every_hour_in(Berlin, || {
+///     println!("Hi");
+/// });
+///
+/// // stop the timer on shutdown
+/// handle.cancel();
+/// ```
+///
+/// *This function requires the following crate features to be activated: `hour`*
+#[cfg(feature = "hour")]
+pub fn spawn_call_every_hour_in<Tz, F, R>(tz: Tz, mut callback: F) -> TimerHandle<R::Output>
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> R + Send + 'static,
+    R: Flow,
+{
+    Timer::every_hour_in(tz).spawn(move |_| callback())
+}
+
+/// calls the given function every minute
+///
+/// # Example
+///
+/// using a closure:
+///
+/// ```rust,no_run
+/// use zila::call_every_minute;
+///
+/// call_every_minute(|| {
+///     println!("Hi");
+/// });
+/// ```
+///
+/// using a function:
+///
+/// ```rust,no_run
+/// use zila::call_every_minute;
+///
+/// fn callback() {
+///     println!("Hi");
+/// }
+///
+/// call_every_minute(callback);
+/// ```
+///
+/// *This function requires the following crate features to be activated: `minute`*
+#[cfg(feature = "minute")]
+pub fn call_every_minute<F, R>(callback: F) -> R::Output
+where
+    F: Fn() -> R,
+    R: Flow,
+{
+    call_every_minute_in(Local, callback)
+}
+
+/// calls the given function every minute, takes a `FnMut` as the argument
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use zila::call_every_minute_mut;
+///
+/// let num = Rc::new(Cell::new(0));
+/// call_every_minute_mut({
+///     let num = num.clone();
+///     move || {
+///         num.set(num.get() + 1);
+///     }
+/// });
+/// ```
+///
+/// *This function requires the following crate features to be activated: `minute`*
+#[cfg(feature = "minute")]
+pub fn call_every_minute_mut<F, R>(callback: F) -> R::Output
+where
+    F: FnMut() -> R,
+    R: Flow,
+{
+    call_every_minute_in_mut(Local, callback)
+}
+
+/// calls the given async function every minute
+///
+/// # Example
+///
+/// using a closure:
+///
+/// ```rust,no_run
+/// use zila::call_every_minute_async;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// call_every_minute_async(|| async {
+///     println!("Hi");
+/// })
+/// .await;
+/// # }
+/// ```
+///
+/// using a function:
+///
+/// ```rust,no_run
+/// use zila::call_every_minute_async;
+///
+/// async fn callback() {
+///     println!("Hi");
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// call_every_minute_async(callback).await;
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `minute`*
+#[cfg(feature = "minute")]
+pub async fn call_every_minute_async<F, Fut, R>(callback: F) -> R::Output
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = R>,
+    R: Flow,
+{
+    call_every_minute_in_async(Local, callback).await
+}
+
+/// calls the given async function every minute, takes a `FnMut` as the argument
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use zila::call_every_minute_async_mut;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let num = Rc::new(Cell::new(0));
+/// call_every_minute_async_mut(move || {
+///     let num = num.clone();
+///     async move {
+///         num.set(num.get() + 1);
+///         println!("This is printed {} times.", num.get());
+///     }
+/// })
+/// .await;
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `minute`*
+#[cfg(feature = "minute")]
+pub async fn call_every_minute_async_mut<F, Fut, R>(callback: F) -> R::Output
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = R>,
+    R: Flow,
+{
+    call_every_minute_in_async_mut(Local, callback).await
+}
+
+/// calls the given function every minute in the given time zone
+///
+/// Like [`call_every_minute`], but the minutes follow `tz` instead of the local
+/// time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use chrono::Utc;
+/// use zila::call_every_minute_in;
+///
+/// call_every_minute_in(Utc, || {
+///     println!("Hi");
+/// });
+/// ```
+///
+/// *This function requires the following crate features to be activated: `minute`*
+#[cfg(feature = "minute")]
+pub fn call_every_minute_in<Tz, F, R>(tz: Tz, callback: F) -> R::Output
+where
+    Tz: TimeZone + Send + 'static,
+    F: Fn() -> R,
+    R: Flow,
+{
+    call_every_minute_in_mut(tz, callback)
+}
+
+/// calls the given function every minute in the given time zone, takes a `FnMut` as
+/// the argument
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use chrono::Utc;
+/// use zila::call_every_minute_in_mut;
+///
+/// let num = Rc::new(Cell::new(0));
+/// call_every_minute_in_mut(Utc, {
+///     let num = num.clone();
+///     move || {
+///         num.set(num.get() + 1);
+///     }
+/// });
+/// ```
+///
+/// *This function requires the following crate features to be activated: `minute`*
+#[cfg(feature = "minute")]
+pub fn call_every_minute_in_mut<Tz, F, R>(tz: Tz, mut callback: F) -> R::Output
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> R,
+    R: Flow,
+{
+    Timer::every_minute_in(tz).run(move |_| callback())
+}
+
+/// calls the given async function every minute in the given time zone
+///
+/// Like [`call_every_minute_async`], but the minutes follow `tz` instead of the local
+/// time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use chrono::Utc;
+/// use zila::call_every_minute_in_async;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// call_every_minute_in_async(Utc, || async {
+///     println!("Hi");
+/// })
+/// .await;
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `minute`*
+#[cfg(feature = "minute")]
+pub async fn call_every_minute_in_async<Tz, F, Fut, R>(tz: Tz, callback: F) -> R::Output
+where
+    Tz: TimeZone + Send + 'static,
+    F: Fn() -> Fut,
+    Fut: Future<Output = R>,
+    R: Flow,
+{
+    call_every_minute_in_async_mut(tz, callback).await
+}
+
+/// calls the given async function every minute in the given time zone, takes a
+/// `FnMut` as the argument
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use chrono::Utc;
+/// use zila::call_every_minute_in_async_mut;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let num = Rc::new(Cell::new(0));
+/// call_every_minute_in_async_mut(Utc, move || {
+///     let num = num.clone();
+///     async move {
+///         num.set(num.get() + 1);
+///         println!("This is printed {} times.", num.get());
+///     }
+/// })
+/// .await;
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `minute`*
+#[cfg(feature = "minute")]
+pub async fn call_every_minute_in_async_mut<Tz, F, Fut, R>(tz: Tz, mut callback: F) -> R::Output
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = R>,
+    R: Flow,
+{
+    Timer::every_minute_in(tz)
+        .run_async(move |_| callback())
+        .await
+}
+
+/// calls the given fallible function every minute
+///
+/// Like [`call_every_minute_mut`], but the function returns a `Result`. What
+/// happens to an error is decided by `policy`, see [`ErrorPolicy`]. When the
+/// policy stops the timer, the function returns the error.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::{try_call_every_minute, ErrorPolicy};
+///
+/// fn remove_lock() -> std::io::Result<()> {
+///     std::fs::remove_file("lock")
+/// }
+///
+/// let result = try_call_every_minute(remove_lock, ErrorPolicy::Stop);
+/// if let Err(error) = result {
+///     eprintln!("remove_lock failed: {}", error);
+/// }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `minute`*
+#[cfg(feature = "minute")]
+pub fn try_call_every_minute<F, E>(callback: F, policy: ErrorPolicy<E>) -> Result<(), E>
+where
+    F: FnMut() -> Result<(), E>,
+    E: fmt::Display + Send + 'static,
+{
+    try_call_every_minute_in(Local, callback, policy)
+}
+
+/// calls the given fallible async function every minute
+///
+/// Like [`call_every_minute_async_mut`], but the future returns a `Result`.
+/// What happens to an error is decided by `policy`, see [`ErrorPolicy`]. When
+/// the policy stops the timer, the function returns the error.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::{try_call_every_minute_async, ErrorPolicy};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// try_call_every_minute_async(|| async {
+///     std::fs::remove_file("lock")
+/// }, ErrorPolicy::Log)
+/// .await
+/// .unwrap();
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `minute`*
+#[cfg(feature = "minute")]
+pub async fn try_call_every_minute_async<F, Fut, E>(
+    callback: F,
+    policy: ErrorPolicy<E>,
+) -> Result<(), E>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<(), E>>,
+    E: fmt::Display + Send + 'static,
+{
+    try_call_every_minute_in_async(Local, callback, policy).await
+}
+
+/// calls the given fallible function every minute in the given time zone
+///
+/// Like [`try_call_every_minute`], but the minutes follow `tz` instead of the local
+/// time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::{try_call_every_minute_in, ErrorPolicy};
+///
+/// let policy = ErrorPolicy::on_error(|error: &std::io::Error| {
+///     eprintln!("remove_lock failed: {}", error);
+/// });
+/// try_call_every_minute_in(Berlin, || std::fs::remove_file("lock"), policy)
+///     .unwrap();
+/// ```
+///
+/// *This function requires the following crate features to be activated: `minute`*
+#[cfg(feature = "minute")]
+pub fn try_call_every_minute_in<Tz, F, E>(
+    tz: Tz,
+    mut callback: F,
+    policy: ErrorPolicy<E>,
+) -> Result<(), E>
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> Result<(), E>,
+    E: fmt::Display + Send + 'static,
+{
+    Timer::every_minute_in(tz).try_run(move |_| callback(), policy)
+}
+
+/// calls the given fallible async function every minute in the given time zone
+///
+/// Like [`try_call_every_minute_async`], but the minutes follow `tz` instead of the
+/// local time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::{try_call_every_minute_in_async, ErrorPolicy};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// try_call_every_minute_in_async(Berlin, || async {
+///     std::fs::remove_file("lock")
+/// }, ErrorPolicy::Stop)
+/// .await
+/// .unwrap();
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `minute`*
+#[cfg(feature = "minute")]
+pub async fn try_call_every_minute_in_async<Tz, F, Fut, E>(
+    tz: Tz,
+    mut callback: F,
+    policy: ErrorPolicy<E>,
+) -> Result<(), E>
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<(), E>>,
+    E: fmt::Display + Send + 'static,
+{
+    Timer::every_minute_in(tz)
+        .try_run_async(move |_| callback(), policy)
+        .await
+}
+
+/// calls the given function every minute on a background thread
+///
+/// Like [`call_every_minute_mut`], but the timer runs on a thread named
+/// `zila-call-every-minute` and the function returns right away with a
+/// [`TimerHandle`] that cancels the timer or waits for it to finish.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::spawn_call_every_minute;
+///
+/// let handle = spawn_call_every_minute(|| {
+///     println!("Hi");
+/// });
+///
+/// // stop the timer on shutdown
+/// handle.cancel();
+/// ```
+///
+/// *This function requires the following crate features to be activated: `minute`*
+#[cfg(feature = "minute")]
+pub fn spawn_call_every_minute<F, R>(callback: F) -> TimerHandle<R::Output>
+where
+    F: FnMut() -> R + Send + 'static,
+    R: Flow,
+{
+    spawn_call_every_minute_in(Local, callback)
+}
+
+/// calls the given function every minute in the given time zone on a background thread
+///
+/// Like [`spawn_call_every_minute`], but the minutes follow `tz` instead of the
+/// local time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::spawn_call_every_minute_in;
+///
+/// let handle = spawn_call_every_minute_in(Berlin, || {
+///     println!("Hi");
+/// });
+///
+/// // stop the timer on shutdown
+/// handle.cancel();
+/// ```
+///
+/// *This function requires the following crate features to be activated: `minute`*
+#[cfg(feature = "minute")]
+pub fn spawn_call_every_minute_in<Tz, F, R>(tz: Tz, mut callback: F) -> TimerHandle<R::Output>
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> R + Send + 'static,
+    R: Flow,
+{
+    Timer::every_minute_in(tz).spawn(move |_| callback())
+}
+
+/// calls the given function every second
+///
+/// # Example
+///
+/// using a closure:
+///
+/// ```rust,no_run
+/// use zila::call_every_second;
+///
+/// call_every_second(|| {
+///     println!("Hi");
+/// });
+/// ```
+///
+/// using a function:
+///
+/// ```rust,no_run
+/// use zila::call_every_second;
+///
+/// fn callback() {
+///     println!("Hi");
+/// }
+///
+/// call_every_second(callback);
+/// ```
+///
+/// *This function requires the following crate features to be activated: `second`*
+#[cfg(feature = "second")]
+pub fn call_every_second<F, R>(callback: F) -> R::Output
+where
+    F: Fn() -> R,
+    R: Flow,
+{
+    call_every_second_in(Local, callback)
+}
+
+/// calls the given function every second, takes a `FnMut` as the argument
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use zila::call_every_second_mut;
+///
+/// let num = Rc::new(Cell::new(0));
+/// call_every_second_mut({
+///     let num = num.clone();
+///     move || {
+///         num.set(num.get() + 1);
+///     }
+/// });
+/// ```
+///
+/// *This function requires the following crate features to be activated: `second`*
+#[cfg(feature = "second")]
+pub fn call_every_second_mut<F, R>(callback: F) -> R::Output
+where
+    F: FnMut() -> R,
+    R: Flow,
+{
+    call_every_second_in_mut(Local, callback)
+}
+
+/// calls the given async function every second
+///
+/// # Example
+///
+/// using a closure:
+///
+/// ```rust,no_run
+/// use zila::call_every_second_async;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// call_every_second_async(|| async {
+///     println!("Hi");
+/// })
+/// .await;
+/// # }
+/// ```
+///
+/// using a function:
+///
+/// ```rust,no_run
+/// use zila::call_every_second_async;
+///
+/// async fn callback() {
+///     println!("Hi");
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// call_every_second_async(callback).await;
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `second`*
+#[cfg(feature = "second")]
+pub async fn call_every_second_async<F, Fut, R>(callback: F) -> R::Output
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = R>,
+    R: Flow,
+{
+    call_every_second_in_async(Local, callback).await
+}
+
+/// calls the given async function every second, takes a `FnMut` as the argument
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use zila::call_every_second_async_mut;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let num = Rc::new(Cell::new(0));
+/// call_every_second_async_mut(move || {
+///     let num = num.clone();
+///     async move {
+///         num.set(num.get() + 1);
+///         println!("This is printed {} times.", num.get());
+///     }
+/// })
+/// .await;
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `second`*
+#[cfg(feature = "second")]
+pub async fn call_every_second_async_mut<F, Fut, R>(callback: F) -> R::Output
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = R>,
+    R: Flow,
+{
+    call_every_second_in_async_mut(Local, callback).await
+}
+
+/// calls the given function every second in the given time zone
+///
+/// Like [`call_every_second`], but the seconds follow `tz` instead of the local
+/// time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use chrono::Utc;
+/// use zila::call_every_second_in;
+///
+/// call_every_second_in(Utc, || {
+///     println!("Hi");
+/// });
+/// ```
+///
+/// *This function requires the following crate features to be activated: `second`*
+#[cfg(feature = "second")]
+pub fn call_every_second_in<Tz, F, R>(tz: Tz, callback: F) -> R::Output
+where
+    Tz: TimeZone + Send + 'static,
+    F: Fn() -> R,
+    R: Flow,
+{
+    call_every_second_in_mut(tz, callback)
+}
+
+/// calls the given function every second in the given time zone, takes a `FnMut` as
+/// the argument
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use chrono::Utc;
+/// use zila::call_every_second_in_mut;
+///
+/// let num = Rc::new(Cell::new(0));
+/// call_every_second_in_mut(Utc, {
+///     let num = num.clone();
+///     move || {
+///         num.set(num.get() + 1);
+///     }
+/// });
+/// ```
+///
+/// *This function requires the following crate features to be activated: `second`*
+#[cfg(feature = "second")]
+pub fn call_every_second_in_mut<Tz, F, R>(tz: Tz, mut callback: F) -> R::Output
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> R,
+    R: Flow,
+{
+    Timer::every_second_in(tz).run(move |_| callback())
+}
+
+/// calls the given async function every second in the given time zone
+///
+/// Like [`call_every_second_async`], but the seconds follow `tz` instead of the local
+/// time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use chrono::Utc;
+/// use zila::call_every_second_in_async;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// call_every_second_in_async(Utc, || async {
+///     println!("Hi");
+/// })
+/// .await;
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `second`*
+#[cfg(feature = "second")]
+pub async fn call_every_second_in_async<Tz, F, Fut, R>(tz: Tz, callback: F) -> R::Output
+where
+    Tz: TimeZone + Send + 'static,
+    F: Fn() -> Fut,
+    Fut: Future<Output = R>,
+    R: Flow,
+{
+    call_every_second_in_async_mut(tz, callback).await
+}
+
+/// calls the given async function every second in the given time zone, takes a
+/// `FnMut` as the argument
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use chrono::Utc;
+/// use zila::call_every_second_in_async_mut;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let num = Rc::new(Cell::new(0));
+/// call_every_second_in_async_mut(Utc, move || {
+///     let num = num.clone();
+///     async move {
+///         num.set(num.get() + 1);
+///         println!("This is printed {} times.", num.get());
+///     }
+/// })
+/// .await;
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `second`*
+#[cfg(feature = "second")]
+pub async fn call_every_second_in_async_mut<Tz, F, Fut, R>(tz: Tz, mut callback: F) -> R::Output
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = R>,
+    R: Flow,
+{
+    Timer::every_second_in(tz)
+        .run_async(move |_| callback())
+        .await
+}
+
+/// calls the given fallible function every second
+///
+/// Like [`call_every_second_mut`], but the function returns a `Result`. What
+/// happens to an error is decided by `policy`, see [`ErrorPolicy`]. When the
+/// policy stops the timer, the function returns the error.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::{try_call_every_second, ErrorPolicy};
+///
+/// fn touch() -> std::io::Result<()> {
+///     std::fs::write("heartbeat", b"")
+/// }
+///
+/// let result = try_call_every_second(touch, ErrorPolicy::Stop);
+/// if let Err(error) = result {
+///     eprintln!("touch failed: {}", error);
+/// }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `second`*
+#[cfg(feature = "second")]
+pub fn try_call_every_second<F, E>(callback: F, policy: ErrorPolicy<E>) -> Result<(), E>
+where
+    F: FnMut() -> Result<(), E>,
+    E: fmt::Display + Send + 'static,
+{
+    try_call_every_second_in(Local, callback, policy)
+}
+
+/// calls the given fallible async function every second
+///
+/// Like [`call_every_second_async_mut`], but the future returns a `Result`.
+/// What happens to an error is decided by `policy`, see [`ErrorPolicy`]. When
+/// the policy stops the timer, the function returns the error.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::{try_call_every_second_async, ErrorPolicy};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// try_call_every_second_async(|| async {
+///     std::fs::write("heartbeat", b"")
+/// }, ErrorPolicy::Log)
+/// .await
+/// .unwrap();
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `second`*
+#[cfg(feature = "second")]
+pub async fn try_call_every_second_async<F, Fut, E>(
+    callback: F,
+    policy: ErrorPolicy<E>,
+) -> Result<(), E>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<(), E>>,
+    E: fmt::Display + Send + 'static,
+{
+    try_call_every_second_in_async(Local, callback, policy).await
+}
+
+/// calls the given fallible function every second in the given time zone
+///
+/// Like [`try_call_every_second`], but the seconds follow `tz` instead of the local
+/// time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::{try_call_every_second_in, ErrorPolicy};
+///
+/// let policy = ErrorPolicy::on_error(|error: &std::io::Error| {
+///     eprintln!("touch failed: {}", error);
+/// });
+/// try_call_every_second_in(Berlin, || std::fs::write("heartbeat", b""), policy)
+///     .unwrap();
+/// ```
+///
+/// *This function requires the following crate features to be activated: `second`*
+#[cfg(feature = "second")]
+pub fn try_call_every_second_in<Tz, F, E>(
+    tz: Tz,
+    mut callback: F,
+    policy: ErrorPolicy<E>,
+) -> Result<(), E>
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> Result<(), E>,
+    E: fmt::Display + Send + 'static,
+{
+    Timer::every_second_in(tz).try_run(move |_| callback(), policy)
+}
+
+/// calls the given fallible async function every second in the given time zone
+///
+/// Like [`try_call_every_second_async`], but the seconds follow `tz` instead of the
+/// local time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::{try_call_every_second_in_async, ErrorPolicy};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// try_call_every_second_in_async(Berlin, || async {
+///     std::fs::write("heartbeat", b"")
+/// }, ErrorPolicy::Stop)
+/// .await
+/// .unwrap();
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `second`*
+#[cfg(feature = "second")]
+pub async fn try_call_every_second_in_async<Tz, F, Fut, E>(
+    tz: Tz,
+    mut callback: F,
+    policy: ErrorPolicy<E>,
+) -> Result<(), E>
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<(), E>>,
+    E: fmt::Display + Send + 'static,
+{
+    Timer::every_second_in(tz)
+        .try_run_async(move |_| callback(), policy)
+        .await
+}
+
+/// calls the given function every second on a background thread
+///
+/// Like [`call_every_second_mut`], but the timer runs on a thread named
+/// `zila-call-every-second` and the function returns right away with a
+/// [`TimerHandle`] that cancels the timer or waits for it to finish.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::spawn_call_every_second;
+///
+/// let handle = spawn_call_every_second(|| {
+///     println!("Hi");
+/// });
+///
+/// // stop the timer on shutdown
+/// handle.cancel();
+/// ```
+///
+/// *This function requires the following crate features to be activated: `second`*
+#[cfg(feature = "second")]
+pub fn spawn_call_every_second<F, R>(callback: F) -> TimerHandle<R::Output>
+where
+    F: FnMut() -> R + Send + 'static,
+    R: Flow,
+{
+    spawn_call_every_second_in(Local, callback)
+}
+
+/// calls the given function every second in the given time zone on a background thread
+///
+/// Like [`spawn_call_every_second`], but the seconds follow `tz` instead of the
+/// local time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::spawn_call_every_second_in;
+///
+/// let handle = spawn_call_every_second_in(Berlin, || {
+///     println!("Hi");
+/// });
+///
+/// // stop the timer on shutdown
+/// handle.cancel();
+/// ```
+///
+/// *This function requires the following crate features to be activated: `second`*
+#[cfg(feature = "second")]
+pub fn spawn_call_every_second_in<Tz, F, R>(tz: Tz, mut callback: F) -> TimerHandle<R::Output>
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> R + Send + 'static,
+    R: Flow,
+{
+    Timer::every_second_in(tz).spawn(move |_| callback())
+}
+
+/// calls the given function every week
+///
+/// Weeks start at midnight on `start`, pass `Weekday::Mon` for ISO 8601 weeks.
+///
+/// # Example
+///
+/// using a closure:
+///
+/// ```rust,no_run
+/// use chrono::Weekday;
+/// use zila::call_every_week;
+///
+/// call_every_week(Weekday::Mon, || {
+///     println!("Hi");
+/// });
+/// ```
+///
+/// using a function:
+///
+/// ```rust,no_run
+/// use chrono::Weekday;
+/// use zila::call_every_week;
+///
+/// fn callback() {
+///     println!("Hi");
+/// }
+///
+/// call_every_week(Weekday::Mon, callback);
+/// ```
+///
+/// *This function requires the following crate features to be activated: `week`*
+#[cfg(feature = "week")]
+pub fn call_every_week<F, R>(start: Weekday, callback: F) -> R::Output
+where
+    F: Fn() -> R,
+    R: Flow,
+{
+    call_every_week_in(start, Local, callback)
+}
+
+/// calls the given function every week, takes a `FnMut` as the argument
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use chrono::Weekday;
+/// use zila::call_every_week_mut;
+///
+/// let num = Rc::new(Cell::new(0));
+/// call_every_week_mut(Weekday::Mon, {
+///     let num = num.clone();
+///     move || {
+///         num.set(num.get() + 1);
+///     }
+/// });
+/// ```
+///
+/// *This function requires the following crate features to be activated: `week`*
+#[cfg(feature = "week")]
+pub fn call_every_week_mut<F, R>(start: Weekday, callback: F) -> R::Output
+where
+    F: FnMut() -> R,
+    R: Flow,
+{
+    call_every_week_in_mut(start, Local, callback)
+}
+
+/// calls the given async function every week
+///
+/// # Example
+///
+/// using a closure:
+///
+/// ```rust,no_run
+/// use chrono::Weekday;
+/// use zila::call_every_week_async;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// call_every_week_async(Weekday::Mon, || async {
+///     println!("Hi");
+/// })
+/// .await;
+/// # }
+/// ```
+///
+/// using a function:
+///
+/// ```rust,no_run
+/// use chrono::Weekday;
+/// use zila::call_every_week_async;
+///
+/// async fn callback() {
+///     println!("Hi");
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// call_every_week_async(Weekday::Mon, callback).await;
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `week`*
+#[cfg(feature = "week")]
+pub async fn call_every_week_async<F, Fut, R>(start: Weekday, callback: F) -> R::Output
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = R>,
+    R: Flow,
+{
+    call_every_week_in_async(start, Local, callback).await
+}
+
+/// calls the given async function every week, takes a `FnMut` as the argument
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use chrono::Weekday;
+/// use zila::call_every_week_async_mut;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let num = Rc::new(Cell::new(0));
+/// call_every_week_async_mut(Weekday::Mon, move || {
+///     let num = num.clone();
+///     async move {
+///         num.set(num.get() + 1);
+///         println!("This is printed {} times.", num.get());
+///     }
+/// })
+/// .await;
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `week`*
+#[cfg(feature = "week")]
+pub async fn call_every_week_async_mut<F, Fut, R>(start: Weekday, callback: F) -> R::Output
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = R>,
+    R: Flow,
+{
+    call_every_week_in_async_mut(start, Local, callback).await
+}
+
+/// calls the given function at the start of every week in the given time zone
+///
+/// Like [`call_every_week`], but the weeks follow `tz` instead of the local
+/// time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use chrono::Weekday;
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::call_every_week_in;
+///
+/// call_every_week_in(Weekday::Mon, Berlin, || {
+///     println!("Hi");
+/// });
+/// ```
+///
+/// *This function requires the following crate features to be activated: `week`*
+#[cfg(feature = "week")]
+pub fn call_every_week_in<Tz, F, R>(start: Weekday, tz: Tz, callback: F) -> R::Output
+where
+    Tz: TimeZone + Send + 'static,
+    F: Fn() -> R,
+    R: Flow,
+{
+    call_every_week_in_mut(start, tz, callback)
+}
+
+/// calls the given function at the start of every week in the given time zone, takes a `FnMut` as
+/// the argument
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use chrono::Weekday;
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::call_every_week_in_mut;
+///
+/// let num = Rc::new(Cell::new(0));
+/// call_every_week_in_mut(Weekday::Mon, Berlin, {
+///     let num = num.clone();
+///     move || {
+///         num.set(num.get() + 1);
+///     }
+/// });
+/// ```
+///
+/// *This function requires the following crate features to be activated: `week`*
+#[cfg(feature = "week")]
+pub fn call_every_week_in_mut<Tz, F, R>(start: Weekday, tz: Tz, mut callback: F) -> R::Output
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> R,
+    R: Flow,
+{
+    Timer::every_week_in(start, tz).run(move |_| callback())
+}
+
+/// calls the given async function at the start of every week in the given time zone
+///
+/// Like [`call_every_week_async`], but the weeks follow `tz` instead of the local
+/// time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use chrono::Weekday;
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::call_every_week_in_async;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// call_every_week_in_async(Weekday::Mon, Berlin, || async {
+///     println!("Hi");
+/// })
+/// .await;
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `week`*
+#[cfg(feature = "week")]
+pub async fn call_every_week_in_async<Tz, F, Fut, R>(
+    start: Weekday,
+    tz: Tz,
+    callback: F,
+) -> R::Output
+where
+    Tz: TimeZone + Send + 'static,
+    F: Fn() -> Fut,
+    Fut: Future<Output = R>,
+    R: Flow,
+{
+    call_every_week_in_async_mut(start, tz, callback).await
+}
+
+/// calls the given async function at the start of every week in the given time zone, takes a
+/// `FnMut` as the argument
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use chrono::Weekday;
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::call_every_week_in_async_mut;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let num = Rc::new(Cell::new(0));
+/// call_every_week_in_async_mut(Weekday::Mon, Berlin, move || {
+///     let num = num.clone();
+///     async move {
+///         num.set(num.get() + 1);
+///         println!("This is printed {} times.", num.get());
+///     }
+/// })
+/// .await;
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `week`*
+#[cfg(feature = "week")]
+pub async fn call_every_week_in_async_mut<Tz, F, Fut, R>(
+    start: Weekday,
+    tz: Tz,
+    mut callback: F,
+) -> R::Output
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = R>,
+    R: Flow,
+{
+    Timer::every_week_in(start, tz)
+        .run_async(move |_| callback())
+        .await
+}
+
+/// calls the given fallible function every week
+///
+/// Like [`call_every_week_mut`], but the function returns a `Result`. What
+/// happens to an error is decided by `policy`, see [`ErrorPolicy`]. When the
+/// policy stops the timer, the function returns the error.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use chrono::Weekday;
+/// use zila::{try_call_every_week, ErrorPolicy};
+///
+/// fn archive() -> std::io::Result<()> {
+///     std::fs::rename("report.csv", "report.old.csv")
+/// }
+///
+/// let result = try_call_every_week(Weekday::Mon, archive, ErrorPolicy::Stop);
+/// if let Err(error) = result {
+///     eprintln!("archive failed: {}", error);
+/// }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `week`*
+#[cfg(feature = "week")]
+pub fn try_call_every_week<F, E>(
+    start: Weekday,
+    callback: F,
+    policy: ErrorPolicy<E>,
+) -> Result<(), E>
+where
+    F: FnMut() -> Result<(), E>,
+    E: fmt::Display + Send + 'static,
+{
+    try_call_every_week_in(start, Local, callback, policy)
+}
+
+/// calls the given fallible async function every week
+///
+/// Like [`call_every_week_async_mut`], but the future returns a `Result`. What
+/// happens to an error is decided by `policy`, see [`ErrorPolicy`]. When the
+/// policy stops the timer, the function returns the error.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use chrono::Weekday;
+/// use zila::{try_call_every_week_async, ErrorPolicy};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// try_call_every_week_async(Weekday::Mon, || async {
+///     std::fs::rename("report.csv", "report.old.csv")
+/// }, ErrorPolicy::Log)
+/// .await
+/// .unwrap();
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `week`*
+#[cfg(feature = "week")]
+pub async fn try_call_every_week_async<F, Fut, E>(
+    start: Weekday,
+    callback: F,
+    policy: ErrorPolicy<E>,
+) -> Result<(), E>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<(), E>>,
+    E: fmt::Display + Send + 'static,
+{
+    try_call_every_week_in_async(start, Local, callback, policy).await
+}
+
+/// calls the given fallible function every week in the given time zone
+///
+/// Like [`try_call_every_week`], but the weeks follow `tz` instead of the local
+/// time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use chrono::Weekday;
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::{try_call_every_week_in, ErrorPolicy};
+///
+/// let policy = ErrorPolicy::on_error(|error: &std::io::Error| {
+///     eprintln!("archive failed: {}", error);
+/// });
+/// try_call_every_week_in(Weekday::Mon, Berlin, || std::fs::rename("report.csv", "report.old.csv"), policy)
+///     .unwrap();
+/// ```
+///
+/// *This function requires the following crate features to be activated: `week`*
+#[cfg(feature = "week")]
+pub fn try_call_every_week_in<Tz, F, E>(
+    start: Weekday,
+    tz: Tz,
+    mut callback: F,
+    policy: ErrorPolicy<E>,
+) -> Result<(), E>
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> Result<(), E>,
+    E: fmt::Display + Send + 'static,
+{
+    Timer::every_week_in(start, tz).try_run(move |_| callback(), policy)
+}
+
+/// calls the given fallible async function every week in the given time zone
+///
+/// Like [`try_call_every_week_async`], but the weeks follow `tz` instead of the
+/// local time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use chrono::Weekday;
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::{try_call_every_week_in_async, ErrorPolicy};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// try_call_every_week_in_async(Weekday::Mon, Berlin, || async {
+///     std::fs::rename("report.csv", "report.old.csv")
+/// }, ErrorPolicy::Stop)
+/// .await
+/// .unwrap();
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `week`*
+#[cfg(feature = "week")]
+pub async fn try_call_every_week_in_async<Tz, F, Fut, E>(
+    start: Weekday,
+    tz: Tz,
+    mut callback: F,
+    policy: ErrorPolicy<E>,
+) -> Result<(), E>
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<(), E>>,
+    E: fmt::Display + Send + 'static,
+{
+    Timer::every_week_in(start, tz)
+        .try_run_async(move |_| callback(), policy)
+        .await
+}
+
+/// calls the given function every week on a background thread
+///
+/// Like [`call_every_week_mut`], but the timer runs on a thread named
+/// `zila-call-every-week` and the function returns right away with a
+/// [`TimerHandle`] that cancels the timer or waits for it to finish.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use chrono::Weekday;
+/// use zila::spawn_call_every_week;
+///
+/// let handle = spawn_call_every_week(Weekday::Mon, || {
+///     println!("Hi");
+/// });
+///
+/// // stop the timer on shutdown
+/// handle.cancel();
+/// ```
+///
+/// *This function requires the following crate features to be activated: `week`*
+#[cfg(feature = "week")]
+pub fn spawn_call_every_week<F, R>(start: Weekday, callback: F) -> TimerHandle<R::Output>
+where
+    F: FnMut() -> R + Send + 'static,
+    R: Flow,
+{
+    spawn_call_every_week_in(start, Local, callback)
+}
+
+/// calls the given function every week in the given time zone on a background thread
+///
+/// Like [`spawn_call_every_week`], but the weeks follow `tz` instead of the
+/// local time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use chrono::Weekday;
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::spawn_call_every_week_in;
+///
+/// let handle = spawn_call_every_week_in(Weekday::Mon, Berlin, || {
+///     println!("Hi");
+/// });
+///
+/// // stop the timer on shutdown
+/// handle.cancel();
+/// ```
+///
+/// *This function requires the following crate features to be activated: `week`*
+#[cfg(feature = "week")]
+pub fn spawn_call_every_week_in<Tz, F, R>(
+    start: Weekday,
+    tz: Tz,
+    mut callback: F,
+) -> TimerHandle<R::Output>
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> R + Send + 'static,
+    R: Flow,
+{
+    Timer::every_week_in(start, tz).spawn(move |_| callback())
+}
+
+/// calls the given function every month
+///
+/// # Example
+///
+/// using a closure:
+///
+/// ```rust,no_run
+/// use zila::call_every_month;
+///
+/// call_every_month(|| {
+///     println!("Hi");
+/// });
+/// ```
+///
+/// using a function:
+///
+/// ```rust,no_run
+/// use zila::call_every_month;
+///
+/// fn callback() {
+///     println!("Hi");
+/// }
+///
+/// call_every_month(callback);
+/// ```
+///
+/// *This function requires the following crate features to be activated: `month`*
+#[cfg(feature = "month")]
+pub fn call_every_month<F, R>(callback: F) -> R::Output
+where
+    F: Fn() -> R,
+    R: Flow,
+{
+    call_every_month_in(Local, callback)
+}
+
+/// calls the given function every month, takes a `FnMut` as the argument
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use zila::call_every_month_mut;
+///
+/// let num = Rc::new(Cell::new(0));
+/// call_every_month_mut({
+///     let num = num.clone();
+///     move || {
+///         num.set(num.get() + 1);
+///     }
+/// });
+/// ```
+///
+/// *This function requires the following crate features to be activated: `month`*
+#[cfg(feature = "month")]
+pub fn call_every_month_mut<F, R>(callback: F) -> R::Output
+where
+    F: FnMut() -> R,
+    R: Flow,
+{
+    call_every_month_in_mut(Local, callback)
+}
+
+/// calls the given async function every month
+///
+/// # Example
+///
+/// using a closure:
+///
+/// ```rust,no_run
+/// use zila::call_every_month_async;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// call_every_month_async(|| async {
+///     println!("Hi");
+/// })
+/// .await;
+/// # }
+/// ```
+///
+/// using a function:
+///
+/// ```rust,no_run
+/// use zila::call_every_month_async;
+///
+/// async fn callback() {
+///     println!("Hi");
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// call_every_month_async(callback).await;
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `month`*
+#[cfg(feature = "month")]
+pub async fn call_every_month_async<F, Fut, R>(callback: F) -> R::Output
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = R>,
+    R: Flow,
+{
+    call_every_month_in_async(Local, callback).await
+}
+
+/// calls the given async function every month, takes a `FnMut` as the argument
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use zila::call_every_month_async_mut;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let num = Rc::new(Cell::new(0));
+/// call_every_month_async_mut(move || {
+///     let num = num.clone();
+///     async move {
+///         num.set(num.get() + 1);
+///         println!("This is printed {} times.", num.get());
+///     }
+/// })
+/// .await;
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `month`*
+#[cfg(feature = "month")]
+pub async fn call_every_month_async_mut<F, Fut, R>(callback: F) -> R::Output
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = R>,
+    R: Flow,
+{
+    call_every_month_in_async_mut(Local, callback).await
+}
+
+/// calls the given function at the start of every month in the given time zone
+///
+/// Like [`call_every_month`], but the months follow `tz` instead of the local
+/// time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::call_every_month_in;
+///
+/// call_every_month_in(Berlin, || {
+///     println!("Hi");
+/// });
+/// ```
+///
+/// *This function requires the following crate features to be activated: `month`*
+#[cfg(feature = "month")]
+pub fn call_every_month_in<Tz, F, R>(tz: Tz, callback: F) -> R::Output
+where
+    Tz: TimeZone + Send + 'static,
+    F: Fn() -> R,
+    R: Flow,
+{
+    call_every_month_in_mut(tz, callback)
+}
+
+/// calls the given function at the start of every month in the given time zone, takes a `FnMut` as
+/// the argument
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::call_every_month_in_mut;
+///
+/// let num = Rc::new(Cell::new(0));
+/// call_every_month_in_mut(Berlin, {
+///     let num = num.clone();
+///     move || {
+///         num.set(num.get() + 1);
+///     }
+/// });
+/// ```
+///
+/// *This function requires the following crate features to be activated: `month`*
+#[cfg(feature = "month")]
+pub fn call_every_month_in_mut<Tz, F, R>(tz: Tz, mut callback: F) -> R::Output
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> R,
+    R: Flow,
+{
+    Timer::every_month_in(tz).run(move |_| callback())
+}
+
+/// calls the given async function at the start of every month in the given time zone
+///
+/// Like [`call_every_month_async`], but the months follow `tz` instead of the local
+/// time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::call_every_month_in_async;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// call_every_month_in_async(Berlin, || async {
+///     println!("Hi");
+/// })
+/// .await;
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `month`*
+#[cfg(feature = "month")]
+pub async fn call_every_month_in_async<Tz, F, Fut, R>(tz: Tz, callback: F) -> R::Output
+where
+    Tz: TimeZone + Send + 'static,
+    F: Fn() -> Fut,
+    Fut: Future<Output = R>,
+    R: Flow,
+{
+    call_every_month_in_async_mut(tz, callback).await
+}
+
+/// calls the given async function at the start of every month in the given time zone, takes a
+/// `FnMut` as the argument
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::call_every_month_in_async_mut;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let num = Rc::new(Cell::new(0));
+/// call_every_month_in_async_mut(Berlin, move || {
+///     let num = num.clone();
+///     async move {
+///         num.set(num.get() + 1);
+///         println!("This is printed {} times.", num.get());
+///     }
+/// })
+/// .await;
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `month`*
+#[cfg(feature = "month")]
+pub async fn call_every_month_in_async_mut<Tz, F, Fut, R>(tz: Tz, mut callback: F) -> R::Output
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = R>,
+    R: Flow,
+{
+    Timer::every_month_in(tz)
+        .run_async(move |_| callback())
+        .await
+}
+
+/// calls the given fallible function every month
+///
+/// Like [`call_every_month_mut`], but the function returns a `Result`. What
+/// happens to an error is decided by `policy`, see [`ErrorPolicy`]. When the
+/// policy stops the timer, the function returns the error.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::{try_call_every_month, ErrorPolicy};
+///
+/// fn rotate_logs() -> std::io::Result<()> {
+///     std::fs::rename("app.log", "app.log.1")
+/// }
+///
+/// let result = try_call_every_month(rotate_logs, ErrorPolicy::Stop);
+/// if let Err(error) = result {
+///     eprintln!("rotate_logs failed: {}", error);
+/// }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `month`*
+#[cfg(feature = "month")]
+pub fn try_call_every_month<F, E>(callback: F, policy: ErrorPolicy<E>) -> Result<(), E>
+where
+    F: FnMut() -> Result<(), E>,
+    E: fmt::Display + Send + 'static,
+{
+    try_call_every_month_in(Local, callback, policy)
+}
+
+/// calls the given fallible async function every month
+///
+/// Like [`call_every_month_async_mut`], but the future returns a `Result`. What
+/// happens to an error is decided by `policy`, see [`ErrorPolicy`]. When the
+/// policy stops the timer, the function returns the error.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::{try_call_every_month_async, ErrorPolicy};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// try_call_every_month_async(|| async {
+///     std::fs::rename("app.log", "app.log.1")
+/// }, ErrorPolicy::Log)
+/// .await
+/// .unwrap();
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `month`*
+#[cfg(feature = "month")]
+pub async fn try_call_every_month_async<F, Fut, E>(
+    callback: F,
+    policy: ErrorPolicy<E>,
+) -> Result<(), E>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<(), E>>,
+    E: fmt::Display + Send + 'static,
+{
+    try_call_every_month_in_async(Local, callback, policy).await
+}
+
+/// calls the given fallible function every month in the given time zone
+///
+/// Like [`try_call_every_month`], but the months follow `tz` instead of the local
+/// time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::{try_call_every_month_in, ErrorPolicy};
+///
+/// let policy = ErrorPolicy::on_error(|error: &std::io::Error| {
+///     eprintln!("rotate_logs failed: {}", error);
+/// });
+/// try_call_every_month_in(Berlin, || std::fs::rename("app.log", "app.log.1"), policy)
+///     .unwrap();
+/// ```
+///
+/// *This function requires the following crate features to be activated: `month`*
+#[cfg(feature = "month")]
+pub fn try_call_every_month_in<Tz, F, E>(
+    tz: Tz,
+    mut callback: F,
+    policy: ErrorPolicy<E>,
+) -> Result<(), E>
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> Result<(), E>,
+    E: fmt::Display + Send + 'static,
+{
+    Timer::every_month_in(tz).try_run(move |_| callback(), policy)
+}
+
+/// calls the given fallible async function every month in the given time zone
+///
+/// Like [`try_call_every_month_async`], but the months follow `tz` instead of the
+/// local time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::{try_call_every_month_in_async, ErrorPolicy};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// try_call_every_month_in_async(Berlin, || async {
+///     std::fs::rename("app.log", "app.log.1")
+/// }, ErrorPolicy::Stop)
+/// .await
+/// .unwrap();
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `month`*
+#[cfg(feature = "month")]
+pub async fn try_call_every_month_in_async<Tz, F, Fut, E>(
+    tz: Tz,
+    mut callback: F,
+    policy: ErrorPolicy<E>,
+) -> Result<(), E>
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<(), E>>,
+    E: fmt::Display + Send + 'static,
+{
+    Timer::every_month_in(tz)
+        .try_run_async(move |_| callback(), policy)
+        .await
+}
+
+/// calls the given function every month on a background thread
+///
+/// Like [`call_every_month_mut`], but the timer runs on a thread named
+/// `zila-call-every-month` and the function returns right away with a
+/// [`TimerHandle`] that cancels the timer or waits for it to finish.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::spawn_call_every_month;
+///
+/// let handle = spawn_call_every_month(|| {
+///     println!("Hi");
+/// });
+///
+/// // stop the timer on shutdown
+/// handle.cancel();
+/// ```
+///
+/// *This function requires the following crate features to be activated: `month`*
+#[cfg(feature = "month")]
+pub fn spawn_call_every_month<F, R>(callback: F) -> TimerHandle<R::Output>
+where
+    F: FnMut() -> R + Send + 'static,
+    R: Flow,
+{
+    spawn_call_every_month_in(Local, callback)
+}
+
+/// calls the given function every month in the given time zone on a background thread
+///
+/// Like [`spawn_call_every_month`], but the months follow `tz` instead of the
+/// local time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::spawn_call_every_month_in;
+///
+/// let handle = spawn_call_every_month_in(Berlin, || {
+///     println!("Hi");
+/// });
+///
+/// // stop the timer on shutdown
+/// handle.cancel();
+/// ```
+///
+/// *This function requires the following crate features to be activated: `month`*
+#[cfg(feature = "month")]
+pub fn spawn_call_every_month_in<Tz, F, R>(tz: Tz, mut callback: F) -> TimerHandle<R::Output>
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> R + Send + 'static,
+    R: Flow,
+{
+    Timer::every_month_in(tz).spawn(move |_| callback())
+}
+
+/// calls the given function every quarter
+///
+/// # Example
+///
+/// using a closure:
+///
+/// ```rust,no_run
+/// use zila::call_every_quarter;
+///
+/// call_every_quarter(|| {
+///     println!("Hi");
+/// });
+/// ```
+///
+/// using a function:
+///
+/// ```rust,no_run
+/// use zila::call_every_quarter;
+///
+/// fn callback() {
+///     println!("Hi");
+/// }
+///
+/// call_every_quarter(callback);
+/// ```
+///
+/// *This function requires the following crate features to be activated: `quarter`*
+#[cfg(feature = "quarter")]
+pub fn call_every_quarter<F, R>(callback: F) -> R::Output
+where
+    F: Fn() -> R,
+    R: Flow,
+{
+    call_every_quarter_in(Local, callback)
+}
+
+/// calls the given function every quarter, takes a `FnMut` as the argument
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use zila::call_every_quarter_mut;
+///
+/// let num = Rc::new(Cell::new(0));
+/// call_every_quarter_mut({
+///     let num = num.clone();
+///     move || {
+///         num.set(num.get() + 1);
+///     }
+/// });
+/// ```
+///
+/// *This function requires the following crate features to be activated: `quarter`*
+#[cfg(feature = "quarter")]
+pub fn call_every_quarter_mut<F, R>(callback: F) -> R::Output
+where
+    F: FnMut() -> R,
+    R: Flow,
+{
+    call_every_quarter_in_mut(Local, callback)
+}
+
+/// calls the given async function every quarter
+///
+/// # Example
+///
+/// using a closure:
+///
+/// ```rust,no_run
+/// use zila::call_every_quarter_async;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// call_every_quarter_async(|| async {
+///     println!("Hi");
+/// })
+/// .await;
+/// # }
+/// ```
+///
+/// using a function:
+///
+/// ```rust,no_run
+/// use zila::call_every_quarter_async;
+///
+/// async fn callback() {
+///     println!("Hi");
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// call_every_quarter_async(callback).await;
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `quarter`*
+#[cfg(feature = "quarter")]
+pub async fn call_every_quarter_async<F, Fut, R>(callback: F) -> R::Output
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = R>,
+    R: Flow,
+{
+    call_every_quarter_in_async(Local, callback).await
+}
+
+/// calls the given async function every quarter, takes a `FnMut` as the argument
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use zila::call_every_quarter_async_mut;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let num = Rc::new(Cell::new(0));
+/// call_every_quarter_async_mut(move || {
+///     let num = num.clone();
+///     async move {
+///         num.set(num.get() + 1);
+///         println!("This is printed {} times.", num.get());
+///     }
+/// })
+/// .await;
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `quarter`*
+#[cfg(feature = "quarter")]
+pub async fn call_every_quarter_async_mut<F, Fut, R>(callback: F) -> R::Output
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = R>,
+    R: Flow,
+{
+    call_every_quarter_in_async_mut(Local, callback).await
+}
+
+/// calls the given function at the start of every quarter in the given time zone
+///
+/// Like [`call_every_quarter`], but the quarters follow `tz` instead of the
+/// local time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::call_every_quarter_in;
+///
+/// call_every_quarter_in(Berlin, || {
+///     println!("Hi");
+/// });
+/// ```
+///
+/// *This function requires the following crate features to be activated: `quarter`*
+#[cfg(feature = "quarter")]
+pub fn call_every_quarter_in<Tz, F, R>(tz: Tz, callback: F) -> R::Output
+where
+    Tz: TimeZone + Send + 'static,
+    F: Fn() -> R,
+    R: Flow,
+{
+    call_every_quarter_in_mut(tz, callback)
+}
+
+/// calls the given function at the start of every quarter in the given time zone, takes a `FnMut` as
+/// the argument
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::call_every_quarter_in_mut;
+///
+/// let num = Rc::new(Cell::new(0));
+/// call_every_quarter_in_mut(Berlin, {
+///     let num = num.clone();
+///     move || {
+///         num.set(num.get() + 1);
+///     }
+/// });
+/// ```
+///
+/// *This function requires the following crate features to be activated: `quarter`*
+#[cfg(feature = "quarter")]
+pub fn call_every_quarter_in_mut<Tz, F, R>(tz: Tz, mut callback: F) -> R::Output
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> R,
+    R: Flow,
+{
+    Timer::every_quarter_in(tz).run(move |_| callback())
+}
+
+/// calls the given async function at the start of every quarter in the given time zone
+///
+/// Like [`call_every_quarter_async`], but the quarters follow `tz` instead of the local
+/// time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::call_every_quarter_in_async;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// call_every_quarter_in_async(Berlin, || async {
+///     println!("Hi");
+/// })
+/// .await;
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `quarter`*
+#[cfg(feature = "quarter")]
+pub async fn call_every_quarter_in_async<Tz, F, Fut, R>(tz: Tz, callback: F) -> R::Output
+where
+    Tz: TimeZone + Send + 'static,
+    F: Fn() -> Fut,
+    Fut: Future<Output = R>,
+    R: Flow,
+{
+    call_every_quarter_in_async_mut(tz, callback).await
+}
+
+/// calls the given async function at the start of every quarter in the given time zone, takes a
+/// `FnMut` as the argument
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::call_every_quarter_in_async_mut;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let num = Rc::new(Cell::new(0));
+/// call_every_quarter_in_async_mut(Berlin, move || {
+///     let num = num.clone();
+///     async move {
+///         num.set(num.get() + 1);
+///         println!("This is printed {} times.", num.get());
+///     }
+/// })
+/// .await;
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `quarter`*
+#[cfg(feature = "quarter")]
+pub async fn call_every_quarter_in_async_mut<Tz, F, Fut, R>(tz: Tz, mut callback: F) -> R::Output
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = R>,
+    R: Flow,
+{
+    Timer::every_quarter_in(tz)
+        .run_async(move |_| callback())
+        .await
+}
+
+/// calls the given fallible function every quarter
+///
+/// Like [`call_every_quarter_mut`], but the function returns a `Result`. What
+/// happens to an error is decided by `policy`, see [`ErrorPolicy`]. When the
+/// policy stops the timer, the function returns the error.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::{try_call_every_quarter, ErrorPolicy};
+///
+/// fn archive() -> std::io::Result<()> {
+///     std::fs::rename("report.csv", "report.old.csv")
+/// }
+///
+/// let result = try_call_every_quarter(archive, ErrorPolicy::Stop);
+/// if let Err(error) = result {
+///     eprintln!("archive failed: {}", error);
+/// }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `quarter`*
+#[cfg(feature = "quarter")]
+pub fn try_call_every_quarter<F, E>(callback: F, policy: ErrorPolicy<E>) -> Result<(), E>
+where
+    F: FnMut() -> Result<(), E>,
+    E: fmt::Display + Send + 'static,
+{
+    try_call_every_quarter_in(Local, callback, policy)
+}
+
+/// calls the given fallible async function every quarter
+///
+/// Like [`call_every_quarter_async_mut`], but the future returns a `Result`.
+/// What happens to an error is decided by `policy`, see [`ErrorPolicy`]. When
+/// the policy stops the timer, the function returns the error.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::{try_call_every_quarter_async, ErrorPolicy};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// try_call_every_quarter_async(|| async {
+///     std::fs::rename("report.csv", "report.old.csv")
+/// }, ErrorPolicy::Log)
+/// .await
+/// .unwrap();
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `quarter`*
+#[cfg(feature = "quarter")]
+pub async fn try_call_every_quarter_async<F, Fut, E>(
+    callback: F,
+    policy: ErrorPolicy<E>,
+) -> Result<(), E>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<(), E>>,
+    E: fmt::Display + Send + 'static,
+{
+    try_call_every_quarter_in_async(Local, callback, policy).await
+}
+
+/// calls the given fallible function every quarter in the given time zone
+///
+/// Like [`try_call_every_quarter`], but the quarters follow `tz` instead of the local
+/// time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::{try_call_every_quarter_in, ErrorPolicy};
+///
+/// let policy = ErrorPolicy::on_error(|error: &std::io::Error| {
+///     eprintln!("archive failed: {}", error);
+/// });
+/// try_call_every_quarter_in(Berlin, || std::fs::rename("report.csv", "report.old.csv"), policy)
+///     .unwrap();
+/// ```
+///
+/// *This function requires the following crate features to be activated: `quarter`*
+#[cfg(feature = "quarter")]
+pub fn try_call_every_quarter_in<Tz, F, E>(
+    tz: Tz,
+    mut callback: F,
+    policy: ErrorPolicy<E>,
+) -> Result<(), E>
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> Result<(), E>,
+    E: fmt::Display + Send + 'static,
+{
+    Timer::every_quarter_in(tz).try_run(move |_| callback(), policy)
+}
+
+/// calls the given fallible async function every quarter in the given time zone
+///
+/// Like [`try_call_every_quarter_async`], but the quarters follow `tz` instead of the
+/// local time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::{try_call_every_quarter_in_async, ErrorPolicy};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// try_call_every_quarter_in_async(Berlin, || async {
+///     std::fs::rename("report.csv", "report.old.csv")
+/// }, ErrorPolicy::Stop)
+/// .await
+/// .unwrap();
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `quarter`*
+#[cfg(feature = "quarter")]
+pub async fn try_call_every_quarter_in_async<Tz, F, Fut, E>(
+    tz: Tz,
+    mut callback: F,
+    policy: ErrorPolicy<E>,
+) -> Result<(), E>
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<(), E>>,
+    E: fmt::Display + Send + 'static,
+{
+    Timer::every_quarter_in(tz)
+        .try_run_async(move |_| callback(), policy)
+        .await
+}
+
+/// calls the given function every quarter on a background thread
+///
+/// Like [`call_every_quarter_mut`], but the timer runs on a thread named
+/// `zila-call-every-quarter` and the function returns right away with a
+/// [`TimerHandle`] that cancels the timer or waits for it to finish.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::spawn_call_every_quarter;
+///
+/// let handle = spawn_call_every_quarter(|| {
+///     println!("Hi");
+/// });
+///
+/// // stop the timer on shutdown
+/// handle.cancel();
+/// ```
+///
+/// *This function requires the following crate features to be activated: `quarter`*
+#[cfg(feature = "quarter")]
+pub fn spawn_call_every_quarter<F, R>(callback: F) -> TimerHandle<R::Output>
+where
+    F: FnMut() -> R + Send + 'static,
+    R: Flow,
+{
+    spawn_call_every_quarter_in(Local, callback)
+}
+
+/// calls the given function every quarter in the given time zone on a background thread
+///
+/// Like [`spawn_call_every_quarter`], but the quarters follow `tz` instead of
+/// the local time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::spawn_call_every_quarter_in;
+///
+/// let handle = spawn_call_every_quarter_in(Berlin, || {
+///     println!("Hi");
+/// });
+///
+/// // stop the timer on shutdown
+/// handle.cancel();
+/// ```
+///
+/// *This function requires the following crate features to be activated: `quarter`*
+#[cfg(feature = "quarter")]
+pub fn spawn_call_every_quarter_in<Tz, F, R>(tz: Tz, mut callback: F) -> TimerHandle<R::Output>
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> R + Send + 'static,
+    R: Flow,
+{
+    Timer::every_quarter_in(tz).spawn(move |_| callback())
+}
+
+/// calls the given function every year
+///
+/// # Example
+///
+/// using a closure:
+///
+/// ```rust,no_run
+/// use zila::call_every_year;
+///
+/// call_every_year(|| {
+///     println!("Hi");
+/// });
+/// ```
+///
+/// using a function:
+///
+/// ```rust,no_run
+/// use zila::call_every_year;
+///
+/// fn callback() {
+///     println!("Hi");
+/// }
+///
+/// call_every_year(callback);
+/// ```
+///
+/// *This function requires the following crate features to be activated: `year`*
+#[cfg(feature = "year")]
+pub fn call_every_year<F, R>(callback: F) -> R::Output
+where
+    F: Fn() -> R,
+    R: Flow,
+{
+    call_every_year_in(Local, callback)
+}
+
+/// calls the given function every year, takes a `FnMut` as the argument
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use zila::call_every_year_mut;
+///
+/// let num = Rc::new(Cell::new(0));
+/// call_every_year_mut({
+///     let num = num.clone();
+///     move || {
+///         num.set(num.get() + 1);
+///     }
+/// });
+/// ```
+///
+/// *This function requires the following crate features to be activated: `year`*
+#[cfg(feature = "year")]
+pub fn call_every_year_mut<F, R>(callback: F) -> R::Output
+where
+    F: FnMut() -> R,
+    R: Flow,
+{
+    call_every_year_in_mut(Local, callback)
+}
+
+/// calls the given async function every year
+///
+/// # Example
+///
+/// using a closure:
+///
+/// ```rust,no_run
+/// use zila::call_every_year_async;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// call_every_year_async(|| async {
+///     println!("Hi");
+/// })
+/// .await;
+/// # }
+/// ```
+///
+/// using a function:
+///
+/// ```rust,no_run
+/// use zila::call_every_year_async;
+///
+/// async fn callback() {
+///     println!("Hi");
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// call_every_year_async(callback).await;
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `year`*
+#[cfg(feature = "year")]
+pub async fn call_every_year_async<F, Fut, R>(callback: F) -> R::Output
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = R>,
+    R: Flow,
+{
+    call_every_year_in_async(Local, callback).await
+}
+
+/// calls the given async function every year, takes a `FnMut` as the argument
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use zila::call_every_year_async_mut;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let num = Rc::new(Cell::new(0));
+/// call_every_year_async_mut(move || {
+///     let num = num.clone();
+///     async move {
+///         num.set(num.get() + 1);
+///         println!("This is printed {} times.", num.get());
+///     }
+/// })
+/// .await;
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `year`*
+#[cfg(feature = "year")]
+pub async fn call_every_year_async_mut<F, Fut, R>(callback: F) -> R::Output
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = R>,
+    R: Flow,
+{
+    call_every_year_in_async_mut(Local, callback).await
+}
+
+/// calls the given function at the start of every year in the given time zone
+///
+/// Like [`call_every_year`], but the years follow `tz` instead of the local
+/// time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::call_every_year_in;
+///
+/// call_every_year_in(Berlin, || {
+///     println!("Hi");
+/// });
+/// ```
+///
+/// *This function requires the following crate features to be activated: `year`*
+#[cfg(feature = "year")]
+pub fn call_every_year_in<Tz, F, R>(tz: Tz, callback: F) -> R::Output
+where
+    Tz: TimeZone + Send + 'static,
+    F: Fn() -> R,
+    R: Flow,
+{
+    call_every_year_in_mut(tz, callback)
+}
+
+/// calls the given function at the start of every year in the given time zone, takes a `FnMut` as
+/// the argument
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::call_every_year_in_mut;
+///
+/// let num = Rc::new(Cell::new(0));
+/// call_every_year_in_mut(Berlin, {
+///     let num = num.clone();
+///     move || {
+///         num.set(num.get() + 1);
+///     }
+/// });
+/// ```
+///
+/// *This function requires the following crate features to be activated: `year`*
+#[cfg(feature = "year")]
+pub fn call_every_year_in_mut<Tz, F, R>(tz: Tz, mut callback: F) -> R::Output
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> R,
+    R: Flow,
+{
+    Timer::every_year_in(tz).run(move |_| callback())
+}
+
+/// calls the given async function at the start of every year in the given time zone
+///
+/// Like [`call_every_year_async`], but the years follow `tz` instead of the local
+/// time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::call_every_year_in_async;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// call_every_year_in_async(Berlin, || async {
+///     println!("Hi");
+/// })
+/// .await;
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `year`*
+#[cfg(feature = "year")]
+pub async fn call_every_year_in_async<Tz, F, Fut, R>(tz: Tz, callback: F) -> R::Output
+where
+    Tz: TimeZone + Send + 'static,
+    F: Fn() -> Fut,
+    Fut: Future<Output = R>,
+    R: Flow,
+{
+    call_every_year_in_async_mut(tz, callback).await
+}
+
+/// calls the given async function at the start of every year in the given time zone, takes a
+/// `FnMut` as the argument
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::call_every_year_in_async_mut;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let num = Rc::new(Cell::new(0));
+/// call_every_year_in_async_mut(Berlin, move || {
+///     let num = num.clone();
+///     async move {
+///         num.set(num.get() + 1);
+///         println!("This is printed {} times.", num.get());
+///     }
+/// })
+/// .await;
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `year`*
+#[cfg(feature = "year")]
+pub async fn call_every_year_in_async_mut<Tz, F, Fut, R>(tz: Tz, mut callback: F) -> R::Output
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = R>,
+    R: Flow,
+{
+    Timer::every_year_in(tz)
+        .run_async(move |_| callback())
+        .await
+}
+
+/// calls the given fallible function every year
+///
+/// Like [`call_every_year_mut`], but the function returns a `Result`. What
+/// happens to an error is decided by `policy`, see [`ErrorPolicy`]. When the
+/// policy stops the timer, the function returns the error.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::{try_call_every_year, ErrorPolicy};
+///
+/// fn archive() -> std::io::Result<()> {
+///     std::fs::rename("report.csv", "report.old.csv")
+/// }
+///
+/// let result = try_call_every_year(archive, ErrorPolicy::Stop);
+/// if let Err(error) = result {
+///     eprintln!("archive failed: {}", error);
+/// }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `year`*
+#[cfg(feature = "year")]
+pub fn try_call_every_year<F, E>(callback: F, policy: ErrorPolicy<E>) -> Result<(), E>
+where
+    F: FnMut() -> Result<(), E>,
+    E: fmt::Display + Send + 'static,
+{
+    try_call_every_year_in(Local, callback, policy)
+}
+
+/// calls the given fallible async function every year
+///
+/// Like [`call_every_year_async_mut`], but the future returns a `Result`. What
+/// happens to an error is decided by `policy`, see [`ErrorPolicy`]. When the
+/// policy stops the timer, the function returns the error.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::{try_call_every_year_async, ErrorPolicy};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// try_call_every_year_async(|| async {
+///     std::fs::rename("report.csv", "report.old.csv")
+/// }, ErrorPolicy::Log)
+/// .await
+/// .unwrap();
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `year`*
+#[cfg(feature = "year")]
+pub async fn try_call_every_year_async<F, Fut, E>(
+    callback: F,
+    policy: ErrorPolicy<E>,
+) -> Result<(), E>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<(), E>>,
+    E: fmt::Display + Send + 'static,
+{
+    try_call_every_year_in_async(Local, callback, policy).await
+}
+
+/// calls the given fallible function every year in the given time zone
+///
+/// Like [`try_call_every_year`], but the years follow `tz` instead of the local
+/// time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::{try_call_every_year_in, ErrorPolicy};
+///
+/// let policy = ErrorPolicy::on_error(|error: &std::io::Error| {
+///     eprintln!("archive failed: {}", error);
+/// });
+/// try_call_every_year_in(Berlin, || std::fs::rename("report.csv", "report.old.csv"), policy)
+///     .unwrap();
+/// ```
+///
+/// *This function requires the following crate features to be activated: `year`*
+#[cfg(feature = "year")]
+pub fn try_call_every_year_in<Tz, F, E>(
+    tz: Tz,
+    mut callback: F,
+    policy: ErrorPolicy<E>,
+) -> Result<(), E>
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> Result<(), E>,
+    E: fmt::Display + Send + 'static,
+{
+    Timer::every_year_in(tz).try_run(move |_| callback(), policy)
+}
+
+/// calls the given fallible async function every year in the given time zone
+///
+/// Like [`try_call_every_year_async`], but the years follow `tz` instead of the
+/// local time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::{try_call_every_year_in_async, ErrorPolicy};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// try_call_every_year_in_async(Berlin, || async {
+///     std::fs::rename("report.csv", "report.old.csv")
+/// }, ErrorPolicy::Stop)
+/// .await
+/// .unwrap();
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `year`*
+#[cfg(feature = "year")]
+pub async fn try_call_every_year_in_async<Tz, F, Fut, E>(
+    tz: Tz,
+    mut callback: F,
+    policy: ErrorPolicy<E>,
+) -> Result<(), E>
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<(), E>>,
+    E: fmt::Display + Send + 'static,
+{
+    Timer::every_year_in(tz)
+        .try_run_async(move |_| callback(), policy)
+        .await
+}
+
+/// calls the given function every year on a background thread
+///
+/// Like [`call_every_year_mut`], but the timer runs on a thread named
+/// `zila-call-every-year` and the function returns right away with a
+/// [`TimerHandle`] that cancels the timer or waits for it to finish.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::spawn_call_every_year;
+///
+/// let handle = spawn_call_every_year(|| {
+///     println!("Hi");
+/// });
+///
+/// // stop the timer on shutdown
+/// handle.cancel();
+/// ```
+///
+/// *This function requires the following crate features to be activated: `year`*
+#[cfg(feature = "year")]
+pub fn spawn_call_every_year<F, R>(callback: F) -> TimerHandle<R::Output>
+where
+    F: FnMut() -> R + Send + 'static,
+    R: Flow,
+{
+    spawn_call_every_year_in(Local, callback)
+}
+
+/// calls the given function every year in the given time zone on a background thread
+///
+/// Like [`spawn_call_every_year`], but the years follow `tz` instead of the
+/// local time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::spawn_call_every_year_in;
+///
+/// let handle = spawn_call_every_year_in(Berlin, || {
+///     println!("Hi");
+/// });
+///
+/// // stop the timer on shutdown
+/// handle.cancel();
+/// ```
+///
+/// *This function requires the following crate features to be activated: `year`*
+#[cfg(feature = "year")]
+pub fn spawn_call_every_year_in<Tz, F, R>(tz: Tz, mut callback: F) -> TimerHandle<R::Output>
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> R + Send + 'static,
+    R: Flow,
+{
+    Timer::every_year_in(tz).spawn(move |_| callback())
+}
+
+/// calls the function after the specified duration
+///
+/// Use [`Timer::timeout`] to cancel the timeout, start it in the background or
+/// drive it with a [`Clock`].
+///
+/// # Example
+///
+/// using a closure:
+///
+/// ```rust
+/// use std::time::Duration;
+/// use zila::set_timeout;
+///
+/// set_timeout(|| {
+///     println!("Hi");
+/// }, Duration::from_millis(10));
+/// ```
+///
+/// using a function:
+///
+/// ```rust
+/// use std::time::Duration;
+/// use zila::set_timeout;
+///
+/// fn callback() {
+///     println!("Hi");
+/// }
+///
+/// set_timeout(callback, Duration::from_millis(10));
+/// ```
+///
+/// *This function requires the following crate features to be activated: `timeout`*
+#[cfg(feature = "timeout")]
+pub fn set_timeout<F>(callback: F, duration: Duration)
+where
+    F: Fn(),
+{
+    Timer::timeout(duration).run(move |_| callback())
+}
+
+/// calls the function after the specified duration, takes `FnMut` as the first argument
+///
+/// # Example
+///
+/// ```rust
+/// use std::{cell::Cell, rc::Rc};
+/// use std::time::Duration;
+/// use zila::set_timeout_mut;
+///
+/// let num = Rc::new(Cell::new(0));
+/// set_timeout_mut({
+///     let num = num.clone();
+///     move || {
+///         num.set(num.get() + 1);
+///     }
+/// }, Duration::from_millis(10));
+/// assert_eq!(num.get(), 1);
+/// ```
+///
+/// *This function requires the following crate features to be activated: `timeout`*
+#[cfg(feature = "timeout")]
+pub fn set_timeout_mut<F>(mut callback: F, duration: Duration)
+where
+    F: FnMut(),
+{
+    Timer::timeout(duration).run(move |_| callback())
+}
+
+/// calls the async function after the specified duration
+///
+/// # Example
+///
+/// using a closure:
+///
+/// ```rust
+/// use std::time::Duration;
+/// use zila::set_timeout_async;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// set_timeout_async(|| async {
+///     println!("Hi");
+/// }, Duration::from_millis(10))
+/// .await;
+/// # }
+/// ```
+///
+/// using a function:
+///
+/// ```rust
+/// use std::time::Duration;
+/// use zila::set_timeout_async;
+///
+/// async fn callback() {
+///     println!("Hi");
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// set_timeout_async(callback, Duration::from_millis(10)).await;
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `timeout`*
+#[cfg(feature = "timeout")]
+pub async fn set_timeout_async<F, Fut>(callback: F, duration: Duration)
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = ()>,
+{
+    Timer::timeout(duration)
+        .run_async(move |_| callback())
+        .await
+}
+
+/// calls the async function after the specified duration, takes `FnMut` as the first argument
+///
+/// # Example
+///
+/// ```rust
+/// use std::{cell::Cell, rc::Rc};
+/// use std::time::Duration;
+/// use zila::set_timeout_async_mut;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let num = Rc::new(Cell::new(0));
+/// set_timeout_async_mut({
+///     let num = num.clone();
+///     move || {
+///         let num = num.clone();
+///         async move {
+///             num.set(num.get() + 1);
+///         }
+///     }
+/// }, Duration::from_millis(10))
+/// .await;
+/// assert_eq!(num.get(), 1);
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `timeout`*
+#[cfg(feature = "timeout")]
+pub async fn set_timeout_async_mut<F, Fut>(mut callback: F, duration: Duration)
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = ()>,
+{
+    Timer::timeout(duration)
+        .run_async(move |_| callback())
+        .await
+}
+
+/// calls the fallible function after the specified duration
+///
+/// Like [`set_timeout`], but the function returns a `Result`. What happens to an
+/// error is decided by `policy`, see [`ErrorPolicy`]. When the policy stops the
+/// timer, the error is returned, the other policies handle the error and return
+/// `Ok(())`.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use zila::{try_set_timeout, ErrorPolicy};
+///
+/// let result = try_set_timeout(
+///     || "42".parse::<u8>().map(|_| ()),
+///     Duration::from_millis(10),
+///     ErrorPolicy::Stop,
+/// );
+/// assert_eq!(result, Ok(()));
+/// ```
+///
+/// *This function requires the following crate features to be activated: `timeout`*
+#[cfg(feature = "timeout")]
+pub fn try_set_timeout<F, E>(
+    callback: F,
+    duration: Duration,
+    policy: ErrorPolicy<E>,
+) -> Result<(), E>
+where
+    F: FnOnce() -> Result<(), E>,
+    E: fmt::Display + Send + 'static,
+{
+    let mut callback = Some(callback);
+    Timer::timeout(duration).try_run(
+        move |_| callback.take().expect("a timeout fires once")(),
+        policy,
+    )
+}
+
+/// calls the fallible async function after the specified duration
+///
+/// Like [`set_timeout_async`], but the future returns a `Result`. What happens
+/// to an error is decided by `policy`, see [`ErrorPolicy`]. When the policy
+/// stops the timer, the error is returned, the other policies handle the error
+/// and return `Ok(())`.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use zila::{try_set_timeout_async, ErrorPolicy};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let result = try_set_timeout_async(
+///     || async { "256".parse::<u8>().map(|_| ()) },
+///     Duration::from_millis(10),
+///     ErrorPolicy::Stop,
+/// )
+/// .await;
+/// assert!(result.is_err());
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `timeout`*
+#[cfg(feature = "timeout")]
+pub async fn try_set_timeout_async<F, Fut, E>(
+    callback: F,
+    duration: Duration,
+    policy: ErrorPolicy<E>,
+) -> Result<(), E>
+where
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Result<(), E>>,
+    E: fmt::Display + Send + 'static,
+{
+    let mut callback = Some(callback);
+    Timer::timeout(duration)
+        .try_run_async(
+            move |_| callback.take().expect("a timeout fires once")(),
+            policy,
+        )
+        .await
+}
+
+/// calls the function after the specified duration on a background thread
+///
+/// Like [`set_timeout`], but the timer runs on a thread named
+/// `zila-set-timeout` and the function returns right away with a
+/// [`TimerHandle`] that cancels the timeout or waits for it to finish.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use zila::spawn_set_timeout;
+///
+/// let handle = spawn_set_timeout(|| {
+///     println!("never printed");
+/// }, Duration::from_secs(60));
+///
+/// handle.cancel();
+/// assert!(handle.join().unwrap_err().is_cancelled());
+/// ```
+///
+/// *This function requires the following crate features to be activated: `timeout`*
+#[cfg(feature = "timeout")]
+pub fn spawn_set_timeout<F>(callback: F, duration: Duration) -> TimerHandle
+where
+    F: FnOnce() + Send + 'static,
+{
+    let mut callback = Some(callback);
+    Timer::timeout(duration).spawn(move |_| callback.take().expect("a timeout fires once")())
+}
+
+/// calls the function in the specified intervals
+///
+/// The interval is measured from the moment the function returns, so the time the function
+/// takes adds up. Use [`Timer::fixed_rate`] to call it at fixed deadlines instead.
+///
+/// # Example
+///
+/// using a closure:
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use zila::set_interval;
+///
+/// set_interval(|| {
+///     println!("Hi");
+/// }, Duration::from_secs(1));
+/// ```
+///
+/// using a function:
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use zila::set_interval;
 ///
 /// fn callback() {
 ///     println!("Hi");
@@ -887,23 +4719,24 @@ where
 ///
 /// set_interval(callback, Duration::from_secs(1));
 /// ```
+///
 /// *This function requires the following crate features to be activated: `interval`*
 #[cfg(feature = "interval")]
-pub fn set_interval<F>(callback: F, duration: Duration)
+pub fn set_interval<F, R>(callback: F, duration: Duration) -> R::Output
 where
-    F: Fn(),
+    F: Fn() -> R,
+    R: Flow,
 {
-    loop {
-        std::thread::sleep(duration);
-        callback();
-    }
+    Timer::interval(duration).run(move |_| callback())
 }
 
 /// calls the function in the specified intervals, takes `FnMut` as the first argument
 ///
 /// # Example
 ///
-/// ```rust
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use std::time::Duration;
 /// use zila::set_interval_mut;
 ///
 /// let num = Rc::new(Cell::new(0));
@@ -916,82 +4749,490 @@ where
 /// ```
 ///
 /// *This function requires the following crate features to be activated: `interval`*
-#[cfg(feature = "timeout")]
-pub fn set_interval_mut<F>(mut callback: F, duration: Duration)
+#[cfg(feature = "interval")]
+pub fn set_interval_mut<F, R>(mut callback: F, duration: Duration) -> R::Output
 where
-    F: FnMut(),
+    F: FnMut() -> R,
+    R: Flow,
 {
-    loop {
-        std::thread::sleep(duration);
-        callback();
-    }
+    Timer::interval(duration).run(move |_| callback())
 }
 
 /// calls the async function in the specified intervals
 ///
+/// The interval is measured from the moment the returned future completes. Use
+/// [`Timer::fixed_rate`] to call it at fixed deadlines instead.
+///
 /// # Example
 ///
 /// using a closure:
 ///
-/// ```rust
+/// ```rust,no_run
+/// use std::time::Duration;
 /// use zila::set_interval_async;
 ///
-/// // async closures are unstable
-/// set_interval_async(async || {
+/// # #[tokio::main]
+/// # async fn main() {
+/// set_interval_async(|| async {
 ///     println!("Hi");
-/// }, Duration::from_secs(1)).await;
+/// }, Duration::from_secs(1))
+/// .await;
+/// # }
 /// ```
 ///
 /// using a function:
 ///
-/// ```rust
+/// ```rust,no_run
+/// use std::time::Duration;
 /// use zila::set_interval_async;
 ///
 /// async fn callback() {
 ///     println!("Hi");
 /// }
 ///
+/// # #[tokio::main]
+/// # async fn main() {
 /// set_interval_async(callback, Duration::from_secs(1)).await;
+/// # }
 /// ```
+///
 /// *This function requires the following crate features to be activated: `interval`*
 #[cfg(feature = "interval")]
-pub async fn set_interval_async<F, Fut>(callback: F, duration: Duration)
+pub async fn set_interval_async<F, Fut, R>(callback: F, duration: Duration) -> R::Output
 where
     F: Fn() -> Fut,
-    Fut: Future<Output = ()>,
+    Fut: Future<Output = R>,
+    R: Flow,
 {
-    loop {
-        sleep(duration).await;
-        callback().await;
-    }
+    Timer::interval(duration)
+        .run_async(move |_| callback())
+        .await
 }
 
 /// calls the async function in the specified intervals, takes `FnMut` as the first argument
 ///
 /// # Example
 ///
-/// ```rust
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use std::time::Duration;
 /// use zila::set_interval_async_mut;
 ///
+/// # #[tokio::main]
+/// # async fn main() {
 /// let num = Rc::new(Cell::new(0));
 /// set_interval_async_mut(move || {
 ///     let num = num.clone();
-///     // async closures are unstable
-///     async move || {
+///     async move {
 ///         num.set(num.get() + 1);
+///         println!("This is printed {} times.", num.get());
 ///     }
-/// }(), Duration::from_secs(1)).await;
+/// }, Duration::from_secs(1))
+/// .await;
+/// # }
 /// ```
 ///
 /// *This function requires the following crate features to be activated: `interval`*
-#[cfg(feature = "timeout")]
-pub async fn set_interval_async_mut<F, Fut>(mut callback: F, duration: Duration)
+#[cfg(feature = "interval")]
+pub async fn set_interval_async_mut<F, Fut, R>(mut callback: F, duration: Duration) -> R::Output
 where
     F: FnMut() -> Fut,
-    Fut: Future<Output = ()>,
+    Fut: Future<Output = R>,
+    R: Flow,
+{
+    Timer::interval(duration)
+        .run_async(move |_| callback())
+        .await
+}
+
+/// calls the fallible function in the specified intervals
+///
+/// Like [`set_interval_mut`], but the function returns a `Result`. What happens
+/// to an error is decided by `policy`, see [`ErrorPolicy`]. When the policy
+/// stops the timer, the function returns the error.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use zila::{try_set_interval, ErrorPolicy};
+///
+/// let mut countdown = 3u8;
+/// let result = try_set_interval(
+///     move || {
+///         countdown = countdown.checked_sub(1).ok_or("countdown ran out")?;
+///         Ok(())
+///     },
+///     Duration::from_millis(1),
+///     ErrorPolicy::Stop,
+/// );
+/// assert_eq!(result, Err("countdown ran out"));
+/// ```
+///
+/// *This function requires the following crate features to be activated: `interval`*
+#[cfg(feature = "interval")]
+pub fn try_set_interval<F, E>(
+    mut callback: F,
+    duration: Duration,
+    policy: ErrorPolicy<E>,
+) -> Result<(), E>
+where
+    F: FnMut() -> Result<(), E>,
+    E: fmt::Display + Send + 'static,
+{
+    Timer::interval(duration).try_run(move |_| callback(), policy)
+}
+
+/// calls the fallible async function in the specified intervals
+///
+/// Like [`set_interval_async_mut`], but the future returns a `Result`. What
+/// happens to an error is decided by `policy`, see [`ErrorPolicy`]. When the
+/// policy stops the timer, the function returns the error.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use zila::{try_set_interval_async, ErrorPolicy};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// try_set_interval_async(
+///     || async { std::fs::write("heartbeat", b"") },
+///     Duration::from_secs(1),
+///     ErrorPolicy::Log,
+/// )
+/// .await
+/// .unwrap();
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `interval`*
+#[cfg(feature = "interval")]
+pub async fn try_set_interval_async<F, Fut, E>(
+    mut callback: F,
+    duration: Duration,
+    policy: ErrorPolicy<E>,
+) -> Result<(), E>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<(), E>>,
+    E: fmt::Display + Send + 'static,
+{
+    Timer::interval(duration)
+        .try_run_async(move |_| callback(), policy)
+        .await
+}
+
+/// calls the function in the specified intervals on a background thread
+///
+/// Like [`set_interval_mut`], but the timer runs on a thread named
+/// `zila-set-interval` and the function returns right away with a
+/// [`TimerHandle`] that cancels the timer or waits for it to finish.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use zila::spawn_set_interval;
+///
+/// let handle = spawn_set_interval(|| {
+///     println!("Hi");
+/// }, Duration::from_millis(10));
+///
+/// std::thread::sleep(Duration::from_millis(50));
+/// handle.cancel();
+/// assert!(handle.join().unwrap_err().is_cancelled());
+/// ```
+///
+/// *This function requires the following crate features to be activated: `interval`*
+#[cfg(feature = "interval")]
+pub fn spawn_set_interval<F, R>(mut callback: F, duration: Duration) -> TimerHandle<R::Output>
+where
+    F: FnMut() -> R + Send + 'static,
+    R: Flow,
+{
+    Timer::interval(duration).spawn(move |_| callback())
+}
+
+/// Returns the duration to the next multiple of `period` since the Unix epoch
+///
+/// Hosts with synchronized clocks see the same multiples, whatever their time
+/// zone, see [`Aligned`].
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use zila::duration_to_next_multiple;
+///
+/// let duration = duration_to_next_multiple(Duration::from_secs(5 * 60));
+/// assert!(duration <= Duration::from_secs(5 * 60));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `period` is zero.
+///
+/// *This function requires the following crate features to be activated: `interval`*
+#[cfg(feature = "interval")]
+pub fn duration_to_next_multiple(period: Duration) -> Duration {
+    duration_to_next_in(&Aligned::new(period), &Utc).expect("date out of range")
+}
+
+/// calls the given function at every multiple of `period` since the Unix epoch
+///
+/// Unlike [`set_interval`], the calls are aligned to the wall clock, every 5
+/// minutes means on the `:00`, `:05` and `:10` marks no matter when the timer
+/// started.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use zila::call_every_aligned;
+///
+/// call_every_aligned(Duration::from_secs(5 * 60), || {
+///     println!("Hi");
+/// });
+/// ```
+///
+/// # Panics
+///
+/// Panics if `period` is zero.
+///
+/// *This function requires the following crate features to be activated: `interval`*
+#[cfg(feature = "interval")]
+pub fn call_every_aligned<F, R>(period: Duration, callback: F) -> R::Output
+where
+    F: Fn() -> R,
+    R: Flow,
+{
+    call_every_aligned_mut(period, callback)
+}
+
+/// calls the given function at every multiple of `period` since the Unix epoch, takes a
+/// `FnMut` as the argument
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use std::time::Duration;
+/// use zila::call_every_aligned_mut;
+///
+/// let num = Rc::new(Cell::new(0));
+/// call_every_aligned_mut(Duration::from_millis(250), {
+///     let num = num.clone();
+///     move || {
+///         num.set(num.get() + 1);
+///     }
+/// });
+/// ```
+///
+/// # Panics
+///
+/// Panics if `period` is zero.
+///
+/// *This function requires the following crate features to be activated: `interval`*
+#[cfg(feature = "interval")]
+pub fn call_every_aligned_mut<F, R>(period: Duration, mut callback: F) -> R::Output
+where
+    F: FnMut() -> R,
+    R: Flow,
+{
+    Timer::aligned(period).run(move |_| callback())
+}
+
+/// calls the given async function at every multiple of `period` since the Unix epoch
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use zila::call_every_aligned_async;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// call_every_aligned_async(Duration::from_secs(5 * 60), || async {
+///     println!("Hi");
+/// })
+/// .await;
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Panics if called outside of a tokio runtime or if `period` is zero.
+///
+/// *This function requires the following crate features to be activated: `interval`*
+#[cfg(feature = "interval")]
+pub async fn call_every_aligned_async<F, Fut, R>(period: Duration, callback: F) -> R::Output
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = R>,
+    R: Flow,
+{
+    call_every_aligned_async_mut(period, callback).await
+}
+
+/// calls the given async function at every multiple of `period` since the Unix epoch, takes
+/// a `FnMut` as the argument
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use std::time::Duration;
+/// use zila::call_every_aligned_async_mut;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let num = Rc::new(Cell::new(0));
+/// call_every_aligned_async_mut(Duration::from_millis(250), move || {
+///     let num = num.clone();
+///     async move {
+///         num.set(num.get() + 1);
+///         println!("This is printed {} times.", num.get());
+///     }
+/// })
+/// .await;
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Panics if called outside of a tokio runtime or if `period` is zero.
+///
+/// *This function requires the following crate features to be activated: `interval`*
+#[cfg(feature = "interval")]
+pub async fn call_every_aligned_async_mut<F, Fut, R>(period: Duration, mut callback: F) -> R::Output
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = R>,
+    R: Flow,
+{
+    Timer::aligned(period).run_async(move |_| callback()).await
+}
+
+/// calls the given fallible function at every multiple of `period` since the
+/// Unix epoch
+///
+/// Like [`call_every_aligned_mut`], but the function returns a `Result`. What
+/// happens to an error is decided by `policy`, see [`ErrorPolicy`]. When the
+/// policy stops the timer, the function returns the error.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use zila::{try_call_every_aligned, ErrorPolicy};
+///
+/// fn rotate_logs() -> std::io::Result<()> {
+///     std::fs::rename("app.log", "app.log.1")
+/// }
+///
+/// let period = Duration::from_secs(5 * 60);
+/// let result = try_call_every_aligned(period, rotate_logs, ErrorPolicy::Stop);
+/// if let Err(error) = result {
+///     eprintln!("rotate_logs failed: {}", error);
+/// }
+/// ```
+///
+/// # Panics
+///
+/// Panics if `period` is zero.
+///
+/// *This function requires the following crate features to be activated: `interval`*
+#[cfg(feature = "interval")]
+pub fn try_call_every_aligned<F, E>(
+    period: Duration,
+    mut callback: F,
+    policy: ErrorPolicy<E>,
+) -> Result<(), E>
+where
+    F: FnMut() -> Result<(), E>,
+    E: fmt::Display + Send + 'static,
+{
+    Timer::aligned(period).try_run(move |_| callback(), policy)
+}
+
+/// calls the given fallible async function at every multiple of `period` since
+/// the Unix epoch
+///
+/// Like [`call_every_aligned_async_mut`], but the future returns a `Result`.
+/// What happens to an error is decided by `policy`, see [`ErrorPolicy`]. When
+/// the policy stops the timer, the function returns the error.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use zila::{try_call_every_aligned_async, ErrorPolicy};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let period = Duration::from_secs(5 * 60);
+/// try_call_every_aligned_async(period, || async {
+///     std::fs::rename("app.log", "app.log.1")
+/// }, ErrorPolicy::Log)
+/// .await
+/// .unwrap();
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Panics if called outside of a tokio runtime or if `period` is zero.
+///
+/// *This function requires the following crate features to be activated: `interval`*
+#[cfg(feature = "interval")]
+pub async fn try_call_every_aligned_async<F, Fut, E>(
+    period: Duration,
+    mut callback: F,
+    policy: ErrorPolicy<E>,
+) -> Result<(), E>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<(), E>>,
+    E: fmt::Display + Send + 'static,
+{
+    Timer::aligned(period)
+        .try_run_async(move |_| callback(), policy)
+        .await
+}
+
+/// calls the given function at every multiple of `period` since the Unix epoch
+/// on a background thread
+///
+/// Like [`call_every_aligned_mut`], but the timer runs on a thread named
+/// `zila-call-every-aligned` and the function returns right away with a
+/// [`TimerHandle`] that cancels the timer or waits for it to finish.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use zila::spawn_call_every_aligned;
+///
+/// let handle = spawn_call_every_aligned(Duration::from_secs(5 * 60), || {
+///     println!("Hi");
+/// });
+///
+/// // stop the timer on shutdown
+/// handle.cancel();
+/// ```
+///
+/// # Panics
+///
+/// Panics if `period` is zero.
+///
+/// *This function requires the following crate features to be activated: `interval`*
+#[cfg(feature = "interval")]
+pub fn spawn_call_every_aligned<F, R>(period: Duration, mut callback: F) -> TimerHandle<R::Output>
+where
+    F: FnMut() -> R + Send + 'static,
+    R: Flow,
 {
-    loop {
-        sleep(duration).await;
-        callback().await;
-    }
+    Timer::aligned(period).spawn(move |_| callback())
 }
@@ -0,0 +1,719 @@
+//! systemd calendar event schedules.
+
+use crate::calendar::{self, Fold};
+use crate::describe;
+use crate::Schedule;
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, TimeZone, Timelike, Utc};
+use std::fmt;
+use std::str::FromStr;
+
+/// A parsed systemd calendar event, the syntax of `OnCalendar=` in systemd
+/// timer units.
+///
+/// An event has up to four parts, all of them optional but at least one of
+/// the first three:
+///
+/// ```text
+/// Mon..Fri 2023-*-01 09:00:00 Europe/Berlin
+/// │        │         │        └ time zone, UTC or an IANA name
+/// │        │         └───────── hour:minute[:second], 00:00:00 if left out
+/// │        └─────────────────── [year-]month-day, *-*-* if left out
+/// └──────────────────────────── days of the week, every day if left out
+/// ```
+///
+/// Every component of the date and the time accepts `*`, single values,
+/// ranges (`1..5`), repetitions (`0/15`, `1..20/5`) and comma separated lists
+/// of those. Days of the week are names like `Mon` or `Monday`, and ranges of
+/// them like `Mon..Fri`. A `~` instead of the second `-` counts the days from
+/// the end of the month, `*-02~01` is the last day of February. A date must
+/// match both the day of the week and the date, unlike in cron.
+///
+/// The shorthands `minutely`, `hourly`, `daily`, `weekly`, `monthly`,
+/// `quarterly`, `semiannually`, `yearly` and `annually` are accepted too.
+///
+/// Without a time zone, events are evaluated in the time zone of the timer.
+/// IANA time zones need the `chrono-tz` feature. Daylight saving time is
+/// handled like in [`Cron`](crate::Cron) expressions.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use zila::{OnCalendar, Schedule};
+///
+/// let event: OnCalendar = "Mon..Fri *-*-* 09:00:00".parse().unwrap();
+/// // a Saturday
+/// let time = Utc.with_ymd_and_hms(2023, 5, 13, 12, 0, 0).unwrap();
+/// let next = Utc.with_ymd_and_hms(2023, 5, 15, 9, 0, 0).unwrap();
+/// assert_eq!(event.next_after(&time), Some(next));
+///
+/// let error = "Mon..Fru 09:00".parse::<OnCalendar>().unwrap_err();
+/// assert_eq!(error.position(), 5);
+/// ```
+///
+/// *This type requires the following crate features to be activated: `systemd`*
+#[derive(Clone, PartialEq, Eq)]
+pub struct OnCalendar {
+    source: String,
+    /// bit 0 is Monday
+    weekdays: u8,
+    /// every year if empty
+    years: Vec<i32>,
+    months: u64,
+    days: u64,
+    /// `days` counts from the end of the month, the last day is 1
+    days_from_end: bool,
+    hours: u64,
+    minutes: u64,
+    seconds: u64,
+    /// `false` if the hour is `*`
+    hours_restricted: bool,
+    zone: Zone,
+}
+
+impl OnCalendar {
+    /// Parses a calendar event, same as [`str::parse`].
+    pub fn parse(expression: &str) -> Result<OnCalendar, OnCalendarError> {
+        expression.parse()
+    }
+
+    /// Returns the first instant strictly after `time` that matches the
+    /// event, `None` if the event never matches again.
+    ///
+    /// Events without a time zone are evaluated in the time zone of `time`.
+    pub fn next_after<Tz: TimeZone>(&self, time: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        let tz = time.timezone();
+        match self.zone {
+            Zone::Local => self.next_after_local(time),
+            Zone::Utc => self
+                .next_after_local(&time.with_timezone(&Utc))
+                .map(|next| next.with_timezone(&tz)),
+            #[cfg(feature = "chrono-tz")]
+            Zone::Named(zone) => self
+                .next_after_local(&time.with_timezone(&zone))
+                .map(|next| next.with_timezone(&tz)),
+        }
+    }
+
+    fn next_after_local<Tz: TimeZone>(&self, time: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        let fold = if self.hours_restricted {
+            Fold::Skip
+        } else {
+            Fold::Repeat
+        };
+        calendar::next_after(time, fold, |from| self.next_local(from))
+    }
+
+    /// Returns the first local time equal to or after `from` that matches.
+    fn next_local(&self, from: NaiveDateTime) -> Option<NaiveDateTime> {
+        // every pattern of the calendar repeats after 400 years
+        let limit = from.year() + 400;
+        let mut time = from.with_nanosecond(0)?;
+        loop {
+            let date = time.date();
+            if date.year() > limit {
+                return None;
+            }
+            if !self.years.is_empty() && !self.years.contains(&date.year()) {
+                if self.years.iter().all(|&year| year < date.year()) {
+                    return None;
+                }
+                time = NaiveDate::from_ymd_opt(date.year() + 1, 1, 1)?.and_hms_opt(0, 0, 0)?;
+            } else if !contains(self.months, date.month()) {
+                time = first_of_next_month(date)?.and_hms_opt(0, 0, 0)?;
+            } else if !self.matches_day(date) {
+                time = date.succ_opt()?.and_hms_opt(0, 0, 0)?;
+            } else if !contains(self.hours, time.hour()) {
+                time = date.and_hms_opt(time.hour(), 0, 0)? + chrono::Duration::hours(1);
+            } else if !contains(self.minutes, time.minute()) {
+                time =
+                    date.and_hms_opt(time.hour(), time.minute(), 0)? + chrono::Duration::minutes(1);
+            } else if !contains(self.seconds, time.second()) {
+                time += chrono::Duration::seconds(1);
+            } else {
+                return Some(time);
+            }
+        }
+    }
+
+    fn matches_day(&self, date: NaiveDate) -> bool {
+        let day = if self.days_from_end {
+            let last = first_of_next_month(date).and_then(|first| first.pred_opt());
+            match last {
+                Some(last) => last.day() - date.day() + 1,
+                None => return false,
+            }
+        } else {
+            date.day()
+        };
+        self.weekdays & (1 << date.weekday().num_days_from_monday()) != 0
+            && contains(self.days, day)
+    }
+}
+
+fn contains(bits: u64, value: u32) -> bool {
+    bits & (1 << value) != 0
+}
+
+fn first_of_next_month(date: NaiveDate) -> Option<NaiveDate> {
+    match date.month() {
+        12 => NaiveDate::from_ymd_opt(date.year() + 1, 1, 1),
+        month => NaiveDate::from_ymd_opt(date.year(), month + 1, 1),
+    }
+}
+
+impl Schedule for OnCalendar {
+    fn next_after<Tz: TimeZone>(&self, time: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        OnCalendar::next_after(self, time)
+    }
+
+    fn describe(&self) -> String {
+        const EVERY_DAY: u64 = 0xffff_fffe;
+        let description = describe::Fields {
+            seconds: self.seconds,
+            minutes: self.minutes,
+            hours: self.hours,
+            days: (self.days_from_end || self.days & EVERY_DAY != EVERY_DAY).then_some(self.days),
+            days_from_end: self.days_from_end,
+            weekdays: (self.weekdays != 0b111_1111).then_some(self.weekdays),
+            either: false,
+            months: self.months,
+            years: self.years.clone(),
+        }
+        .describe();
+        match self.zone.name() {
+            Some(zone) => format!("{} ({})", description, zone),
+            None => description,
+        }
+    }
+
+    fn describe_in(&self, zone: impl fmt::Display) -> String {
+        match self.zone {
+            Zone::Local => format!("{} ({})", self.describe(), zone),
+            _ => self.describe(),
+        }
+    }
+}
+
+impl FromStr for OnCalendar {
+    type Err = OnCalendarError;
+
+    fn from_str(source: &str) -> Result<OnCalendar, OnCalendarError> {
+        let mut tokens = split_tokens(source);
+        if tokens.is_empty() {
+            return Err(OnCalendarError::new(0, OnCalendarErrorKind::Empty));
+        }
+        let (position, first) = tokens[0];
+        if let Some(expansion) = shorthand(first) {
+            let expanded = expansion.split(' ').map(|token| (position, token));
+            tokens.splice(0..1, expanded);
+        }
+
+        let mut tokens = tokens.into_iter().peekable();
+        let weekdays = tokens.next_if(|&(_, token)| is_weekdays(token));
+        let date = tokens.next_if(|&(_, token)| {
+            token.starts_with(|char: char| char == '*' || char.is_ascii_digit())
+                && token.contains(['-', '~'])
+                && !token.contains(':')
+        });
+        let time = tokens.next_if(|&(_, token)| token.contains(':'));
+        if weekdays.is_none() && date.is_none() && time.is_none() {
+            let (position, token) = tokens.next().unwrap_or((0, source));
+            return Err(OnCalendarError::new(
+                position,
+                OnCalendarErrorKind::UnexpectedToken(token.to_owned()),
+            ));
+        }
+        let zone = match tokens.next() {
+            Some((position, token)) => parse_zone(token, position)?,
+            None => Zone::Local,
+        };
+        if let Some((position, token)) = tokens.next() {
+            return Err(OnCalendarError::new(
+                position,
+                OnCalendarErrorKind::UnexpectedToken(token.to_owned()),
+            ));
+        }
+
+        let weekdays = match weekdays {
+            Some((position, token)) => parse_weekdays(token, position)?,
+            None => 0b111_1111,
+        };
+        let mut event = OnCalendar {
+            source: source.trim().to_owned(),
+            weekdays,
+            years: Vec::new(),
+            months: u64::MAX,
+            days: u64::MAX,
+            days_from_end: false,
+            hours: 1,
+            minutes: 1,
+            seconds: 1,
+            hours_restricted: true,
+            zone,
+        };
+        if let Some((position, date)) = date {
+            event.parse_date(date, position)?;
+        }
+        if let Some((position, time)) = time {
+            event.parse_time(time, position)?;
+        }
+        Ok(event)
+    }
+}
+
+impl OnCalendar {
+    /// Parses `[year-]month-day` or `[year-]month~day`.
+    fn parse_date(&mut self, date: &str, position: usize) -> Result<(), OnCalendarError> {
+        let invalid = || {
+            OnCalendarError::new(
+                position,
+                OnCalendarErrorKind::InvalidValue {
+                    field: "date",
+                    value: date.to_owned(),
+                },
+            )
+        };
+        let (head, day, from_end) = match date.rsplit_once('~') {
+            Some((head, day)) => (head, day, true),
+            None => {
+                let (head, day) = date.rsplit_once('-').ok_or_else(invalid)?;
+                (head, day, false)
+            }
+        };
+        let day_position = position + head.len() + 1;
+        let (year, month) = match head.split_once('-') {
+            Some((year, month)) => (Some(year), month),
+            None => (None, head),
+        };
+        if let Some(year) = year {
+            if month.contains('-') {
+                return Err(invalid());
+            }
+            if year != "*" {
+                self.years = parse_component(year, position, &YEAR, false)?
+                    .into_iter()
+                    .map(|year| year as i32)
+                    .collect();
+            }
+        }
+        let month_position = position + year.map_or(0, |year| year.len() + 1);
+        self.months = to_bits(parse_component(month, month_position, &MONTH, false)?);
+        self.days = to_bits(parse_component(day, day_position, &DAY, from_end)?);
+        self.days_from_end = from_end;
+        Ok(())
+    }
+
+    /// Parses `hour:minute[:second]`.
+    fn parse_time(&mut self, time: &str, position: usize) -> Result<(), OnCalendarError> {
+        let parts: Vec<_> = split(time, position, ':').collect();
+        let parts = match parts[..] {
+            [hour, minute] => [hour, minute, (position + time.len(), "00")],
+            [hour, minute, second] => [hour, minute, second],
+            _ => {
+                return Err(OnCalendarError::new(
+                    position,
+                    OnCalendarErrorKind::InvalidValue {
+                        field: "time",
+                        value: time.to_owned(),
+                    },
+                ))
+            }
+        };
+        let [(hour_position, hour), (minute_position, minute), (second_position, second)] = parts;
+        // fractions of a second are accepted as long as they are zero
+        let second = match second.split_once('.') {
+            Some((whole, fraction))
+                if !fraction.is_empty() && fraction.bytes().all(|byte| byte == b'0') =>
+            {
+                whole
+            }
+            _ => second,
+        };
+        self.hours = to_bits(parse_component(hour, hour_position, &HOUR, false)?);
+        self.minutes = to_bits(parse_component(minute, minute_position, &MINUTE, false)?);
+        self.seconds = to_bits(parse_component(second, second_position, &SECOND, false)?);
+        self.hours_restricted = !hour.starts_with('*');
+        Ok(())
+    }
+}
+
+impl fmt::Display for OnCalendar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+impl fmt::Debug for OnCalendar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("OnCalendar").field(&self.source).finish()
+    }
+}
+
+/// The time zone an event is evaluated in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Zone {
+    /// The time zone of the timer.
+    Local,
+    Utc,
+    #[cfg(feature = "chrono-tz")]
+    Named(chrono_tz::Tz),
+}
+
+impl Zone {
+    /// Returns the name of the time zone, `None` for the one of the timer.
+    fn name(&self) -> Option<&'static str> {
+        match self {
+            Zone::Local => None,
+            Zone::Utc => Some("UTC"),
+            #[cfg(feature = "chrono-tz")]
+            Zone::Named(zone) => Some(zone.name()),
+        }
+    }
+}
+
+fn parse_zone(name: &str, position: usize) -> Result<Zone, OnCalendarError> {
+    if name.eq_ignore_ascii_case("UTC") {
+        return Ok(Zone::Utc);
+    }
+    #[cfg(feature = "chrono-tz")]
+    if let Ok(zone) = name.parse() {
+        return Ok(Zone::Named(zone));
+    }
+    Err(OnCalendarError::new(
+        position,
+        OnCalendarErrorKind::UnknownTimeZone(name.to_owned()),
+    ))
+}
+
+fn shorthand(token: &str) -> Option<&'static str> {
+    let expansion = match token.to_ascii_lowercase().as_str() {
+        "minutely" => "*-*-* *:*:00",
+        "hourly" => "*-*-* *:00:00",
+        "daily" => "*-*-* 00:00:00",
+        "weekly" => "Mon *-*-* 00:00:00",
+        "monthly" => "*-*-01 00:00:00",
+        "quarterly" => "*-01,04,07,10-01 00:00:00",
+        "semiannually" => "*-01,07-01 00:00:00",
+        "yearly" | "annually" => "*-01-01 00:00:00",
+        _ => return None,
+    };
+    Some(expansion)
+}
+
+const WEEKDAYS: [&str; 7] = [
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+    "sunday",
+];
+
+/// Returns the day of the week named `name`, Monday is 0.
+fn weekday(name: &str) -> Option<u32> {
+    let name = name.to_ascii_lowercase();
+    WEEKDAYS
+        .iter()
+        .position(|weekday| {
+            name.len() >= 3
+                && weekday.starts_with(&name)
+                && (name.len() == 3 || name.len() == weekday.len())
+        })
+        .map(|index| index as u32)
+}
+
+/// Returns whether the token starts with the name of a day of the week.
+fn is_weekdays(token: &str) -> bool {
+    let name = token.split([',', '.', '-']).next().unwrap_or_default();
+    weekday(name).is_some()
+}
+
+/// Parses a list of days of the week and ranges of them, like `Mon..Wed,Fri`.
+fn parse_weekdays(token: &str, position: usize) -> Result<u8, OnCalendarError> {
+    let mut bits = 0;
+    for (position, item) in split(token, position, ',') {
+        let (start, end, end_position) =
+            match item.split_once("..").or_else(|| item.split_once('-')) {
+                Some((start, end)) => (start, end, position + item.len() - end.len()),
+                None => (item, item, position),
+            };
+        let start = parse_weekday(start, position)?;
+        let end = parse_weekday(end, end_position)?;
+        let mut day = start;
+        loop {
+            bits |= 1 << day;
+            if day == end {
+                break;
+            }
+            day = (day + 1) % 7;
+        }
+    }
+    Ok(bits)
+}
+
+fn parse_weekday(name: &str, position: usize) -> Result<u32, OnCalendarError> {
+    weekday(name).ok_or_else(|| {
+        OnCalendarError::new(
+            position,
+            OnCalendarErrorKind::InvalidValue {
+                field: "weekday",
+                value: name.to_owned(),
+            },
+        )
+    })
+}
+
+struct Field {
+    name: &'static str,
+    min: u32,
+    max: u32,
+}
+
+const YEAR: Field = Field {
+    name: "year",
+    min: 1970,
+    max: 2199,
+};
+const MONTH: Field = Field {
+    name: "month",
+    min: 1,
+    max: 12,
+};
+const DAY: Field = Field {
+    name: "day",
+    min: 1,
+    max: 31,
+};
+const HOUR: Field = Field {
+    name: "hour",
+    min: 0,
+    max: 23,
+};
+const MINUTE: Field = Field {
+    name: "minute",
+    min: 0,
+    max: 59,
+};
+const SECOND: Field = Field {
+    name: "second",
+    min: 0,
+    max: 59,
+};
+
+fn to_bits(values: Vec<u32>) -> u64 {
+    values.into_iter().fold(0, |bits, value| bits | 1 << value)
+}
+
+/// Parses a comma separated list of values, ranges and repetitions.
+///
+/// Repetitions of days counted from the end of the month go towards the end,
+/// `~7/2` is the 7th, 5th, 3rd and last day from the end.
+fn parse_component(
+    component: &str,
+    position: usize,
+    spec: &Field,
+    from_end: bool,
+) -> Result<Vec<u32>, OnCalendarError> {
+    let mut values = Vec::new();
+    for (position, item) in split(component, position, ',') {
+        let (range, step) = match item.split_once('/') {
+            Some((range, step)) => {
+                let step_position = position + range.len() + 1;
+                let step = step
+                    .parse::<u32>()
+                    .ok()
+                    .filter(|&step| step > 0)
+                    .ok_or_else(|| {
+                        OnCalendarError::new(
+                            step_position,
+                            OnCalendarErrorKind::InvalidStep(step.to_owned()),
+                        )
+                    })?;
+                (range, Some(step))
+            }
+            None => (item, None),
+        };
+        let step = step.unwrap_or(1) as usize;
+
+        if range == "*" {
+            values.extend((spec.min..=spec.max).step_by(step));
+        } else if let Some((start, end)) = range.split_once("..") {
+            let end_position = position + start.len() + 2;
+            let (start, end) = (
+                parse_value(start, position, spec)?,
+                parse_value(end, end_position, spec)?,
+            );
+            if start > end {
+                return Err(OnCalendarError::new(
+                    position,
+                    OnCalendarErrorKind::InvalidRange(range.to_owned()),
+                ));
+            }
+            values.extend((start..=end).step_by(step));
+        } else {
+            let value = parse_value(range, position, spec)?;
+            match item.contains('/') {
+                // `5/10` means every 10th value starting at 5
+                true if from_end => values.extend((spec.min..=value).rev().step_by(step)),
+                true => values.extend((value..=spec.max).step_by(step)),
+                false => values.push(value),
+            }
+        }
+    }
+    Ok(values)
+}
+
+fn parse_value(value: &str, position: usize, spec: &Field) -> Result<u32, OnCalendarError> {
+    if value.is_empty() || !value.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(OnCalendarError::new(
+            position,
+            OnCalendarErrorKind::InvalidValue {
+                field: spec.name,
+                value: value.to_owned(),
+            },
+        ));
+    }
+    let number = value.parse::<u32>().ok().map(|number| {
+        // two digit years are in 1970..2069
+        match spec.name {
+            "year" if value.len() == 2 && number < 70 => number + 2000,
+            "year" if value.len() == 2 => number + 1900,
+            _ => number,
+        }
+    });
+    match number {
+        Some(number) if (spec.min..=spec.max).contains(&number) => Ok(number),
+        _ => Err(OnCalendarError::new(
+            position,
+            OnCalendarErrorKind::OutOfRange {
+                field: spec.name,
+                value: value.to_owned(),
+                min: spec.min,
+                max: spec.max,
+            },
+        )),
+    }
+}
+
+fn split_tokens(expression: &str) -> Vec<(usize, &str)> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+    for (index, char) in expression.char_indices() {
+        match (char.is_whitespace(), start) {
+            (true, Some(from)) => {
+                tokens.push((from, &expression[from..index]));
+                start = None;
+            }
+            (false, None) => start = Some(index),
+            _ => {}
+        }
+    }
+    if let Some(from) = start {
+        tokens.push((from, &expression[from..]));
+    }
+    tokens
+}
+
+/// Splits `value` at every `separator`, along with the position of every
+/// item, given that `value` starts at `position`.
+fn split(value: &str, position: usize, separator: char) -> impl Iterator<Item = (usize, &str)> {
+    let mut offset = position;
+    value.split(separator).map(move |item| {
+        let position = offset;
+        offset += item.len() + separator.len_utf8();
+        (position, item)
+    })
+}
+
+/// An error returned when parsing an [`OnCalendar`] event fails.
+///
+/// *This type requires the following crate features to be activated: `systemd`*
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OnCalendarError {
+    position: usize,
+    kind: OnCalendarErrorKind,
+}
+
+/// The reason an [`OnCalendar`] event failed to parse.
+///
+/// *This type requires the following crate features to be activated: `systemd`*
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum OnCalendarErrorKind {
+    /// The event is empty.
+    Empty,
+    /// The token is neither days of the week, a date, a time nor a time zone,
+    /// or it is out of order.
+    UnexpectedToken(String),
+    /// The value is not a number or not a day of the week.
+    InvalidValue {
+        /// The name of the component, like `"month"`.
+        field: &'static str,
+        /// The value as written.
+        value: String,
+    },
+    /// The value is outside of the range of the component.
+    OutOfRange {
+        /// The name of the component, like `"month"`.
+        field: &'static str,
+        /// The value as written.
+        value: String,
+        /// The smallest value of the component.
+        min: u32,
+        /// The largest value of the component.
+        max: u32,
+    },
+    /// The start of the range is after its end.
+    InvalidRange(String),
+    /// The repetition is not a positive number.
+    InvalidStep(String),
+    /// The time zone is not UTC nor a known IANA time zone, or the
+    /// `chrono-tz` feature is not activated.
+    UnknownTimeZone(String),
+}
+
+impl OnCalendarError {
+    fn new(position: usize, kind: OnCalendarErrorKind) -> OnCalendarError {
+        OnCalendarError { position, kind }
+    }
+
+    /// Returns the byte offset into the event at which the error was found.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the reason the event failed to parse.
+    pub fn kind(&self) -> &OnCalendarErrorKind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for OnCalendarError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid calendar event at position {}: ", self.position)?;
+        match &self.kind {
+            OnCalendarErrorKind::Empty => f.write_str("empty"),
+            OnCalendarErrorKind::UnexpectedToken(token) => {
+                write!(f, "unexpected `{}`", token)
+            }
+            OnCalendarErrorKind::InvalidValue { field, value } => {
+                write!(f, "invalid {} `{}`", field, value)
+            }
+            OnCalendarErrorKind::OutOfRange {
+                field,
+                value,
+                min,
+                max,
+            } => write!(f, "{} `{}` is not in {}..{}", field, value, min, max),
+            OnCalendarErrorKind::InvalidRange(range) => write!(f, "invalid range `{}`", range),
+            OnCalendarErrorKind::InvalidStep(step) => write!(f, "invalid repetition `{}`", step),
+            OnCalendarErrorKind::UnknownTimeZone(name) => {
+                write!(f, "unknown time zone `{}`", name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for OnCalendarError {}
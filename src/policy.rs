@@ -0,0 +1,191 @@
+//! Policies that decide what a timer does when its callback misbehaves.
+
+use std::any::Any;
+use std::fmt;
+
+#[cfg(feature = "timers")]
+type ErrorHook<E> = Box<dyn FnMut(&E) + Send + 'static>;
+
+/// What a timer does when its callback returns an error.
+///
+/// The `try_` functions, like [`try_set_interval`](crate::try_set_interval)
+/// or [`try_call_every_hour`](crate::try_call_every_hour), take a callback
+/// that returns a `Result<(), E>` and a policy for its errors. A timer that
+/// stops because of an error hands it back, the function returns
+/// `Err(error)`.
+///
+/// The crate never writes to the standard error on its own, only
+/// [`Log`](ErrorPolicy::Log) prints the errors it is given.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use zila::{try_set_interval, ErrorPolicy};
+///
+/// let mut attempts = 0;
+/// let result = try_set_interval(
+///     || {
+///         attempts += 1;
+///         if attempts < 3 {
+///             Ok(())
+///         } else {
+///             Err(format!("gave up after {} attempts", attempts))
+///         }
+///     },
+///     Duration::from_millis(1),
+///     ErrorPolicy::Stop,
+/// );
+/// let error = result.unwrap_err();
+/// assert_eq!(error, "gave up after 3 attempts");
+/// ```
+#[cfg(feature = "timers")]
+pub enum ErrorPolicy<E> {
+    /// Prints the error to the standard error, prefixed with `zila: callback
+    /// failed:`, and waits for the next tick.
+    ///
+    /// Use [`on_error`](ErrorPolicy::on_error) to send the errors anywhere
+    /// else, like a logger or a metrics counter.
+    Log,
+    /// Stops the timer and returns the error.
+    Stop,
+    /// Passes the error to a hook and waits for the next tick, see
+    /// [`ErrorPolicy::on_error`].
+    OnError(ErrorHook<E>),
+}
+
+#[cfg(feature = "timers")]
+impl<E> ErrorPolicy<E> {
+    /// Creates a policy that passes every error to `hook` and keeps the timer
+    /// running.
+    ///
+    /// The hook runs on the timer's thread or task, right after the callback.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    /// use zila::ErrorPolicy;
+    ///
+    /// let failures = Arc::new(AtomicUsize::new(0));
+    /// let policy = ErrorPolicy::on_error({
+    ///     let failures = failures.clone();
+    ///     move |error: &std::io::Error| {
+    ///         failures.fetch_add(1, Ordering::Relaxed);
+    ///         eprintln!("sync failed: {}", error);
+    ///     }
+    /// });
+    /// ```
+    pub fn on_error<F>(hook: F) -> ErrorPolicy<E>
+    where
+        F: FnMut(&E) + Send + 'static,
+    {
+        ErrorPolicy::OnError(Box::new(hook))
+    }
+}
+
+#[cfg(feature = "timers")]
+impl<E: fmt::Display> ErrorPolicy<E> {
+    /// Applies the policy to the result of a callback, returns the error if
+    /// the timer has to stop.
+    pub(crate) fn check(&mut self, result: Result<(), E>) -> Result<(), E> {
+        let Err(error) = result else {
+            return Ok(());
+        };
+        match self {
+            ErrorPolicy::Log => eprintln!("zila: callback failed: {}", error),
+            ErrorPolicy::Stop => return Err(error),
+            ErrorPolicy::OnError(hook) => hook(&error),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "timers")]
+impl<E> fmt::Debug for ErrorPolicy<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorPolicy::Log => f.write_str("Log"),
+            ErrorPolicy::Stop => f.write_str("Stop"),
+            ErrorPolicy::OnError(_) => f.write_str("OnError(..)"),
+        }
+    }
+}
+
+/// What a timer does when its callback panics.
+///
+/// Panics are caught with [`std::panic::catch_unwind`], the ones of async
+/// callbacks on every poll of the future, and passed to the hook set with
+/// [`Timer::on_panic`](crate::Timer::on_panic) before the policy applies. A
+/// callback that is called again after a panic may find its captured state
+/// half updated, just like the data behind a poisoned
+/// [`Mutex`](std::sync::Mutex).
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::{PanicPolicy, Timer};
+///
+/// Timer::every_day()
+///     .panic_policy(PanicPolicy::Restart)
+///     .on_panic(|panic| {
+///         eprintln!("maintenance failed: {}", panic.message().unwrap_or("unknown"));
+///     })
+///     .run(|_| {
+///         // tomorrow's run isn't lost if this panics
+///     });
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PanicPolicy {
+    /// Keeps the timer running, the callback is called again on the next
+    /// tick.
+    Restart,
+    /// Stops the timer as if its schedule ended, joining its handle succeeds.
+    Stop,
+    /// Lets the panic unwind the timer's thread or task, joining its handle
+    /// returns [`JoinError::Panic`](crate::JoinError::Panic). The default.
+    #[default]
+    Propagate,
+}
+
+/// A panic caught in a timer callback.
+///
+/// Passed to the hook set with [`Timer::on_panic`](crate::Timer::on_panic).
+pub struct CaughtPanic {
+    payload: Box<dyn Any + Send + 'static>,
+}
+
+impl CaughtPanic {
+    #[allow(dead_code)] // every caller is gated behind its own feature
+    pub(crate) fn new(payload: Box<dyn Any + Send + 'static>) -> CaughtPanic {
+        CaughtPanic { payload }
+    }
+
+    /// Returns the value the callback panicked with.
+    pub fn payload(&self) -> &(dyn Any + Send + 'static) {
+        &*self.payload
+    }
+
+    /// Returns the panic message, if the callback panicked with a string like
+    /// [`panic!`] does.
+    pub fn message(&self) -> Option<&str> {
+        match self.payload.downcast_ref::<&'static str>() {
+            Some(message) => Some(message),
+            None => self.payload.downcast_ref::<String>().map(String::as_str),
+        }
+    }
+
+    #[allow(dead_code)] // every caller is gated behind its own feature
+    pub(crate) fn into_payload(self) -> Box<dyn Any + Send + 'static> {
+        self.payload
+    }
+}
+
+impl fmt::Debug for CaughtPanic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CaughtPanic")
+            .field("message", &self.message())
+            .finish_non_exhaustive()
+    }
+}
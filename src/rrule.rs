@@ -0,0 +1,1856 @@
+//! iCalendar recurrence sets, as defined by RFC 5545.
+
+use crate::calendar::{self, Fold};
+use crate::describe;
+use crate::{ErrorPolicy, Flow, Schedule, Timer, TimerHandle, Upcoming};
+use chrono::{
+    DateTime, Datelike, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike, Utc,
+    Weekday,
+};
+use std::fmt;
+use std::future::Future;
+use std::str::FromStr;
+use tokio::time::Duration;
+
+/// A recurrence set in the iCalendar format of RFC 5545.
+///
+/// A recurrence starts at its `DTSTART`. `RRULE` lines add the occurrences of
+/// a rule and `RDATE` lines add single dates, `EXRULE` and `EXDATE` lines
+/// remove them again:
+///
+/// ```text
+/// DTSTART;TZID=Europe/Berlin:20230509T090000
+/// RRULE:FREQ=MONTHLY;BYDAY=2TU;COUNT=10
+/// EXDATE;TZID=Europe/Berlin:20230613T090000
+/// ```
+///
+/// Rules support every part of RFC 5545: `FREQ`, `INTERVAL`, `COUNT`,
+/// `UNTIL`, `BYSECOND`, `BYMINUTE`, `BYHOUR`, `BYDAY` (with ordinals like
+/// `2TU` or `-1FR`), `BYMONTHDAY`, `BYYEARDAY`, `BYWEEKNO`, `BYMONTH`,
+/// `BYSETPOS` and `WKST`. Like in most calendar applications, the `DTSTART`
+/// is only an occurrence if one of the rules matches it, or if there are no
+/// rules at all.
+///
+/// Times without a time zone are floating, they are evaluated in the time zone
+/// of the timer like a [`Cron`](crate::Cron) expression. Times that end with
+/// `Z` are in UTC, and times with a `TZID` parameter are in that IANA time
+/// zone, which needs the `chrono-tz` feature. Local times that are skipped when
+/// the clocks spring forward fire at the first instant after the jump. When
+/// the clocks fall back, daily and longer rules fire only once, while hourly
+/// and shorter rules keep firing through the repeated hour.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use zila::Recurrence;
+///
+/// let recurrence = Recurrence::parse(
+///     "DTSTART:20230509T090000Z\n\
+///      RRULE:FREQ=MONTHLY;BYDAY=2TU;COUNT=3",
+/// )
+/// .unwrap();
+/// let times: Vec<_> = recurrence.occurrences(&Utc).collect();
+/// assert_eq!(
+///     times,
+///     [
+///         Utc.with_ymd_and_hms(2023, 5, 9, 9, 0, 0).unwrap(),
+///         Utc.with_ymd_and_hms(2023, 6, 13, 9, 0, 0).unwrap(),
+///         Utc.with_ymd_and_hms(2023, 7, 11, 9, 0, 0).unwrap(),
+///     ]
+/// );
+/// ```
+///
+/// *This type requires the following crate features to be activated: `rrule`*
+#[derive(Clone, PartialEq, Eq)]
+pub struct Recurrence {
+    source: String,
+    start: NaiveDateTime,
+    zone: Zone,
+    rules: Vec<Rule>,
+    exclusion_rules: Vec<Rule>,
+    /// sorted
+    dates: Vec<NaiveDateTime>,
+    /// sorted
+    exclusion_dates: Vec<NaiveDateTime>,
+}
+
+impl Recurrence {
+    /// Parses a recurrence set, same as [`str::parse`].
+    pub fn parse(text: &str) -> Result<Recurrence, RecurrenceError> {
+        text.parse()
+    }
+
+    /// Returns the first occurrence strictly after `time`, `None` if there are
+    /// no occurrences after it.
+    ///
+    /// Floating times are evaluated in the time zone of `time`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use zila::Recurrence;
+    ///
+    /// let recurrence = Recurrence::parse(
+    ///     "DTSTART:20230101T120000\n\
+    ///      RRULE:FREQ=WEEKLY;BYDAY=MO,FR",
+    /// )
+    /// .unwrap();
+    /// // a Saturday
+    /// let time = Utc.with_ymd_and_hms(2023, 5, 13, 12, 0, 0).unwrap();
+    /// let next = Utc.with_ymd_and_hms(2023, 5, 15, 12, 0, 0).unwrap();
+    /// assert_eq!(recurrence.next_after(&time), Some(next));
+    /// ```
+    pub fn next_after<Tz: TimeZone>(&self, time: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        let tz = time.timezone();
+        match self.zone {
+            Zone::Floating => self.next_after_local(time),
+            Zone::Utc => self
+                .next_after_local(&time.with_timezone(&Utc))
+                .map(|next| next.with_timezone(&tz)),
+            #[cfg(feature = "chrono-tz")]
+            Zone::Named(zone) => self
+                .next_after_local(&time.with_timezone(&zone))
+                .map(|next| next.with_timezone(&tz)),
+        }
+    }
+
+    /// Returns an iterator over every occurrence, starting with the first one.
+    ///
+    /// Floating times are evaluated in `tz`, the iterator ends with the last
+    /// occurrence of recurrences that end.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zila::chrono_tz::Europe::Berlin;
+    /// use zila::Recurrence;
+    ///
+    /// let recurrence = Recurrence::parse(
+    ///     "DTSTART:20230509T090000\n\
+    ///      RRULE:FREQ=DAILY;COUNT=10\n\
+    ///      EXRULE:FREQ=WEEKLY;BYDAY=SA,SU",
+    /// )
+    /// .unwrap();
+    /// assert_eq!(recurrence.occurrences(&Berlin).count(), 8);
+    /// ```
+    pub fn occurrences<Tz: TimeZone>(&self, tz: &Tz) -> Upcoming<'_, Recurrence, Tz> {
+        let first = self
+            .dates
+            .first()
+            .map_or(self.start, |&date| date.min(self.start));
+        // two days earlier in UTC is before the first occurrence in any time zone
+        let before = first - chrono::Duration::days(2);
+        self.iter_after(tz.from_utc_datetime(&before))
+    }
+
+    fn next_after_local<Tz: TimeZone>(&self, time: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        let fold = if self
+            .rules
+            .iter()
+            .any(|rule| rule.frequency <= Frequency::Hourly)
+        {
+            Fold::Repeat
+        } else {
+            Fold::Skip
+        };
+        calendar::next_after(time, fold, |from| self.next_local(from))
+    }
+
+    /// Returns the first local time equal to or after `from` that occurs.
+    fn next_local(&self, mut from: NaiveDateTime) -> Option<NaiveDateTime> {
+        let limit = from.year().saturating_add(400);
+        loop {
+            let start = Some(self.start).filter(|&start| self.rules.is_empty() && start >= from);
+            let index = self.dates.partition_point(|&date| date < from);
+            let next = self
+                .rules
+                .iter()
+                .filter_map(|rule| rule.next(self.start, from))
+                .chain(start)
+                .chain(self.dates.get(index).copied())
+                .min()?;
+            if next.year() > limit {
+                return None;
+            }
+            if !self.is_excluded(next) {
+                return Some(next);
+            }
+            from = next + chrono::Duration::seconds(1);
+        }
+    }
+
+    fn is_excluded(&self, time: NaiveDateTime) -> bool {
+        self.exclusion_dates.binary_search(&time).is_ok()
+            || self
+                .exclusion_rules
+                .iter()
+                .any(|rule| rule.next(self.start, time) == Some(time))
+    }
+}
+
+impl Schedule for Recurrence {
+    fn next_after<Tz: TimeZone>(&self, time: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        Recurrence::next_after(self, time)
+    }
+
+    fn describe(&self) -> String {
+        let mut description = if self.rules.is_empty() {
+            let dates: Vec<_> = std::iter::once(&self.start).chain(&self.dates).collect();
+            match dates[..] {
+                [date] => format!("once at {}", date_time(date)),
+                [first, ..] if dates.len() > 3 => {
+                    format!("at {} and {} more dates", date_time(first), dates.len() - 1)
+                }
+                _ => format!("at {}", describe::join(dates.into_iter().map(date_time))),
+            }
+        } else {
+            let mut parts: Vec<_> = self.rules.iter().map(Rule::describe).collect();
+            match self.dates.len() {
+                0 => {}
+                1 => parts.push(format!("at {}", date_time(&self.dates[0]))),
+                count => parts.push(format!("at {} more dates", count)),
+            }
+            describe::join(parts)
+        };
+        if self.rules.iter().any(|rule| rule.interval > 1) {
+            description += &format!(", starting {}", date_time(&self.start));
+        }
+        let mut exceptions: Vec<_> = self.exclusion_rules.iter().map(Rule::describe).collect();
+        match self.exclusion_dates.len() {
+            0 => {}
+            1 => exceptions.push(format!("at {}", date_time(&self.exclusion_dates[0]))),
+            count => exceptions.push(format!("at {} dates", count)),
+        }
+        if !exceptions.is_empty() {
+            description += &format!(", except {}", describe::join(exceptions));
+        }
+        match self.zone.name() {
+            Some(zone) => format!("{} ({})", description, zone),
+            None => description,
+        }
+    }
+
+    fn describe_in(&self, zone: impl fmt::Display) -> String {
+        match self.zone {
+            Zone::Floating => format!("{} ({})", self.describe(), zone),
+            _ => self.describe(),
+        }
+    }
+}
+
+/// Formats a local time like `2023-05-09 09:30`.
+fn date_time(time: &NaiveDateTime) -> String {
+    if time.second() == 0 {
+        time.format("%Y-%m-%d %H:%M").to_string()
+    } else {
+        time.format("%Y-%m-%d %H:%M:%S").to_string()
+    }
+}
+
+impl FromStr for Recurrence {
+    type Err = RecurrenceError;
+
+    fn from_str(text: &str) -> Result<Recurrence, RecurrenceError> {
+        let lines = split(text, 0, '\n')
+            .map(|(position, line)| {
+                let trimmed = line.trim_start();
+                (position + line.len() - trimmed.len(), trimmed.trim_end())
+            })
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(position, line)| Line::parse(line, position))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut starts = lines.iter().filter(|line| line.name == "DTSTART");
+        let Some(start) = starts.next() else {
+            return Err(RecurrenceError::new(0, RecurrenceErrorKind::MissingStart));
+        };
+        if let Some(duplicate) = starts.next() {
+            return Err(RecurrenceError::new(
+                duplicate.position,
+                RecurrenceErrorKind::Duplicate("DTSTART".to_owned()),
+            ));
+        }
+        let zone = start.zone()?;
+        let (start, _) = parse_date_time(start.value, start.value_position, "DTSTART")?;
+
+        let mut recurrence = Recurrence {
+            source: text.trim().to_owned(),
+            start,
+            zone,
+            rules: Vec::new(),
+            exclusion_rules: Vec::new(),
+            dates: Vec::new(),
+            exclusion_dates: Vec::new(),
+        };
+        for line in &lines {
+            match line.name.as_str() {
+                "DTSTART" => {}
+                "RRULE" => recurrence.rules.push(Rule::parse(line, start, zone)?),
+                "EXRULE" => recurrence
+                    .exclusion_rules
+                    .push(Rule::parse(line, start, zone)?),
+                "RDATE" => recurrence.dates.extend(line.dates("RDATE", zone)?),
+                "EXDATE" => recurrence
+                    .exclusion_dates
+                    .extend(line.dates("EXDATE", zone)?),
+                _ => {
+                    return Err(RecurrenceError::new(
+                        line.position,
+                        RecurrenceErrorKind::UnknownProperty(line.name.clone()),
+                    ))
+                }
+            }
+        }
+        recurrence.dates.sort_unstable();
+        recurrence.exclusion_dates.sort_unstable();
+        Ok(recurrence)
+    }
+}
+
+impl fmt::Display for Recurrence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+impl fmt::Debug for Recurrence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Recurrence").field(&self.source).finish()
+    }
+}
+
+/// The time zone the local times of a recurrence are written in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Zone {
+    /// The time zone of the timer.
+    Floating,
+    Utc,
+    #[cfg(feature = "chrono-tz")]
+    Named(chrono_tz::Tz),
+}
+
+impl Zone {
+    /// Returns the name of the time zone, `None` if it's floating.
+    fn name(self) -> Option<&'static str> {
+        match self {
+            Zone::Floating => None,
+            Zone::Utc => Some("UTC"),
+            #[cfg(feature = "chrono-tz")]
+            Zone::Named(zone) => Some(zone.name()),
+        }
+    }
+
+    /// Converts a local time in `self` to a local time in `to`, `None` if the
+    /// time doesn't exist or `to` is floating.
+    fn convert(self, time: NaiveDateTime, to: Zone) -> Option<NaiveDateTime> {
+        if self == to || self == Zone::Floating {
+            return Some(time);
+        }
+        let utc = match self {
+            Zone::Floating | Zone::Utc => time,
+            #[cfg(feature = "chrono-tz")]
+            Zone::Named(zone) => zone.from_local_datetime(&time).earliest()?.naive_utc(),
+        };
+        match to {
+            Zone::Floating => None,
+            Zone::Utc => Some(utc),
+            #[cfg(feature = "chrono-tz")]
+            Zone::Named(zone) => Some(zone.from_utc_datetime(&utc).naive_local()),
+        }
+    }
+}
+
+/// A content line, like `DTSTART;TZID=Europe/Berlin:20230509T090000`.
+struct Line<'a> {
+    position: usize,
+    /// uppercase
+    name: String,
+    parameters: Vec<(String, &'a str)>,
+    value: &'a str,
+    value_position: usize,
+}
+
+impl<'a> Line<'a> {
+    fn parse(line: &'a str, position: usize) -> Result<Line<'a>, RecurrenceError> {
+        let Some((head, value)) = line.split_once(':') else {
+            return Err(RecurrenceError::new(
+                position,
+                RecurrenceErrorKind::InvalidLine,
+            ));
+        };
+        let mut head = head.split(';');
+        let name = head.next().unwrap_or_default().to_ascii_uppercase();
+        let parameters = head
+            .filter_map(|parameter| parameter.split_once('='))
+            .map(|(key, value)| (key.to_ascii_uppercase(), value.trim_matches('"')))
+            .collect();
+        Ok(Line {
+            position,
+            name,
+            parameters,
+            value,
+            value_position: position + line.len() - value.len(),
+        })
+    }
+
+    fn parameter(&self, key: &str) -> Option<&'a str> {
+        self.parameters
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|&(_, value)| value)
+    }
+
+    /// Returns the time zone of the values of the line.
+    fn zone(&self) -> Result<Zone, RecurrenceError> {
+        match self.parameter("TZID") {
+            Some(name) => parse_zone(name, self.position),
+            None if self.value.ends_with('Z') => Ok(Zone::Utc),
+            None => Ok(Zone::Floating),
+        }
+    }
+
+    /// Parses the comma separated dates of an `RDATE` or `EXDATE` line as
+    /// local times in `zone`.
+    fn dates(&self, name: &'static str, zone: Zone) -> Result<Vec<NaiveDateTime>, RecurrenceError> {
+        let tzid = self
+            .parameter("TZID")
+            .map(|tzid| parse_zone(tzid, self.position))
+            .transpose()?;
+        split(self.value, self.value_position, ',')
+            .map(|(position, value)| {
+                let (time, utc) = parse_date_time(value, position, name)?;
+                let from = match tzid {
+                    Some(tzid) => tzid,
+                    None if utc => Zone::Utc,
+                    None => Zone::Floating,
+                };
+                from.convert(time, zone).ok_or_else(|| {
+                    RecurrenceError::new(
+                        position,
+                        RecurrenceErrorKind::Conflict(
+                            "a floating DTSTART can't be combined with dates in a time zone",
+                        ),
+                    )
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "chrono-tz")]
+fn parse_zone(name: &str, position: usize) -> Result<Zone, RecurrenceError> {
+    name.parse().map(Zone::Named).map_err(|_| {
+        RecurrenceError::new(
+            position,
+            RecurrenceErrorKind::UnknownTimeZone(name.to_owned()),
+        )
+    })
+}
+
+#[cfg(not(feature = "chrono-tz"))]
+fn parse_zone(name: &str, position: usize) -> Result<Zone, RecurrenceError> {
+    Err(RecurrenceError::new(
+        position,
+        RecurrenceErrorKind::UnknownTimeZone(name.to_owned()),
+    ))
+}
+
+/// Parses a `DATE` (`20230509`) or a `DATE-TIME` (`20230509T090000`, with an
+/// optional `Z`), returns the local time and whether it is in UTC.
+fn parse_date_time(
+    value: &str,
+    position: usize,
+    name: &'static str,
+) -> Result<(NaiveDateTime, bool), RecurrenceError> {
+    let (time, utc) = match value.strip_suffix('Z') {
+        Some(time) => (time, true),
+        None => (value, false),
+    };
+    let digits = |text: &str| text.bytes().all(|byte| byte.is_ascii_digit());
+    let parsed = match time.split_once('T') {
+        Some((date, time))
+            if date.len() == 8 && time.len() == 6 && digits(date) && digits(time) =>
+        {
+            NaiveDateTime::parse_from_str(&format!("{}{}", date, time), "%Y%m%d%H%M%S").ok()
+        }
+        None if time.len() == 8 && digits(time) && !utc => {
+            NaiveDate::parse_from_str(time, "%Y%m%d")
+                .ok()
+                .map(|date| date.and_time(NaiveTime::MIN))
+        }
+        _ => None,
+    };
+    match parsed {
+        Some(parsed) => Ok((parsed, utc)),
+        None => Err(RecurrenceError::new(
+            position,
+            RecurrenceErrorKind::InvalidValue {
+                name,
+                value: value.to_owned(),
+            },
+        )),
+    }
+}
+
+/// Splits `value` at every `separator`, along with the position of every
+/// item, given that `value` starts at `position`.
+fn split(value: &str, position: usize, separator: char) -> impl Iterator<Item = (usize, &str)> {
+    let mut offset = position;
+    value.split(separator).map(move |item| {
+        let position = offset;
+        offset += item.len() + separator.len_utf8();
+        (position, item)
+    })
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Frequency {
+    Secondly,
+    Minutely,
+    Hourly,
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+impl Frequency {
+    /// The length of a period in seconds, for periods of a fixed length.
+    fn seconds(self) -> i64 {
+        match self {
+            Frequency::Secondly => 1,
+            Frequency::Minutely => 60,
+            Frequency::Hourly => 60 * 60,
+            Frequency::Daily => 24 * 60 * 60,
+            Frequency::Weekly | Frequency::Monthly | Frequency::Yearly => 7 * 24 * 60 * 60,
+        }
+    }
+}
+
+/// An `RRULE` or an `EXRULE`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Rule {
+    frequency: Frequency,
+    interval: u32,
+    count: Option<u32>,
+    until: Option<NaiveDateTime>,
+    seconds: Vec<u32>,
+    minutes: Vec<u32>,
+    hours: Vec<u32>,
+    week_days: Vec<(Option<i32>, Weekday)>,
+    month_days: Vec<i32>,
+    year_days: Vec<i32>,
+    week_numbers: Vec<i32>,
+    months: Vec<u32>,
+    positions: Vec<i32>,
+    week_start: Weekday,
+}
+
+impl Rule {
+    fn parse(line: &Line<'_>, start: NaiveDateTime, zone: Zone) -> Result<Rule, RecurrenceError> {
+        let mut frequency = None;
+        let mut rule = Rule {
+            frequency: Frequency::Daily,
+            interval: 1,
+            count: None,
+            until: None,
+            seconds: Vec::new(),
+            minutes: Vec::new(),
+            hours: Vec::new(),
+            week_days: Vec::new(),
+            month_days: Vec::new(),
+            year_days: Vec::new(),
+            week_numbers: Vec::new(),
+            months: Vec::new(),
+            positions: Vec::new(),
+            week_start: Weekday::Mon,
+        };
+        let mut seen = Vec::new();
+        for (position, part) in split(line.value, line.value_position, ';') {
+            let Some((key, value)) = part.split_once('=') else {
+                return Err(RecurrenceError::new(
+                    position,
+                    RecurrenceErrorKind::UnknownPart(part.to_owned()),
+                ));
+            };
+            let key = key.to_ascii_uppercase();
+            if seen.contains(&key) {
+                return Err(RecurrenceError::new(
+                    position,
+                    RecurrenceErrorKind::Duplicate(key),
+                ));
+            }
+            let position = position + key.len() + 1;
+            match key.as_str() {
+                "FREQ" => frequency = Some(parse_frequency(value, position)?),
+                "INTERVAL" => {
+                    rule.interval = parse_number(value, position, "INTERVAL", 1, i32::MAX)? as u32
+                }
+                "COUNT" => {
+                    rule.count = Some(parse_number(value, position, "COUNT", 1, i32::MAX)? as u32)
+                }
+                "UNTIL" => {
+                    let (until, utc) = parse_date_time(value, position, "UNTIL")?;
+                    // a date includes the whole day
+                    let until = if value.len() == 8 {
+                        until.date().and_hms_opt(23, 59, 59).unwrap_or(until)
+                    } else {
+                        until
+                    };
+                    let from = if utc { Zone::Utc } else { Zone::Floating };
+                    rule.until = Some(from.convert(until, zone).unwrap_or(until));
+                }
+                "BYSECOND" => rule.seconds = parse_list(value, position, "BYSECOND", 0, 59)?,
+                "BYMINUTE" => rule.minutes = parse_list(value, position, "BYMINUTE", 0, 59)?,
+                "BYHOUR" => rule.hours = parse_list(value, position, "BYHOUR", 0, 23)?,
+                "BYDAY" => {
+                    rule.week_days = split(value, position, ',')
+                        .map(|(position, day)| parse_week_day(day, position))
+                        .collect::<Result<_, _>>()?
+                }
+                "BYMONTHDAY" => {
+                    rule.month_days = parse_list(value, position, "BYMONTHDAY", -31, 31)?
+                }
+                "BYYEARDAY" => {
+                    rule.year_days = parse_list(value, position, "BYYEARDAY", -366, 366)?
+                }
+                "BYWEEKNO" => rule.week_numbers = parse_list(value, position, "BYWEEKNO", -53, 53)?,
+                "BYMONTH" => rule.months = parse_list(value, position, "BYMONTH", 1, 12)?,
+                "BYSETPOS" => rule.positions = parse_list(value, position, "BYSETPOS", -366, 366)?,
+                "WKST" => rule.week_start = parse_weekday(value, position)?,
+                _ => {
+                    return Err(RecurrenceError::new(
+                        position - key.len() - 1,
+                        RecurrenceErrorKind::UnknownPart(key),
+                    ))
+                }
+            }
+            seen.push(key);
+        }
+
+        let conflict = |reason| {
+            Err(RecurrenceError::new(
+                line.value_position,
+                RecurrenceErrorKind::Conflict(reason),
+            ))
+        };
+        let Some(frequency) = frequency else {
+            return Err(RecurrenceError::new(
+                line.value_position,
+                RecurrenceErrorKind::MissingFrequency,
+            ));
+        };
+        rule.frequency = frequency;
+        if rule.count.is_some() && rule.until.is_some() {
+            return conflict("COUNT and UNTIL can't be used together");
+        }
+        if !rule.week_numbers.is_empty() && frequency != Frequency::Yearly {
+            return conflict("BYWEEKNO is only allowed with FREQ=YEARLY");
+        }
+        if !rule.year_days.is_empty()
+            && matches!(
+                frequency,
+                Frequency::Daily | Frequency::Weekly | Frequency::Monthly
+            )
+        {
+            return conflict("BYYEARDAY is not allowed with FREQ=DAILY, WEEKLY or MONTHLY");
+        }
+        if !rule.month_days.is_empty() && frequency == Frequency::Weekly {
+            return conflict("BYMONTHDAY is not allowed with FREQ=WEEKLY");
+        }
+        let ordinals = rule.week_days.iter().any(|(nth, _)| nth.is_some());
+        if ordinals
+            && (frequency < Frequency::Monthly
+                || (frequency == Frequency::Yearly && !rule.week_numbers.is_empty()))
+        {
+            return conflict("BYDAY ordinals are only allowed with FREQ=MONTHLY or YEARLY");
+        }
+
+        rule.fill_defaults(start);
+        Ok(rule)
+    }
+
+    /// Takes the parts that the rule doesn't restrict from `start`.
+    /// Describes the rule, like `every month on the 2nd Tuesday at 09:00`.
+    fn describe(&self) -> String {
+        let (one, many) = match self.frequency {
+            Frequency::Secondly => ("second", "seconds"),
+            Frequency::Minutely => ("minute", "minutes"),
+            Frequency::Hourly => ("hour", "hours"),
+            Frequency::Daily => ("day", "days"),
+            Frequency::Weekly => ("week", "weeks"),
+            Frequency::Monthly => ("month", "months"),
+            Frequency::Yearly => ("year", "years"),
+        };
+        let mut description = match self.interval {
+            1 => format!("every {}", one),
+            interval => format!("every {} {}", interval, many),
+        };
+        let numbers =
+            |numbers: &[i32]| describe::join(numbers.iter().map(|&n| describe::ordinal(n)));
+        let weekday = |day: Weekday| describe::WEEKDAYS[day.num_days_from_monday() as usize];
+        if !self.week_days.is_empty() {
+            let plain = self.week_days.iter().all(|(n, _)| n.is_none());
+            let bits = self
+                .week_days
+                .iter()
+                .fold(0, |bits, (_, day)| bits | 1 << day.num_days_from_monday());
+            let days = if !plain {
+                describe::join(self.week_days.iter().map(|&(n, day)| match n {
+                    Some(n) => format!("the {} {}", describe::ordinal(n), weekday(day)),
+                    None => weekday(day).to_owned(),
+                }))
+            } else if self.positions.is_empty() {
+                describe::weekdays(bits)
+            } else if bits == 0b001_1111 {
+                format!("the {} weekday", numbers(&self.positions))
+            } else {
+                format!(
+                    "the {} of {}",
+                    numbers(&self.positions),
+                    describe::weekdays(bits)
+                )
+            };
+            description += &format!(" on {}", days);
+        }
+        if !self.month_days.is_empty() {
+            description += &format!(
+                " on the {} {}",
+                numbers(&self.month_days),
+                describe::plural(self.month_days.len(), "day", "days")
+            );
+        }
+        if !self.year_days.is_empty() {
+            description += &format!(
+                " on the {} {} of the year",
+                numbers(&self.year_days),
+                describe::plural(self.year_days.len(), "day", "days")
+            );
+        }
+        if !self.week_numbers.is_empty() {
+            description += &format!(
+                " in {} {}",
+                describe::plural(self.week_numbers.len(), "week", "weeks"),
+                describe::join(&self.week_numbers)
+            );
+        }
+        if !self.months.is_empty() {
+            let months = self
+                .months
+                .iter()
+                .map(|&month| describe::MONTHS[month as usize - 1]);
+            description += &format!(" in {}", describe::join(months));
+        }
+        let list = |values: &[u32]| describe::join(values);
+        if self.frequency >= Frequency::Daily {
+            let times: Vec<_> = self
+                .hours
+                .iter()
+                .flat_map(|hour| {
+                    self.minutes.iter().flat_map(move |minute| {
+                        self.seconds
+                            .iter()
+                            .map(move |second| hour * 3600 + minute * 60 + second)
+                    })
+                })
+                .collect();
+            if times.len() <= 6 {
+                description += &format!(" {}", describe::times(&times));
+            } else {
+                description += &format!(
+                    " at minutes {} of hours {}",
+                    list(&self.minutes),
+                    list(&self.hours)
+                );
+            }
+        } else {
+            if self.frequency == Frequency::Hourly && self.minutes.len() * self.seconds.len() <= 6 {
+                let offsets: Vec<_> = self
+                    .minutes
+                    .iter()
+                    .flat_map(|minute| self.seconds.iter().map(move |second| minute * 60 + second))
+                    .collect();
+                let at = describe::hourly(&offsets);
+                description += at.strip_prefix("every hour").unwrap_or(&at);
+            } else if self.frequency == Frequency::Minutely && self.seconds != [0] {
+                description += &format!(" at seconds {}", list(&self.seconds));
+            }
+            if self.frequency < Frequency::Hourly && !self.minutes.is_empty() {
+                description += &format!(" during minutes {}", list(&self.minutes));
+            }
+            if !self.hours.is_empty() {
+                description += &format!(" during hours {}", list(&self.hours));
+            }
+        }
+        // the positions of plain days of the week are described with them
+        let plain_week_days =
+            !self.week_days.is_empty() && self.week_days.iter().all(|(n, _)| n.is_none());
+        if !self.positions.is_empty() && !plain_week_days {
+            description += &format!(", only the {} of every {}", numbers(&self.positions), one);
+        }
+        if let Some(count) = self.count {
+            description += &format!(
+                ", {} {}",
+                count,
+                describe::plural(count as usize, "time", "times")
+            );
+        }
+        if let Some(until) = self.until {
+            description += &format!(", until {}", date_time(&until));
+        }
+        description
+    }
+
+    fn fill_defaults(&mut self, start: NaiveDateTime) {
+        if self.frequency > Frequency::Hourly && self.hours.is_empty() {
+            self.hours.push(start.hour());
+        }
+        if self.frequency > Frequency::Minutely && self.minutes.is_empty() {
+            self.minutes.push(start.minute());
+        }
+        if self.frequency > Frequency::Secondly && self.seconds.is_empty() {
+            self.seconds.push(start.second());
+        }
+        if self.week_numbers.is_empty()
+            && self.year_days.is_empty()
+            && self.month_days.is_empty()
+            && self.week_days.is_empty()
+        {
+            match self.frequency {
+                Frequency::Yearly => {
+                    if self.months.is_empty() {
+                        self.months.push(start.month());
+                    }
+                    self.month_days.push(start.day() as i32);
+                }
+                Frequency::Monthly => self.month_days.push(start.day() as i32),
+                Frequency::Weekly => self.week_days.push((None, start.weekday())),
+                _ => {}
+            }
+        }
+    }
+
+    /// Returns the first occurrence equal to or after `from` of the rule that
+    /// starts at `start`.
+    fn next(&self, start: NaiveDateTime, from: NaiveDateTime) -> Option<NaiveDateTime> {
+        let base = self.base(start)?;
+        let from = from.max(start);
+        // without a count, the periods before `from` don't matter
+        let mut period = match self.count {
+            Some(_) => 0,
+            None => self.period_at(base, from).max(0),
+        };
+        let years = match self.frequency {
+            Frequency::Yearly => self.interval,
+            Frequency::Monthly => self.interval / 12 + 1,
+            _ => 1,
+        };
+        // every pattern of the calendar repeats after 400 years
+        let limit = i64::from(from.year()) + 400 + i64::from(years);
+        let mut count = 0;
+        loop {
+            let period_start = self.period_start(base, period)?;
+            if i64::from(period_start.year()) > limit {
+                return None;
+            }
+            if self.frequency < Frequency::Daily && !self.matches_day(period_start.date()) {
+                // skip the rest of the day
+                let next_day = period_start.date().succ_opt()?.and_time(NaiveTime::MIN);
+                period = self.period_at(base, next_day - chrono::Duration::seconds(1)) + 1;
+                continue;
+            }
+            for time in self.occurrences(period_start) {
+                if time < start {
+                    continue;
+                }
+                if self.until.is_some_and(|until| time > until) {
+                    return None;
+                }
+                count += 1;
+                if self.count.is_some_and(|max| count > max) {
+                    return None;
+                }
+                if time >= from {
+                    return Some(time);
+                }
+            }
+            period += 1;
+        }
+    }
+
+    /// Returns the start of the first period.
+    fn base(&self, start: NaiveDateTime) -> Option<NaiveDateTime> {
+        let date = start.date();
+        match self.frequency {
+            Frequency::Yearly => date.with_ordinal(1)?.and_hms_opt(0, 0, 0),
+            Frequency::Monthly => date.with_day(1)?.and_hms_opt(0, 0, 0),
+            Frequency::Weekly => {
+                let days = days_since(date.weekday(), self.week_start);
+                (date - chrono::Duration::days(days)).and_hms_opt(0, 0, 0)
+            }
+            Frequency::Daily => date.and_hms_opt(0, 0, 0),
+            Frequency::Hourly => date.and_hms_opt(start.hour(), 0, 0),
+            Frequency::Minutely => date.and_hms_opt(start.hour(), start.minute(), 0),
+            Frequency::Secondly => Some(start),
+        }
+    }
+
+    /// Returns the index of the last period that starts at or before `time`.
+    fn period_at(&self, base: NaiveDateTime, time: NaiveDateTime) -> i64 {
+        let periods = match self.frequency {
+            Frequency::Yearly => i64::from(time.year() - base.year()),
+            Frequency::Monthly => {
+                i64::from(time.year() - base.year()) * 12 + i64::from(time.month())
+                    - i64::from(base.month())
+            }
+            frequency => (time - base).num_seconds().div_euclid(frequency.seconds()),
+        };
+        periods.div_euclid(i64::from(self.interval))
+    }
+
+    fn period_start(&self, base: NaiveDateTime, period: i64) -> Option<NaiveDateTime> {
+        let step = period.checked_mul(i64::from(self.interval))?;
+        let month = match self.frequency {
+            Frequency::Yearly => step.checked_mul(12)?,
+            Frequency::Monthly => step,
+            frequency => {
+                let seconds =
+                    chrono::Duration::try_seconds(step.checked_mul(frequency.seconds())?)?;
+                return base.checked_add_signed(seconds);
+            }
+        };
+        let month = i64::from(base.month0()).checked_add(month)?;
+        let year = i64::from(base.year()).checked_add(month.div_euclid(12))?;
+        NaiveDate::from_ymd_opt(year.try_into().ok()?, month.rem_euclid(12) as u32 + 1, 1)?
+            .and_hms_opt(0, 0, 0)
+    }
+
+    /// Returns the occurrences in the period that starts at `period_start`,
+    /// sorted.
+    fn occurrences(&self, period_start: NaiveDateTime) -> Vec<NaiveDateTime> {
+        let first = period_start.date();
+        let days = match self.frequency {
+            Frequency::Yearly => days_in_year(first.year()),
+            Frequency::Monthly => days_in_month(first),
+            Frequency::Weekly => 7,
+            _ => 1,
+        };
+        let times = self.times(period_start);
+        let occurrences: Vec<_> = first
+            .iter_days()
+            .take(days as usize)
+            .filter(|&date| self.matches_day(date))
+            .flat_map(|date| times.iter().map(move |&time| date.and_time(time)))
+            .collect();
+        if self.positions.is_empty() {
+            return occurrences;
+        }
+        let len = occurrences.len() as i32;
+        let mut selected: Vec<_> = self
+            .positions
+            .iter()
+            .map(|&position| {
+                if position > 0 {
+                    position - 1
+                } else {
+                    len + position
+                }
+            })
+            .filter(|index| (0..len).contains(index))
+            .map(|index| occurrences[index as usize])
+            .collect();
+        selected.sort_unstable();
+        selected.dedup();
+        selected
+    }
+
+    /// Returns the times of day of the period that starts at `period_start`,
+    /// sorted.
+    fn times(&self, period_start: NaiveDateTime) -> Vec<NaiveTime> {
+        let fixed = |values: &[u32], value: u32| {
+            if values.is_empty() || values.contains(&value) {
+                vec![value]
+            } else {
+                Vec::new()
+            }
+        };
+        let hours = match self.frequency {
+            Frequency::Secondly | Frequency::Minutely | Frequency::Hourly => {
+                fixed(&self.hours, period_start.hour())
+            }
+            _ => self.hours.clone(),
+        };
+        let minutes = match self.frequency {
+            Frequency::Secondly | Frequency::Minutely => {
+                fixed(&self.minutes, period_start.minute())
+            }
+            _ => self.minutes.clone(),
+        };
+        let seconds = match self.frequency {
+            Frequency::Secondly => fixed(&self.seconds, period_start.second()),
+            _ => self.seconds.clone(),
+        };
+        let mut times = Vec::new();
+        for &hour in &hours {
+            for &minute in &minutes {
+                for &second in &seconds {
+                    times.extend(NaiveTime::from_hms_opt(hour, minute, second));
+                }
+            }
+        }
+        times
+    }
+
+    fn matches_day(&self, date: NaiveDate) -> bool {
+        (self.months.is_empty() || self.months.contains(&date.month()))
+            && (self.week_numbers.is_empty() || self.matches_week_number(date))
+            && (self.year_days.is_empty()
+                || matches_ordinal(&self.year_days, date.ordinal(), days_in_year(date.year())))
+            && (self.month_days.is_empty()
+                || matches_ordinal(&self.month_days, date.day(), days_in_month(date)))
+            && (self.week_days.is_empty()
+                || self.week_days.iter().any(|&(nth, weekday)| {
+                    date.weekday() == weekday && nth.is_none_or(|nth| self.is_nth(date, nth))
+                }))
+    }
+
+    /// Returns whether `date` is the `nth` of its weekday in its month, or in
+    /// its year for yearly rules that don't restrict the month.
+    fn is_nth(&self, date: NaiveDate, nth: i32) -> bool {
+        let (day, days) = if self.frequency == Frequency::Monthly || !self.months.is_empty() {
+            (date.day(), days_in_month(date))
+        } else {
+            (date.ordinal(), days_in_year(date.year()))
+        };
+        nth == ((day - 1) / 7 + 1) as i32 || nth == -(((days - day) / 7 + 1) as i32)
+    }
+
+    fn matches_week_number(&self, date: NaiveDate) -> bool {
+        let Some(week) = self.week_number(date) else {
+            return false;
+        };
+        let (week, weeks) = week;
+        self.week_numbers
+            .iter()
+            .any(|&number| number == week || number == week - weeks - 1)
+    }
+
+    /// Returns the week number of `date` and the number of weeks in its year,
+    /// week 1 is the first week with at least 4 days in the year.
+    fn week_number(&self, date: NaiveDate) -> Option<(i32, i32)> {
+        let mut year = date.year();
+        if date < self.first_week(year)? {
+            year -= 1;
+        } else if date >= self.first_week(year + 1)? {
+            year += 1;
+        }
+        let first = self.first_week(year)?;
+        let weeks = (self.first_week(year + 1)? - first).num_days() / 7;
+        let week = (date - first).num_days() / 7 + 1;
+        Some((week as i32, weeks as i32))
+    }
+
+    /// Returns the first day of week 1 of `year`.
+    fn first_week(&self, year: i32) -> Option<NaiveDate> {
+        let new_year = NaiveDate::from_ymd_opt(year, 1, 1)?;
+        let days = days_since(new_year.weekday(), self.week_start);
+        if days <= 3 {
+            Some(new_year - chrono::Duration::days(days))
+        } else {
+            Some(new_year + chrono::Duration::days(7 - days))
+        }
+    }
+}
+
+/// Returns the number of days from the last `start` to `weekday`.
+fn days_since(weekday: Weekday, start: Weekday) -> i64 {
+    i64::from((7 + weekday.num_days_from_monday() - start.num_days_from_monday()) % 7)
+}
+
+fn days_in_year(year: i32) -> u32 {
+    if NaiveDate::from_ymd_opt(year, 2, 29).is_some() {
+        366
+    } else {
+        365
+    }
+}
+
+fn days_in_month(date: NaiveDate) -> u32 {
+    (28..=31)
+        .rev()
+        .find(|&day| date.with_day(day).is_some())
+        .unwrap_or(28)
+}
+
+/// Returns whether `value`, counted from the start, or counted from the end
+/// of `count` values as a negative number, is one of `values`.
+fn matches_ordinal(values: &[i32], value: u32, count: u32) -> bool {
+    let value = value as i32;
+    values
+        .iter()
+        .any(|&ordinal| ordinal == value || ordinal == value - count as i32 - 1)
+}
+
+fn parse_frequency(value: &str, position: usize) -> Result<Frequency, RecurrenceError> {
+    match value.to_ascii_uppercase().as_str() {
+        "SECONDLY" => Ok(Frequency::Secondly),
+        "MINUTELY" => Ok(Frequency::Minutely),
+        "HOURLY" => Ok(Frequency::Hourly),
+        "DAILY" => Ok(Frequency::Daily),
+        "WEEKLY" => Ok(Frequency::Weekly),
+        "MONTHLY" => Ok(Frequency::Monthly),
+        "YEARLY" => Ok(Frequency::Yearly),
+        _ => Err(invalid_value(value, position, "FREQ")),
+    }
+}
+
+fn parse_weekday(value: &str, position: usize) -> Result<Weekday, RecurrenceError> {
+    match value.to_ascii_uppercase().as_str() {
+        "MO" => Ok(Weekday::Mon),
+        "TU" => Ok(Weekday::Tue),
+        "WE" => Ok(Weekday::Wed),
+        "TH" => Ok(Weekday::Thu),
+        "FR" => Ok(Weekday::Fri),
+        "SA" => Ok(Weekday::Sat),
+        "SU" => Ok(Weekday::Sun),
+        _ => Err(invalid_value(value, position, "weekday")),
+    }
+}
+
+/// Parses a `BYDAY` item, like `TU`, `2TU` or `-1FR`.
+fn parse_week_day(value: &str, position: usize) -> Result<(Option<i32>, Weekday), RecurrenceError> {
+    let split = value.len().saturating_sub(2);
+    if !value.is_char_boundary(split) {
+        return Err(invalid_value(value, position, "BYDAY"));
+    }
+    let (nth, weekday) = value.split_at(split);
+    let weekday = parse_weekday(weekday, position + split)?;
+    if nth.is_empty() {
+        return Ok((None, weekday));
+    }
+    let nth = parse_number(nth, position, "BYDAY", -53, 53)?;
+    Ok((Some(nth), weekday))
+}
+
+/// Parses a comma separated list of numbers in `min..=max`, zero is out of
+/// range if `min` is negative.
+fn parse_list<T: TryFrom<i32>>(
+    value: &str,
+    position: usize,
+    name: &'static str,
+    min: i32,
+    max: i32,
+) -> Result<Vec<T>, RecurrenceError> {
+    let mut values = split(value, position, ',')
+        .map(|(position, item)| parse_number(item, position, name, min, max))
+        .collect::<Result<Vec<_>, _>>()?;
+    values.sort_unstable();
+    values.dedup();
+    Ok(values
+        .into_iter()
+        .filter_map(|value| T::try_from(value).ok())
+        .collect())
+}
+
+fn parse_number(
+    value: &str,
+    position: usize,
+    name: &'static str,
+    min: i32,
+    max: i32,
+) -> Result<i32, RecurrenceError> {
+    if value.is_empty()
+        || !value
+            .trim_start_matches(['+', '-'])
+            .bytes()
+            .all(|byte| byte.is_ascii_digit())
+    {
+        return Err(invalid_value(value, position, name));
+    }
+    match value.parse::<i32>() {
+        Ok(number) if (min..=max).contains(&number) && (min >= 0 || number != 0) => Ok(number),
+        _ => Err(RecurrenceError::new(
+            position,
+            RecurrenceErrorKind::OutOfRange {
+                name,
+                value: value.to_owned(),
+                min,
+                max,
+            },
+        )),
+    }
+}
+
+fn invalid_value(value: &str, position: usize, name: &'static str) -> RecurrenceError {
+    RecurrenceError::new(
+        position,
+        RecurrenceErrorKind::InvalidValue {
+            name,
+            value: value.to_owned(),
+        },
+    )
+}
+
+/// An error returned when parsing a [`Recurrence`] fails.
+///
+/// *This type requires the following crate features to be activated: `rrule`*
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecurrenceError {
+    position: usize,
+    kind: RecurrenceErrorKind,
+}
+
+/// The reason a [`Recurrence`] failed to parse.
+///
+/// *This type requires the following crate features to be activated: `rrule`*
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RecurrenceErrorKind {
+    /// The line is not of the form `NAME:VALUE`.
+    InvalidLine,
+    /// The property is not one of `DTSTART`, `RRULE`, `EXRULE`, `RDATE` and
+    /// `EXDATE`.
+    UnknownProperty(String),
+    /// There is no `DTSTART` line.
+    MissingStart,
+    /// `DTSTART` or a rule part is given more than once.
+    Duplicate(String),
+    /// The rule part is not one of RFC 5545.
+    UnknownPart(String),
+    /// The rule has no `FREQ` part.
+    MissingFrequency,
+    /// The value can't be parsed.
+    InvalidValue {
+        /// The name of the property or the rule part, like `"BYDAY"`.
+        name: &'static str,
+        /// The value as written.
+        value: String,
+    },
+    /// The value is outside of the range of the rule part. Zero is out of
+    /// range of the parts that also count from the end, like `BYMONTHDAY`.
+    OutOfRange {
+        /// The name of the rule part, like `"BYMONTHDAY"`.
+        name: &'static str,
+        /// The value as written.
+        value: String,
+        /// The smallest value of the rule part.
+        min: i32,
+        /// The largest value of the rule part.
+        max: i32,
+    },
+    /// The `TZID` is not a known time zone, or the `chrono-tz` feature is not
+    /// activated.
+    UnknownTimeZone(String),
+    /// The rule parts or the dates can't be used together, like `COUNT` and
+    /// `UNTIL`.
+    Conflict(&'static str),
+}
+
+impl RecurrenceError {
+    fn new(position: usize, kind: RecurrenceErrorKind) -> RecurrenceError {
+        RecurrenceError { position, kind }
+    }
+
+    /// Returns the byte offset into the text at which the error was found.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the reason the recurrence failed to parse.
+    pub fn kind(&self) -> &RecurrenceErrorKind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for RecurrenceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid recurrence at position {}: ", self.position)?;
+        match &self.kind {
+            RecurrenceErrorKind::InvalidLine => f.write_str("expected `NAME:VALUE`"),
+            RecurrenceErrorKind::UnknownProperty(name) => {
+                write!(f, "unknown property `{}`", name)
+            }
+            RecurrenceErrorKind::MissingStart => f.write_str("missing DTSTART"),
+            RecurrenceErrorKind::Duplicate(name) => write!(f, "duplicate `{}`", name),
+            RecurrenceErrorKind::UnknownPart(part) => write!(f, "unknown rule part `{}`", part),
+            RecurrenceErrorKind::MissingFrequency => f.write_str("missing FREQ"),
+            RecurrenceErrorKind::InvalidValue { name, value } => {
+                write!(f, "invalid {} `{}`", name, value)
+            }
+            RecurrenceErrorKind::OutOfRange {
+                name,
+                value,
+                min,
+                max,
+            } => write!(f, "{} `{}` is not in {} to {}", name, value, min, max),
+            RecurrenceErrorKind::UnknownTimeZone(name) => {
+                write!(f, "unknown time zone `{}`", name)
+            }
+            RecurrenceErrorKind::Conflict(reason) => f.write_str(reason),
+        }
+    }
+}
+
+impl std::error::Error for RecurrenceError {}
+
+/// Returns the duration to the next occurrence of the recurrence, `None` if
+/// there are no occurrences left.
+///
+/// # Example
+///
+/// ```rust
+/// use zila::{duration_to_next_recurrence, Recurrence};
+///
+/// let recurrence = Recurrence::parse(
+///     "DTSTART:20230509T090000\n\
+///      RRULE:FREQ=SECONDLY;INTERVAL=5",
+/// )
+/// .unwrap();
+/// let duration = duration_to_next_recurrence(&recurrence).unwrap();
+/// assert!(duration.as_secs() < 5);
+/// ```
+///
+/// *This function requires the following crate features to be activated: `rrule`*
+pub fn duration_to_next_recurrence(recurrence: &Recurrence) -> Option<Duration> {
+    duration_to_next_recurrence_in(recurrence, &Local)
+}
+
+/// Returns the duration to the next occurrence of the recurrence with its
+/// floating times in the given time zone, `None` if there are no occurrences
+/// left.
+///
+/// # Example
+///
+/// ```rust
+/// use zila::chrono_tz::America::New_York;
+/// use zila::{duration_to_next_recurrence_in, Recurrence};
+///
+/// let recurrence = Recurrence::parse(
+///     "DTSTART:20230509T090000\n\
+///      RRULE:FREQ=DAILY",
+/// )
+/// .unwrap();
+/// let duration = duration_to_next_recurrence_in(&recurrence, &New_York).unwrap();
+/// assert!(duration.as_secs() <= 25 * 60 * 60);
+/// ```
+///
+/// *This function requires the following crate features to be activated: `rrule`*
+pub fn duration_to_next_recurrence_in<Tz: TimeZone>(
+    recurrence: &Recurrence,
+    tz: &Tz,
+) -> Option<Duration> {
+    crate::duration_to_next_in(recurrence, tz)
+}
+
+/// calls the given function at every occurrence of the recurrence
+///
+/// The timer finishes after the last occurrence.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::{call_every_recurrence, Recurrence};
+///
+/// let recurrence = Recurrence::parse(
+///     "DTSTART:20230509T090000\n\
+///      RRULE:FREQ=MONTHLY;BYDAY=2TU;COUNT=10",
+/// )
+/// .unwrap();
+/// call_every_recurrence(recurrence, || {
+///     println!("Patch Tuesday");
+/// });
+/// ```
+///
+/// *This function requires the following crate features to be activated: `rrule`*
+pub fn call_every_recurrence<F, R>(recurrence: Recurrence, callback: F) -> R::Output
+where
+    F: Fn() -> R,
+    R: Flow,
+{
+    call_every_recurrence_mut(recurrence, callback)
+}
+
+/// calls the given function at every occurrence of the recurrence, takes a
+/// `FnMut` as the argument
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use zila::{call_every_recurrence_mut, Recurrence};
+///
+/// let recurrence = Recurrence::parse(
+///     "DTSTART:20230509T090000\n\
+///      RRULE:FREQ=WEEKLY;BYDAY=MO,WE,FR",
+/// )
+/// .unwrap();
+/// let num = Rc::new(Cell::new(0));
+/// call_every_recurrence_mut(recurrence, {
+///     let num = num.clone();
+///     move || {
+///         num.set(num.get() + 1);
+///     }
+/// });
+/// ```
+///
+/// *This function requires the following crate features to be activated: `rrule`*
+pub fn call_every_recurrence_mut<F, R>(recurrence: Recurrence, callback: F) -> R::Output
+where
+    F: FnMut() -> R,
+    R: Flow,
+{
+    call_every_recurrence_in_mut(recurrence, Local, callback)
+}
+
+/// calls the given async function at every occurrence of the recurrence
+///
+/// The task finishes after the last occurrence.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::{call_every_recurrence_async, Recurrence};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let recurrence = Recurrence::parse(
+///     "DTSTART:20230509T090000\n\
+///      RRULE:FREQ=MONTHLY;BYDAY=2TU;COUNT=10",
+/// )
+/// .unwrap();
+/// call_every_recurrence_async(recurrence, || async {
+///     println!("Patch Tuesday");
+/// })
+/// .await;
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `rrule`*
+pub async fn call_every_recurrence_async<F, Fut, R>(
+    recurrence: Recurrence,
+    callback: F,
+) -> R::Output
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = R>,
+    R: Flow,
+{
+    call_every_recurrence_async_mut(recurrence, callback).await
+}
+
+/// calls the given async function at every occurrence of the recurrence,
+/// takes a `FnMut` as the argument
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use zila::{call_every_recurrence_async_mut, Recurrence};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let recurrence = Recurrence::parse(
+///     "DTSTART:20230509T090000\n\
+///      RRULE:FREQ=WEEKLY;BYDAY=MO,WE,FR",
+/// )
+/// .unwrap();
+/// let num = Rc::new(Cell::new(0));
+/// call_every_recurrence_async_mut(recurrence, move || {
+///     let num = num.clone();
+///     async move {
+///         num.set(num.get() + 1);
+///         println!("This is printed {} times.", num.get());
+///     }
+/// })
+/// .await;
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `rrule`*
+pub async fn call_every_recurrence_async_mut<F, Fut, R>(
+    recurrence: Recurrence,
+    callback: F,
+) -> R::Output
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = R>,
+    R: Flow,
+{
+    call_every_recurrence_in_async_mut(recurrence, Local, callback).await
+}
+
+/// calls the given function at every occurrence of the recurrence with its
+/// floating times in the given time zone
+///
+/// Like [`call_every_recurrence`], but floating times are the wall time of
+/// `tz` instead of the local time zone of the machine. Times in UTC or with a
+/// `TZID` are not affected.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::{call_every_recurrence_in, Recurrence};
+///
+/// let recurrence = Recurrence::parse(
+///     "DTSTART:20230509T090000\n\
+///      RRULE:FREQ=MONTHLY;BYMONTHDAY=-1",
+/// )
+/// .unwrap();
+/// call_every_recurrence_in(recurrence, Berlin, || {
+///     println!("The last day of the month in Berlin");
+/// });
+/// ```
+///
+/// *This function requires the following crate features to be activated: `rrule`*
+pub fn call_every_recurrence_in<Tz, F, R>(recurrence: Recurrence, tz: Tz, callback: F) -> R::Output
+where
+    Tz: TimeZone + Send + 'static,
+    F: Fn() -> R,
+    R: Flow,
+{
+    call_every_recurrence_in_mut(recurrence, tz, callback)
+}
+
+/// calls the given function at every occurrence of the recurrence with its
+/// floating times in the given time zone, takes a `FnMut` as the argument
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::{call_every_recurrence_in_mut, Recurrence};
+///
+/// let recurrence = Recurrence::parse(
+///     "DTSTART:20230509T090000\n\
+///      RRULE:FREQ=HOURLY;INTERVAL=4",
+/// )
+/// .unwrap();
+/// let num = Rc::new(Cell::new(0));
+/// call_every_recurrence_in_mut(recurrence, Berlin, {
+///     let num = num.clone();
+///     move || {
+///         num.set(num.get() + 1);
+///     }
+/// });
+/// ```
+///
+/// *This function requires the following crate features to be activated: `rrule`*
+pub fn call_every_recurrence_in_mut<Tz, F, R>(
+    recurrence: Recurrence,
+    tz: Tz,
+    mut callback: F,
+) -> R::Output
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> R,
+    R: Flow,
+{
+    Timer::recurrence_in(recurrence, tz).run(move |_| callback())
+}
+
+/// calls the given async function at every occurrence of the recurrence with
+/// its floating times in the given time zone
+///
+/// Like [`call_every_recurrence_async`], but floating times are the wall time
+/// of `tz` instead of the local time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::{call_every_recurrence_in_async, Recurrence};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let recurrence = Recurrence::parse(
+///     "DTSTART:20230509T090000\n\
+///      RRULE:FREQ=MONTHLY;BYMONTHDAY=-1",
+/// )
+/// .unwrap();
+/// call_every_recurrence_in_async(recurrence, Berlin, || async {
+///     println!("The last day of the month in Berlin");
+/// })
+/// .await;
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `rrule`*
+pub async fn call_every_recurrence_in_async<Tz, F, Fut, R>(
+    recurrence: Recurrence,
+    tz: Tz,
+    callback: F,
+) -> R::Output
+where
+    Tz: TimeZone + Send + 'static,
+    F: Fn() -> Fut,
+    Fut: Future<Output = R>,
+    R: Flow,
+{
+    call_every_recurrence_in_async_mut(recurrence, tz, callback).await
+}
+
+/// calls the given async function at every occurrence of the recurrence with
+/// its floating times in the given time zone, takes a `FnMut` as the argument
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::{call_every_recurrence_in_async_mut, Recurrence};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let recurrence = Recurrence::parse(
+///     "DTSTART:20230509T090000\n\
+///      RRULE:FREQ=HOURLY;INTERVAL=4",
+/// )
+/// .unwrap();
+/// let num = Rc::new(Cell::new(0));
+/// call_every_recurrence_in_async_mut(recurrence, Berlin, move || {
+///     let num = num.clone();
+///     async move {
+///         num.set(num.get() + 1);
+///         println!("This is printed {} times.", num.get());
+///     }
+/// })
+/// .await;
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `rrule`*
+pub async fn call_every_recurrence_in_async_mut<Tz, F, Fut, R>(
+    recurrence: Recurrence,
+    tz: Tz,
+    mut callback: F,
+) -> R::Output
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = R>,
+    R: Flow,
+{
+    Timer::recurrence_in(recurrence, tz)
+        .run_async(move |_| callback())
+        .await
+}
+
+/// calls the given fallible function at every occurrence of the recurrence
+///
+/// Like [`call_every_recurrence_mut`], but the function returns a `Result`. What
+/// happens to an error is decided by `policy`, see [`ErrorPolicy`]. When the
+/// policy stops the timer, the function returns the error.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::{try_call_every_recurrence, Recurrence, ErrorPolicy};
+///
+/// fn rotate_logs() -> std::io::Result<()> {
+///     std::fs::rename("app.log", "app.log.1")
+/// }
+///
+/// let recurrence = Recurrence::parse("FREQ=WEEKLY;BYDAY=MO").unwrap();
+/// let result = try_call_every_recurrence(recurrence, rotate_logs, ErrorPolicy::Stop);
+/// if let Err(error) = result {
+///     eprintln!("rotate_logs failed: {}", error);
+/// }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `rrule`*
+pub fn try_call_every_recurrence<F, E>(
+    recurrence: Recurrence,
+    callback: F,
+    policy: ErrorPolicy<E>,
+) -> Result<(), E>
+where
+    F: FnMut() -> Result<(), E>,
+    E: fmt::Display + Send + 'static,
+{
+    try_call_every_recurrence_in(recurrence, Local, callback, policy)
+}
+
+/// calls the given fallible async function at every occurrence of the
+/// recurrence
+///
+/// Like [`call_every_recurrence_async_mut`], but the future returns a `Result`.
+/// What happens to an error is decided by `policy`, see [`ErrorPolicy`]. When
+/// the policy stops the timer, the function returns the error.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::{try_call_every_recurrence_async, Recurrence, ErrorPolicy};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let recurrence = Recurrence::parse("FREQ=WEEKLY;BYDAY=MO").unwrap();
+/// try_call_every_recurrence_async(recurrence, || async {
+///     std::fs::rename("app.log", "app.log.1")
+/// }, ErrorPolicy::Log)
+/// .await
+/// .unwrap();
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `rrule`*
+pub async fn try_call_every_recurrence_async<F, Fut, E>(
+    recurrence: Recurrence,
+    callback: F,
+    policy: ErrorPolicy<E>,
+) -> Result<(), E>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<(), E>>,
+    E: fmt::Display + Send + 'static,
+{
+    try_call_every_recurrence_in_async(recurrence, Local, callback, policy).await
+}
+
+/// calls the given fallible function at every occurrence of the recurrence
+/// with its floating times in the given time zone
+///
+/// Like [`try_call_every_recurrence`], but floating times are the wall
+/// time of `tz` instead of the local time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::{try_call_every_recurrence_in, Recurrence, ErrorPolicy};
+///
+/// let policy = ErrorPolicy::on_error(|error: &std::io::Error| {
+///     eprintln!("rotate_logs failed: {}", error);
+/// });
+/// let recurrence = Recurrence::parse("FREQ=WEEKLY;BYDAY=MO").unwrap();
+/// try_call_every_recurrence_in(recurrence, Berlin, || std::fs::rename("app.log", "app.log.1"), policy)
+///     .unwrap();
+/// ```
+///
+/// *This function requires the following crate features to be activated: `rrule`*
+pub fn try_call_every_recurrence_in<Tz, F, E>(
+    recurrence: Recurrence,
+    tz: Tz,
+    mut callback: F,
+    policy: ErrorPolicy<E>,
+) -> Result<(), E>
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> Result<(), E>,
+    E: fmt::Display + Send + 'static,
+{
+    Timer::recurrence_in(recurrence, tz).try_run(move |_| callback(), policy)
+}
+
+/// calls the given fallible async function at every occurrence of the
+/// recurrence with its floating times in the given time zone
+///
+/// Like [`try_call_every_recurrence_async`], but floating times are the
+/// wall time of `tz` instead of the local time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::{try_call_every_recurrence_in_async, Recurrence, ErrorPolicy};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let recurrence = Recurrence::parse("FREQ=WEEKLY;BYDAY=MO").unwrap();
+/// try_call_every_recurrence_in_async(recurrence, Berlin, || async {
+///     std::fs::rename("app.log", "app.log.1")
+/// }, ErrorPolicy::Stop)
+/// .await
+/// .unwrap();
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `rrule`*
+pub async fn try_call_every_recurrence_in_async<Tz, F, Fut, E>(
+    recurrence: Recurrence,
+    tz: Tz,
+    mut callback: F,
+    policy: ErrorPolicy<E>,
+) -> Result<(), E>
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<(), E>>,
+    E: fmt::Display + Send + 'static,
+{
+    Timer::recurrence_in(recurrence, tz)
+        .try_run_async(move |_| callback(), policy)
+        .await
+}
+
+/// calls the given function every time the recurrence occurs on a background
+/// thread
+///
+/// Like [`call_every_recurrence_mut`], but the timer runs on a thread named
+/// `zila-call-every-recurrence` and the function returns right away with a
+/// [`TimerHandle`] that cancels the timer or waits for it to finish.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::{spawn_call_every_recurrence, Recurrence};
+///
+/// let recurrence = Recurrence::parse("FREQ=MONTHLY;BYDAY=2TU").unwrap();
+/// let handle = spawn_call_every_recurrence(recurrence, || {
+///     println!("Hi");
+/// });
+///
+/// // stop the timer on shutdown
+/// handle.cancel();
+/// ```
+///
+/// *This function requires the following crate features to be activated: `rrule`*
+pub fn spawn_call_every_recurrence<F, R>(
+    recurrence: Recurrence,
+    callback: F,
+) -> TimerHandle<R::Output>
+where
+    F: FnMut() -> R + Send + 'static,
+    R: Flow,
+{
+    spawn_call_every_recurrence_in(recurrence, Local, callback)
+}
+
+/// calls the given function every time the recurrence occurs in the given time
+/// zone on a background thread
+///
+/// Like [`spawn_call_every_recurrence`], but the recurrence follows `tz`
+/// instead of the local time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::{spawn_call_every_recurrence_in, Recurrence};
+///
+/// let recurrence = Recurrence::parse("FREQ=MONTHLY;BYDAY=2TU").unwrap();
+/// let handle = spawn_call_every_recurrence_in(recurrence, Berlin, || {
+///     println!("Hi");
+/// });
+///
+/// // stop the timer on shutdown
+/// handle.cancel();
+/// ```
+///
+/// *This function requires the following crate features to be activated: `rrule`*
+pub fn spawn_call_every_recurrence_in<Tz, F, R>(
+    recurrence: Recurrence,
+    tz: Tz,
+    mut callback: F,
+) -> TimerHandle<R::Output>
+where
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> R + Send + 'static,
+    R: Flow,
+{
+    Timer::recurrence_in(recurrence, tz).spawn(move |_| callback())
+}
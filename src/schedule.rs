@@ -0,0 +1,1307 @@
+//! Schedules, the instants at which a timer fires.
+
+#[cfg(any(
+    feature = "day",
+    feature = "hour",
+    feature = "minute",
+    feature = "second",
+    feature = "week",
+    feature = "month",
+    feature = "quarter",
+    feature = "year",
+    feature = "interval"
+))]
+use crate::calendar::{self, Fold};
+use crate::describe;
+use crate::{Flow, Timer};
+#[cfg(any(
+    feature = "week",
+    feature = "month",
+    feature = "quarter",
+    feature = "year"
+))]
+use chrono::Datelike;
+#[cfg(any(feature = "day", feature = "hour"))]
+use chrono::NaiveDateTime;
+#[cfg(any(
+    feature = "day",
+    feature = "week",
+    feature = "month",
+    feature = "quarter",
+    feature = "year",
+    feature = "interval"
+))]
+use chrono::NaiveTime;
+#[cfg(any(feature = "hour", feature = "minute"))]
+use chrono::Timelike;
+#[cfg(feature = "week")]
+use chrono::Weekday;
+use chrono::{DateTime, Local, TimeZone, Utc};
+#[cfg(any(feature = "month", feature = "quarter", feature = "year"))]
+use chrono::{Months, NaiveDate};
+use std::fmt;
+use std::future::Future;
+use std::time::Duration;
+
+/// The instants at which a timer fires.
+///
+/// A schedule only has to answer one question, what is the first instant after
+/// a given one at which it fires. The built-in schedules cover the calendar
+/// boundaries ([`EveryDay`], [`EveryHour`], [`EveryMinute`], [`EverySecond`]),
+/// fixed intervals ([`Interval`]), one-shots ([`Once`]) and cron expressions,
+/// any other schedule can be run by implementing this trait.
+///
+/// Schedules are evaluated in the time zone of the instant they are given, so
+/// the same [`EveryDay`] fires at midnight in Berlin or in New York depending
+/// on the time zone it is [run](crate::run_in) in.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{DateTime, DurationRound, TimeZone, Utc};
+/// use zila::Schedule;
+///
+/// /// Fires every quarter of an hour.
+/// struct Quarterly;
+///
+/// impl Schedule for Quarterly {
+///     fn next_after<Tz: TimeZone>(&self, time: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+///         let quarter = chrono::Duration::minutes(15);
+///         Some(time.clone().duration_trunc(quarter).ok()? + quarter)
+///     }
+/// }
+///
+/// let time = Utc.with_ymd_and_hms(2023, 5, 9, 13, 45, 0).unwrap();
+/// let times: Vec<_> = Quarterly.iter_after(time).take(2).collect();
+/// assert_eq!(times, [
+///     Utc.with_ymd_and_hms(2023, 5, 9, 14, 0, 0).unwrap(),
+///     Utc.with_ymd_and_hms(2023, 5, 9, 14, 15, 0).unwrap(),
+/// ]);
+/// ```
+///
+/// *This trait requires at least one of the following crate features to be activated: `interval`,
+/// `day`, `hour`, `minute`, `second`, `week`, `month`, `quarter`, `year`, `cron`, `rrule`, `systemd`, `human`*
+pub trait Schedule {
+    /// Returns the first instant strictly after `time` at which the schedule
+    /// fires, `None` if it never fires again.
+    fn next_after<Tz: TimeZone>(&self, time: &DateTime<Tz>) -> Option<DateTime<Tz>>;
+
+    /// Returns an iterator over the instants at which the schedule fires after
+    /// `time`.
+    fn iter_after<Tz: TimeZone>(&self, time: DateTime<Tz>) -> Upcoming<'_, Self, Tz>
+    where
+        Self: Sized,
+    {
+        Upcoming {
+            schedule: self,
+            last: Some(time),
+        }
+    }
+
+    /// Returns an iterator over the instants at which the schedule fires from
+    /// now on, in the local time zone.
+    fn iter(&self) -> Upcoming<'_, Self, Local>
+    where
+        Self: Sized,
+    {
+        // the first instant may be right now
+        self.iter_after(Local::now() - chrono::Duration::nanoseconds(1))
+    }
+
+    /// Returns the next `n` instants at which the schedule fires, in the local
+    /// time zone, fewer if it stops firing before.
+    fn upcoming(&self, n: usize) -> Vec<DateTime<Local>>
+    where
+        Self: Sized,
+    {
+        self.iter().take(n).collect()
+    }
+
+    /// Describes the schedule in plain English, like `every hour at minute 15`
+    /// or `at 09:30 on weekdays`.
+    ///
+    /// The built-in schedules describe themselves, other schedules are
+    /// described as `on a custom schedule` unless they override this method.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use zila::chrono_tz::Europe::Berlin;
+    /// use zila::{Hourly, Schedule};
+    ///
+    /// let hourly = Hourly::at(Duration::from_secs(15 * 60)).unwrap();
+    /// assert_eq!(hourly.describe(), "every hour at minute 15");
+    /// assert_eq!(hourly.describe_in(Berlin), "every hour at minute 15 (Europe/Berlin)");
+    /// ```
+    fn describe(&self) -> String {
+        "on a custom schedule".to_owned()
+    }
+
+    /// Describes the schedule like [`describe`](Schedule::describe), followed
+    /// by the name of the time zone it runs in, like
+    /// `every hour at minute 15 (Europe/Berlin)`.
+    ///
+    /// Schedules that carry a time zone of their own, like an
+    /// [`OnCalendar`](crate::OnCalendar) event ending in `UTC`, name that one
+    /// instead.
+    fn describe_in(&self, zone: impl fmt::Display) -> String {
+        format!("{} ({})", self.describe(), zone)
+    }
+}
+
+/// An iterator over the instants at which a [`Schedule`] fires.
+///
+/// Returned by [`Schedule::iter_after`] and [`Schedule::iter`].
+#[derive(Clone, Debug)]
+pub struct Upcoming<'a, S, Tz: TimeZone> {
+    schedule: &'a S,
+    last: Option<DateTime<Tz>>,
+}
+
+impl<S: Schedule, Tz: TimeZone> Iterator for Upcoming<'_, S, Tz> {
+    type Item = DateTime<Tz>;
+
+    fn next(&mut self) -> Option<DateTime<Tz>> {
+        let next = self.schedule.next_after(self.last.as_ref()?);
+        self.last.clone_from(&next);
+        next
+    }
+}
+
+/// Fires at the start of every day, see
+/// [`start_of_next_day`](crate::start_of_next_day).
+///
+/// *This type requires the following crate features to be activated: `day`*
+#[cfg(feature = "day")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct EveryDay;
+
+#[cfg(feature = "day")]
+impl Schedule for EveryDay {
+    fn next_after<Tz: TimeZone>(&self, time: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        next_midnight(time)
+    }
+
+    fn describe(&self) -> String {
+        "every day at midnight".to_owned()
+    }
+}
+
+#[cfg(feature = "day")]
+impl fmt::Display for EveryDay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.describe())
+    }
+}
+
+/// Returns the first instant of the local day after the day of `time`.
+#[cfg(any(feature = "day", feature = "interval"))]
+fn next_midnight<Tz: TimeZone>(time: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+    let midnight = NaiveTime::MIN;
+    calendar::next_after(time, Fold::Skip, |from| {
+        let date = if from.time() == midnight {
+            from.date()
+        } else {
+            from.date().succ_opt()?
+        };
+        Some(date.and_time(midnight))
+    })
+}
+
+/// Fires at the start of every hour.
+///
+/// When the clocks fall back, the repeated hour starts again.
+///
+/// *This type requires the following crate features to be activated: `hour`*
+#[cfg(feature = "hour")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct EveryHour;
+
+#[cfg(feature = "hour")]
+impl Schedule for EveryHour {
+    fn next_after<Tz: TimeZone>(&self, time: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        calendar::next_after(time, Fold::Repeat, |from| {
+            let hour = from.with_minute(0)?.with_second(0)?;
+            if hour == from {
+                Some(from)
+            } else {
+                hour.checked_add_signed(chrono::Duration::hours(1))
+            }
+        })
+    }
+
+    fn describe(&self) -> String {
+        "every hour".to_owned()
+    }
+}
+
+#[cfg(feature = "hour")]
+impl fmt::Display for EveryHour {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.describe())
+    }
+}
+
+/// Fires at the start of every minute.
+///
+/// *This type requires the following crate features to be activated: `minute`*
+#[cfg(feature = "minute")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct EveryMinute;
+
+#[cfg(feature = "minute")]
+impl Schedule for EveryMinute {
+    fn next_after<Tz: TimeZone>(&self, time: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        calendar::next_after(time, Fold::Repeat, |from| {
+            let minute = from.with_second(0)?;
+            if minute == from {
+                Some(from)
+            } else {
+                minute.checked_add_signed(chrono::Duration::minutes(1))
+            }
+        })
+    }
+
+    fn describe(&self) -> String {
+        "every minute".to_owned()
+    }
+}
+
+#[cfg(feature = "minute")]
+impl fmt::Display for EveryMinute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.describe())
+    }
+}
+
+/// Fires at every whole second.
+///
+/// *This type requires the following crate features to be activated: `second`*
+#[cfg(feature = "second")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct EverySecond;
+
+#[cfg(feature = "second")]
+impl Schedule for EverySecond {
+    fn next_after<Tz: TimeZone>(&self, time: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        calendar::next_after(time, Fold::Repeat, Some)
+    }
+
+    fn describe(&self) -> String {
+        "every second".to_owned()
+    }
+}
+
+#[cfg(feature = "second")]
+impl fmt::Display for EverySecond {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.describe())
+    }
+}
+
+/// Fires at the start of every week, at midnight on the day the week starts.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc, Weekday};
+/// use zila::{EveryWeek, Schedule};
+///
+/// let time = Utc.with_ymd_and_hms(2023, 5, 10, 13, 45, 0).unwrap(); // a Wednesday
+/// let sunday = Utc.with_ymd_and_hms(2023, 5, 14, 0, 0, 0).unwrap();
+/// let monday = Utc.with_ymd_and_hms(2023, 5, 15, 0, 0, 0).unwrap();
+/// assert_eq!(EveryWeek::starting(Weekday::Sun).next_after(&time), Some(sunday));
+/// assert_eq!(EveryWeek::default().next_after(&time), Some(monday));
+/// ```
+///
+/// *This type requires the following crate features to be activated: `week`*
+#[cfg(feature = "week")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EveryWeek {
+    start: Weekday,
+}
+
+#[cfg(feature = "week")]
+impl EveryWeek {
+    /// Creates a schedule for weeks that start on `start`.
+    pub fn starting(start: Weekday) -> EveryWeek {
+        EveryWeek { start }
+    }
+
+    /// Returns the day the weeks start on.
+    pub fn start(&self) -> Weekday {
+        self.start
+    }
+}
+
+#[cfg(feature = "week")]
+impl Default for EveryWeek {
+    /// Weeks that start on Monday, as in ISO 8601.
+    fn default() -> EveryWeek {
+        EveryWeek::starting(Weekday::Mon)
+    }
+}
+
+#[cfg(feature = "week")]
+impl Schedule for EveryWeek {
+    fn next_after<Tz: TimeZone>(&self, time: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        let start = self.start.num_days_from_monday();
+        calendar::next_after(time, Fold::Skip, |from| {
+            let days = (from.weekday().num_days_from_monday() + 7 - start) % 7;
+            let week = from.date() - chrono::Duration::days(days.into());
+            let week = week.and_time(NaiveTime::MIN);
+            if week == from {
+                Some(week)
+            } else {
+                week.checked_add_signed(chrono::Duration::weeks(1))
+            }
+        })
+    }
+
+    fn describe(&self) -> String {
+        format!(
+            "every {} at midnight",
+            describe::WEEKDAYS[self.start.num_days_from_monday() as usize]
+        )
+    }
+}
+
+#[cfg(feature = "week")]
+impl fmt::Display for EveryWeek {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.describe())
+    }
+}
+
+/// Fires at the start of every month, at midnight on the first day.
+///
+/// *This type requires the following crate features to be activated: `month`*
+#[cfg(feature = "month")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct EveryMonth;
+
+#[cfg(feature = "month")]
+impl Schedule for EveryMonth {
+    fn next_after<Tz: TimeZone>(&self, time: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        next_months(time, 1)
+    }
+
+    fn describe(&self) -> String {
+        "at midnight on the first day of every month".to_owned()
+    }
+}
+
+#[cfg(feature = "month")]
+impl fmt::Display for EveryMonth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.describe())
+    }
+}
+
+/// Fires at the start of every quarter, at midnight on the first day of
+/// January, April, July and October.
+///
+/// *This type requires the following crate features to be activated: `quarter`*
+#[cfg(feature = "quarter")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct EveryQuarter;
+
+#[cfg(feature = "quarter")]
+impl Schedule for EveryQuarter {
+    fn next_after<Tz: TimeZone>(&self, time: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        next_months(time, 3)
+    }
+
+    fn describe(&self) -> String {
+        "at midnight on the first day of every quarter".to_owned()
+    }
+}
+
+#[cfg(feature = "quarter")]
+impl fmt::Display for EveryQuarter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.describe())
+    }
+}
+
+/// Fires at the start of every year, at midnight on the first of January.
+///
+/// *This type requires the following crate features to be activated: `year`*
+#[cfg(feature = "year")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct EveryYear;
+
+#[cfg(feature = "year")]
+impl Schedule for EveryYear {
+    fn next_after<Tz: TimeZone>(&self, time: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        next_months(time, 12)
+    }
+
+    fn describe(&self) -> String {
+        "at midnight on the first of January".to_owned()
+    }
+}
+
+#[cfg(feature = "year")]
+impl fmt::Display for EveryYear {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.describe())
+    }
+}
+
+/// Returns the start of the next period of `months` months after `time`, the
+/// periods are aligned to the start of the year.
+#[cfg(any(feature = "month", feature = "quarter", feature = "year"))]
+fn next_months<Tz: TimeZone>(time: &DateTime<Tz>, months: u32) -> Option<DateTime<Tz>> {
+    calendar::next_after(time, Fold::Skip, |from| {
+        let month = from.month0() - from.month0() % months;
+        let start = NaiveDate::from_ymd_opt(from.year(), month + 1, 1)?;
+        let start = start.and_time(NaiveTime::MIN);
+        if start == from {
+            Some(start)
+        } else {
+            start.checked_add_months(Months::new(months))
+        }
+    })
+}
+
+/// Fires at one or more offsets into every hour, like at `:15` or at `:00` and
+/// `:30`.
+///
+/// The offsets are wall clock minutes and seconds, when the clocks fall back
+/// the repeated hour fires again, like [`EveryHour`].
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use std::time::Duration;
+/// use zila::{Hourly, Schedule};
+///
+/// let quarter_past = Hourly::at(Duration::from_secs(15 * 60)).unwrap();
+/// let time = Utc.with_ymd_and_hms(2023, 5, 9, 13, 45, 0).unwrap();
+/// let next = Utc.with_ymd_and_hms(2023, 5, 9, 14, 15, 0).unwrap();
+/// assert_eq!(quarter_past.next_after(&time), Some(next));
+///
+/// assert!(Hourly::at(Duration::from_secs(60 * 60)).is_err());
+/// ```
+///
+/// *This type requires the following crate features to be activated: `hour`*
+#[cfg(feature = "hour")]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Hourly {
+    offsets: Offsets,
+}
+
+#[cfg(feature = "hour")]
+impl Hourly {
+    const PERIOD: Duration = Duration::from_secs(60 * 60);
+
+    /// Creates a schedule that fires `offset` into every hour.
+    ///
+    /// Returns an error if `offset` is not a whole number of seconds or not
+    /// shorter than an hour.
+    pub fn at(offset: Duration) -> Result<Hourly, OffsetError> {
+        Hourly::at_each([offset])
+    }
+
+    /// Creates a schedule that fires at each of the `offsets` into every hour.
+    ///
+    /// Returns an error if there are no `offsets` or if one of them is not a
+    /// whole number of seconds or not shorter than an hour.
+    pub fn at_each<I>(offsets: I) -> Result<Hourly, OffsetError>
+    where
+        I: IntoIterator<Item = Duration>,
+    {
+        let offsets = Offsets::new(offsets, Hourly::PERIOD)?;
+        Ok(Hourly { offsets })
+    }
+}
+
+#[cfg(feature = "hour")]
+impl Schedule for Hourly {
+    fn next_after<Tz: TimeZone>(&self, time: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        calendar::next_after(time, Fold::Repeat, |from| {
+            let hour = from.date().and_hms_opt(from.hour(), 0, 0)?;
+            self.offsets.next_local(from, hour, |hour| {
+                hour.checked_add_signed(chrono::Duration::hours(1))
+            })
+        })
+    }
+
+    fn describe(&self) -> String {
+        describe::hourly(&self.offsets.0)
+    }
+}
+
+#[cfg(feature = "hour")]
+impl fmt::Display for Hourly {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.describe())
+    }
+}
+
+/// Fires at one or more times of every day, like at 02:30 or at 08:00, 12:00
+/// and 18:00.
+///
+/// The times are wall clock times. A time that is skipped because the clocks
+/// sprang forward fires at the transition, a time that happens twice because
+/// the clocks fell back fires only the first time.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use std::time::Duration;
+/// use zila::{Daily, Schedule};
+///
+/// let hours = |hours: u64| Duration::from_secs(hours * 60 * 60);
+/// let meals = Daily::at_each([hours(8), hours(12), hours(18)]).unwrap();
+/// let time = Utc.with_ymd_and_hms(2023, 5, 9, 13, 45, 0).unwrap();
+/// let next = Utc.with_ymd_and_hms(2023, 5, 9, 18, 0, 0).unwrap();
+/// assert_eq!(meals.next_after(&time), Some(next));
+/// ```
+///
+/// *This type requires the following crate features to be activated: `day`*
+#[cfg(feature = "day")]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Daily {
+    offsets: Offsets,
+}
+
+#[cfg(feature = "day")]
+impl Daily {
+    const PERIOD: Duration = Duration::from_secs(24 * 60 * 60);
+
+    /// Creates a schedule that fires `offset` after midnight, every day.
+    ///
+    /// Returns an error if `offset` is not a whole number of seconds or not
+    /// shorter than a day.
+    pub fn at(offset: Duration) -> Result<Daily, OffsetError> {
+        Daily::at_each([offset])
+    }
+
+    /// Creates a schedule that fires at each of the `offsets` after midnight,
+    /// every day.
+    ///
+    /// Returns an error if there are no `offsets` or if one of them is not a
+    /// whole number of seconds or not shorter than a day.
+    pub fn at_each<I>(offsets: I) -> Result<Daily, OffsetError>
+    where
+        I: IntoIterator<Item = Duration>,
+    {
+        let offsets = Offsets::new(offsets, Daily::PERIOD)?;
+        Ok(Daily { offsets })
+    }
+}
+
+#[cfg(feature = "day")]
+impl Schedule for Daily {
+    fn next_after<Tz: TimeZone>(&self, time: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        calendar::next_after(time, Fold::Skip, |from| {
+            let day = from.date().and_time(NaiveTime::MIN);
+            self.offsets.next_local(from, day, |day| {
+                day.checked_add_signed(chrono::Duration::days(1))
+            })
+        })
+    }
+
+    fn describe(&self) -> String {
+        format!("every day {}", describe::times(&self.offsets.0))
+    }
+}
+
+#[cfg(feature = "day")]
+impl fmt::Display for Daily {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.describe())
+    }
+}
+
+/// Sorted offsets into a period, in seconds.
+#[cfg(any(feature = "day", feature = "hour"))]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct Offsets(Vec<u32>);
+
+#[cfg(any(feature = "day", feature = "hour"))]
+impl Offsets {
+    fn new<I>(offsets: I, period: Duration) -> Result<Offsets, OffsetError>
+    where
+        I: IntoIterator<Item = Duration>,
+    {
+        let mut seconds = offsets
+            .into_iter()
+            .map(|offset| {
+                if offset >= period || offset.subsec_nanos() != 0 {
+                    return Err(OffsetError {
+                        offset: Some(offset),
+                        period,
+                    });
+                }
+                Ok(offset.as_secs() as u32)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if seconds.is_empty() {
+            return Err(OffsetError {
+                offset: None,
+                period,
+            });
+        }
+        seconds.sort_unstable();
+        seconds.dedup();
+        Ok(Offsets(seconds))
+    }
+
+    /// Returns the first local time equal to or after `from` at one of the
+    /// offsets, `start` is the start of the period of `from` and `next`
+    /// returns the start of the period after a given one.
+    fn next_local<F>(
+        &self,
+        from: NaiveDateTime,
+        start: NaiveDateTime,
+        next: F,
+    ) -> Option<NaiveDateTime>
+    where
+        F: Fn(NaiveDateTime) -> Option<NaiveDateTime>,
+    {
+        // the offsets are shorter than the period, so they fall into this
+        // period or the next one
+        let at = |start: NaiveDateTime, offset: u32| {
+            start.checked_add_signed(chrono::Duration::seconds(offset.into()))
+        };
+        let later = self
+            .0
+            .iter()
+            .find_map(|&offset| at(start, offset).filter(|time| *time >= from));
+        later.or_else(|| at(next(start)?, *self.0.first()?))
+    }
+}
+
+/// An error returned when an offset doesn't fit into the period of a
+/// schedule, like [`Hourly::at`] with 90 minutes, or when a schedule is
+/// created without any offsets.
+///
+/// *This type requires at least one of the following crate features to be activated: `day`,
+/// `hour`*
+#[cfg(any(feature = "day", feature = "hour"))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OffsetError {
+    offset: Option<Duration>,
+    period: Duration,
+}
+
+#[cfg(any(feature = "day", feature = "hour"))]
+impl OffsetError {
+    /// Returns the offset that was rejected, `None` if there were no offsets
+    /// at all.
+    pub fn offset(&self) -> Option<Duration> {
+        self.offset
+    }
+
+    /// Returns the period of the schedule.
+    pub fn period(&self) -> Duration {
+        self.period
+    }
+}
+
+#[cfg(any(feature = "day", feature = "hour"))]
+impl fmt::Display for OffsetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.offset {
+            Some(offset) => write!(
+                f,
+                "invalid offset {:?}, offsets must be whole seconds shorter than {:?}",
+                offset, self.period
+            ),
+            None => f.write_str("no offsets, a schedule needs at least one"),
+        }
+    }
+}
+
+#[cfg(any(feature = "day", feature = "hour"))]
+impl std::error::Error for OffsetError {}
+
+/// Fires `period` after the instant it is given, the first time `period` after
+/// it starts.
+///
+/// Unlike [`Timer::interval`](crate::Timer::interval), the period is measured
+/// on the wall clock.
+///
+/// *This type requires the following crate features to be activated: `interval`*
+#[cfg(feature = "interval")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Interval {
+    period: chrono::Duration,
+}
+
+#[cfg(feature = "interval")]
+impl Interval {
+    /// Creates a schedule that fires every `period`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is zero or too long to be added to a date.
+    pub fn new(period: Duration) -> Interval {
+        assert!(!period.is_zero(), "`period` must be non-zero");
+        let period = chrono::Duration::from_std(period).expect("`period` is too long");
+        Interval { period }
+    }
+
+    /// Returns the period of the schedule.
+    pub fn period(&self) -> Duration {
+        self.period.to_std().expect("the period is positive")
+    }
+}
+
+#[cfg(feature = "interval")]
+impl Schedule for Interval {
+    fn next_after<Tz: TimeZone>(&self, time: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        time.clone().checked_add_signed(self.period)
+    }
+
+    fn describe(&self) -> String {
+        format!("every {}", describe::duration(self.period(), 7))
+    }
+}
+
+#[cfg(feature = "interval")]
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.describe())
+    }
+}
+
+/// Fires at the multiples of a period, like every 5 minutes on the `:00`,
+/// `:05` and `:10` marks.
+///
+/// The multiples are measured from the Unix epoch, so timers on different
+/// hosts fire at the same instants, or from the start of every local day with
+/// [`from_midnight`](Aligned::from_midnight). Periods that don't divide a day
+/// restart at midnight then.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use std::time::Duration;
+/// use zila::{Aligned, Schedule};
+///
+/// let every_5_minutes = Aligned::new(Duration::from_secs(5 * 60));
+/// let time = Utc.with_ymd_and_hms(2023, 5, 9, 13, 42, 10).unwrap();
+/// let next = Utc.with_ymd_and_hms(2023, 5, 9, 13, 45, 0).unwrap();
+/// assert_eq!(every_5_minutes.next_after(&time), Some(next));
+/// ```
+///
+/// *This type requires the following crate features to be activated: `interval`*
+#[cfg(feature = "interval")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Aligned {
+    period: Duration,
+    from_midnight: bool,
+}
+
+#[cfg(feature = "interval")]
+impl Aligned {
+    /// Creates a schedule that fires at the multiples of `period` since the
+    /// Unix epoch.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is zero.
+    pub fn new(period: Duration) -> Aligned {
+        assert!(!period.is_zero(), "`period` must be non-zero");
+        Aligned {
+            period,
+            from_midnight: false,
+        }
+    }
+
+    /// Creates a schedule that fires at the multiples of `period` since the
+    /// start of every local day.
+    ///
+    /// The multiples are measured in elapsed time, on the days the clocks
+    /// change the marks after the change are an hour off the wall clock
+    /// unless `period` divides an hour.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is zero.
+    pub fn from_midnight(period: Duration) -> Aligned {
+        Aligned {
+            from_midnight: true,
+            ..Aligned::new(period)
+        }
+    }
+
+    /// Returns the period of the schedule.
+    pub fn period(&self) -> Duration {
+        self.period
+    }
+}
+
+#[cfg(feature = "interval")]
+impl Schedule for Aligned {
+    fn next_after<Tz: TimeZone>(&self, time: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        if !self.from_midnight {
+            let epoch = DateTime::UNIX_EPOCH.with_timezone(&time.timezone());
+            return next_multiple(&epoch, time, self.period);
+        }
+        let next_day = next_midnight(time)?;
+        // days last between 23 and 25 hours, 30 hours before the next day is
+        // the day before
+        let day = next_midnight(&(next_day.clone() - chrono::Duration::hours(30)))?;
+        let next = next_multiple(&day, time, self.period)?;
+        Some(next.min(next_day))
+    }
+
+    fn describe(&self) -> String {
+        let start = if self.from_midnight {
+            "midnight"
+        } else {
+            "the Unix epoch"
+        };
+        format!(
+            "every {} counted from {}",
+            describe::duration(self.period, 7),
+            start
+        )
+    }
+}
+
+#[cfg(feature = "interval")]
+impl fmt::Display for Aligned {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.describe())
+    }
+}
+
+/// Returns the first instant strictly after `time` that is a multiple of
+/// `period` after `start`.
+#[cfg(feature = "interval")]
+fn next_multiple<Tz: TimeZone>(
+    start: &DateTime<Tz>,
+    time: &DateTime<Tz>,
+    period: Duration,
+) -> Option<DateTime<Tz>> {
+    let nanos = |time: &DateTime<Tz>| {
+        i128::from(time.timestamp()) * 1_000_000_000 + i128::from(time.timestamp_subsec_nanos())
+    };
+    let start = nanos(start);
+    let period = i128::try_from(period.as_nanos()).ok()?;
+    let count = (nanos(time) - start).div_euclid(period) + 1;
+    let next = start.checked_add(count.checked_mul(period)?)?;
+    let seconds = i64::try_from(next.div_euclid(1_000_000_000)).ok()?;
+    let next = DateTime::from_timestamp(seconds, next.rem_euclid(1_000_000_000) as u32)?;
+    Some(next.with_timezone(&time.timezone()))
+}
+
+/// Fires once, at a given instant.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use zila::{Once, Schedule};
+///
+/// let at = Utc.with_ymd_and_hms(2023, 5, 9, 14, 0, 0).unwrap();
+/// let time = Utc.with_ymd_and_hms(2023, 5, 9, 13, 45, 0).unwrap();
+/// assert_eq!(Once::at(at).iter_after(time).collect::<Vec<_>>(), [at]);
+/// ```
+///
+/// *This type requires at least one of the following crate features to be activated: `interval`,
+/// `day`, `hour`, `minute`, `second`, `week`, `month`, `quarter`, `year`, `cron`, `rrule`, `systemd`, `human`*
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Once {
+    at: DateTime<Utc>,
+}
+
+impl Once {
+    /// Creates a schedule that fires at `time`, in any time zone.
+    pub fn at<Tz: TimeZone>(time: DateTime<Tz>) -> Once {
+        Once {
+            at: time.with_timezone(&Utc),
+        }
+    }
+}
+
+impl Schedule for Once {
+    fn next_after<Tz: TimeZone>(&self, time: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        (self.at > *time).then(|| self.at.with_timezone(&time.timezone()))
+    }
+
+    fn describe(&self) -> String {
+        format!("once at {}", self.at.format("%Y-%m-%d %H:%M:%S UTC"))
+    }
+}
+
+impl fmt::Display for Once {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.describe())
+    }
+}
+
+/// Returns the duration to the next time the schedule fires, `None` if it
+/// never fires again
+///
+/// The schedule is evaluated in the local time zone, see [`Schedule`].
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use zila::{duration_to_next, Daily};
+///
+/// let daily = Daily::at(Duration::from_secs(2 * 60 * 60 + 30 * 60)).unwrap();
+/// let duration = duration_to_next(&daily).unwrap();
+/// assert!(duration.as_secs() <= 25 * 60 * 60);
+/// ```
+///
+/// *This function requires at least one of the following crate features to be activated:
+/// `interval`, `day`, `hour`, `minute`, `second`, `week`, `month`, `quarter`, `year`, `cron`, `rrule`, `systemd`, `human`*
+pub fn duration_to_next<S: Schedule>(schedule: &S) -> Option<Duration> {
+    duration_to_next_in(schedule, &Local)
+}
+
+/// Returns the duration to the next time the schedule fires in the given time
+/// zone, `None` if it never fires again
+///
+/// The duration is zero if the schedule fires right now.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::{duration_to_next_in, Hourly};
+///
+/// let hourly = Hourly::at(Duration::from_secs(15 * 60)).unwrap();
+/// let duration = duration_to_next_in(&hourly, &Berlin).unwrap();
+/// assert!(duration.as_secs() <= 60 * 60);
+/// ```
+///
+/// *This function requires at least one of the following crate features to be activated:
+/// `interval`, `day`, `hour`, `minute`, `second`, `week`, `month`, `quarter`, `year`, `cron`, `rrule`, `systemd`, `human`*
+pub fn duration_to_next_in<S, Tz>(schedule: &S, tz: &Tz) -> Option<Duration>
+where
+    S: Schedule,
+    Tz: TimeZone,
+{
+    let now = Utc::now().with_timezone(tz);
+    // returns zero if `now` is exactly on a boundary
+    let next = schedule.next_after(&(now.clone() - chrono::Duration::nanoseconds(1)))?;
+    Some((next - now).to_std().unwrap_or(Duration::ZERO))
+}
+
+/// Formats a duration for people, with its two most significant units, like
+/// `3h 12m`, `45s` or `250ms`.
+///
+/// The less significant units are cut off, so the result is never longer than
+/// the duration.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use zila::format_duration;
+///
+/// assert_eq!(format_duration(Duration::from_secs(3 * 60 * 60 + 12 * 60 + 5)), "3h 12m");
+/// assert_eq!(format_duration(Duration::from_millis(250)), "250ms");
+/// ```
+///
+/// *This function requires at least one of the following crate features to be activated:
+/// `interval`, `day`, `hour`, `minute`, `second`, `week`, `month`, `quarter`, `year`, `cron`, `rrule`, `systemd`, `human`*
+pub fn format_duration(duration: Duration) -> String {
+    describe::duration(duration, 2)
+}
+
+/// Describes when the schedule fires next, like `next run in 3h 12m`
+///
+/// The schedule is evaluated in the local time zone, see
+/// [`duration_to_next`]. Returns `next run now` if the schedule fires right
+/// now and `no next run` if it never fires again.
+///
+/// # Example
+///
+/// ```rust
+/// use zila::{describe_next_run, EveryHour};
+///
+/// assert!(describe_next_run(&EveryHour).starts_with("next run "));
+/// ```
+///
+/// *This function requires at least one of the following crate features to be activated:
+/// `interval`, `day`, `hour`, `minute`, `second`, `week`, `month`, `quarter`, `year`, `cron`, `rrule`, `systemd`, `human`*
+pub fn describe_next_run<S: Schedule>(schedule: &S) -> String {
+    describe_next_run_in(schedule, &Local)
+}
+
+/// Describes when the schedule fires next in the given time zone, like
+/// `next run in 3h 12m`
+///
+/// See [`describe_next_run`].
+///
+/// *This function requires at least one of the following crate features to be activated:
+/// `interval`, `day`, `hour`, `minute`, `second`, `week`, `month`, `quarter`, `year`, `cron`, `rrule`, `systemd`, `human`*
+pub fn describe_next_run_in<S, Tz>(schedule: &S, tz: &Tz) -> String
+where
+    S: Schedule,
+    Tz: TimeZone,
+{
+    match duration_to_next_in(schedule, tz) {
+        None => "no next run".to_owned(),
+        Some(Duration::ZERO) => "next run now".to_owned(),
+        Some(duration) => format!("next run in {}", format_duration(duration)),
+    }
+}
+
+/// calls the given function every time the schedule fires
+///
+/// The timer finishes once the schedule never fires again.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::{run, EveryHour};
+///
+/// run(EveryHour, || {
+///     println!("Hi");
+/// });
+/// ```
+///
+/// *This function requires at least one of the following crate features to be activated:
+/// `interval`, `day`, `hour`, `minute`, `second`, `week`, `month`, `quarter`, `year`, `cron`, `rrule`, `systemd`, `human`*
+pub fn run<S, F, R>(schedule: S, callback: F) -> R::Output
+where
+    S: Schedule + Send + 'static,
+    F: Fn() -> R,
+    R: Flow,
+{
+    run_mut(schedule, callback)
+}
+
+/// calls the given function every time the schedule fires, takes a `FnMut` as
+/// the argument
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use zila::{run_mut, EveryHour};
+///
+/// let num = Rc::new(Cell::new(0));
+/// run_mut(EveryHour, {
+///     let num = num.clone();
+///     move || {
+///         num.set(num.get() + 1);
+///     }
+/// });
+/// ```
+///
+/// *This function requires at least one of the following crate features to be activated:
+/// `interval`, `day`, `hour`, `minute`, `second`, `week`, `month`, `quarter`, `year`, `cron`, `rrule`, `systemd`, `human`*
+pub fn run_mut<S, F, R>(schedule: S, callback: F) -> R::Output
+where
+    S: Schedule + Send + 'static,
+    F: FnMut() -> R,
+    R: Flow,
+{
+    run_in_mut(schedule, Local, callback)
+}
+
+/// calls the given async function every time the schedule fires
+///
+/// The timer finishes once the schedule never fires again.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::{run_async, EveryHour};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// run_async(EveryHour, || async {
+///     println!("Hi");
+/// })
+/// .await;
+/// # }
+/// ```
+///
+/// *This function requires at least one of the following crate features to be activated:
+/// `interval`, `day`, `hour`, `minute`, `second`, `week`, `month`, `quarter`, `year`, `cron`, `rrule`, `systemd`, `human`*
+pub async fn run_async<S, F, Fut, R>(schedule: S, callback: F) -> R::Output
+where
+    S: Schedule + Send + 'static,
+    F: Fn() -> Fut,
+    Fut: Future<Output = R>,
+    R: Flow,
+{
+    run_async_mut(schedule, callback).await
+}
+
+/// calls the given async function every time the schedule fires, takes a
+/// `FnMut` as the argument
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use zila::{run_async_mut, EveryHour};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let num = Rc::new(Cell::new(0));
+/// run_async_mut(EveryHour, move || {
+///     let num = num.clone();
+///     async move {
+///         num.set(num.get() + 1);
+///         println!("This is printed {} times.", num.get());
+///     }
+/// })
+/// .await;
+/// # }
+/// ```
+///
+/// *This function requires at least one of the following crate features to be activated:
+/// `interval`, `day`, `hour`, `minute`, `second`, `week`, `month`, `quarter`, `year`, `cron`, `rrule`, `systemd`, `human`*
+pub async fn run_async_mut<S, F, Fut, R>(schedule: S, callback: F) -> R::Output
+where
+    S: Schedule + Send + 'static,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = R>,
+    R: Flow,
+{
+    run_in_async_mut(schedule, Local, callback).await
+}
+
+/// calls the given function every time the schedule fires in the given time
+/// zone
+///
+/// Like [`run`], but the schedule is evaluated in `tz` instead of the local
+/// time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::{run_in, EveryDay};
+///
+/// run_in(EveryDay, Berlin, || {
+///     println!("Midnight in Berlin");
+/// });
+/// ```
+///
+/// *This function requires at least one of the following crate features to be activated:
+/// `interval`, `day`, `hour`, `minute`, `second`, `week`, `month`, `quarter`, `year`, `cron`, `rrule`, `systemd`, `human`*
+pub fn run_in<S, Tz, F, R>(schedule: S, tz: Tz, callback: F) -> R::Output
+where
+    S: Schedule + Send + 'static,
+    Tz: TimeZone + Send + 'static,
+    F: Fn() -> R,
+    R: Flow,
+{
+    run_in_mut(schedule, tz, callback)
+}
+
+/// calls the given function every time the schedule fires in the given time
+/// zone, takes a `FnMut` as the argument
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::{run_in_mut, EveryDay};
+///
+/// let num = Rc::new(Cell::new(0));
+/// run_in_mut(EveryDay, Berlin, {
+///     let num = num.clone();
+///     move || {
+///         num.set(num.get() + 1);
+///     }
+/// });
+/// ```
+///
+/// *This function requires at least one of the following crate features to be activated:
+/// `interval`, `day`, `hour`, `minute`, `second`, `week`, `month`, `quarter`, `year`, `cron`, `rrule`, `systemd`, `human`*
+pub fn run_in_mut<S, Tz, F, R>(schedule: S, tz: Tz, mut callback: F) -> R::Output
+where
+    S: Schedule + Send + 'static,
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> R,
+    R: Flow,
+{
+    Timer::schedule_in(schedule, tz).run(move |_| callback())
+}
+
+/// calls the given async function every time the schedule fires in the given
+/// time zone
+///
+/// Like [`run_async`], but the schedule is evaluated in `tz` instead of the
+/// local time zone of the machine.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::{run_in_async, EveryDay};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// run_in_async(EveryDay, Berlin, || async {
+///     println!("Midnight in Berlin");
+/// })
+/// .await;
+/// # }
+/// ```
+///
+/// *This function requires at least one of the following crate features to be activated:
+/// `interval`, `day`, `hour`, `minute`, `second`, `week`, `month`, `quarter`, `year`, `cron`, `rrule`, `systemd`, `human`*
+pub async fn run_in_async<S, Tz, F, Fut, R>(schedule: S, tz: Tz, callback: F) -> R::Output
+where
+    S: Schedule + Send + 'static,
+    Tz: TimeZone + Send + 'static,
+    F: Fn() -> Fut,
+    Fut: Future<Output = R>,
+    R: Flow,
+{
+    run_in_async_mut(schedule, tz, callback).await
+}
+
+/// calls the given async function every time the schedule fires in the given
+/// time zone, takes a `FnMut` as the argument
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::{cell::Cell, rc::Rc};
+/// use zila::chrono_tz::Europe::Berlin;
+/// use zila::{run_in_async_mut, EveryDay};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let num = Rc::new(Cell::new(0));
+/// run_in_async_mut(EveryDay, Berlin, move || {
+///     let num = num.clone();
+///     async move {
+///         num.set(num.get() + 1);
+///         println!("This is printed {} times.", num.get());
+///     }
+/// })
+/// .await;
+/// # }
+/// ```
+///
+/// *This function requires at least one of the following crate features to be activated:
+/// `interval`, `day`, `hour`, `minute`, `second`, `week`, `month`, `quarter`, `year`, `cron`, `rrule`, `systemd`, `human`*
+pub async fn run_in_async_mut<S, Tz, F, Fut, R>(schedule: S, tz: Tz, mut callback: F) -> R::Output
+where
+    S: Schedule + Send + 'static,
+    Tz: TimeZone + Send + 'static,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = R>,
+    R: Flow,
+{
+    Timer::schedule_in(schedule, tz)
+        .run_async(move |_| callback())
+        .await
+}
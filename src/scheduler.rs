@@ -0,0 +1,753 @@
+//! A scheduler that runs many jobs on a single timer thread.
+
+use crate::clock::{Clock, SystemClock};
+use crate::jump::{ClockJump, Detector, CHECK_INTERVAL};
+use crate::policy::{CaughtPanic, PanicPolicy};
+#[cfg(any(
+    feature = "day",
+    feature = "hour",
+    feature = "minute",
+    feature = "second",
+    feature = "week",
+    feature = "month",
+    feature = "quarter",
+    feature = "year"
+))]
+use crate::Schedule;
+#[cfg(feature = "week")]
+use chrono::Weekday;
+use chrono::{DateTime, Utc};
+#[cfg(any(
+    feature = "day",
+    feature = "hour",
+    feature = "minute",
+    feature = "second",
+    feature = "week",
+    feature = "month",
+    feature = "quarter",
+    feature = "year"
+))]
+use chrono::{Local, TimeZone};
+use std::any::Any;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+type Callback = Box<dyn FnMut() + Send + 'static>;
+type NextAfter = Box<dyn Fn(&DateTime<Utc>) -> Option<DateTime<Utc>> + Send + 'static>;
+type JumpHook = Box<dyn FnMut(ClockJump) + Send + 'static>;
+type PanicHook = Box<dyn FnMut(JobId, &CaughtPanic) + Send + 'static>;
+
+/// Identifies a job added to a [`Scheduler`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JobId(u64);
+
+/// Runs any number of jobs on a single background thread.
+///
+/// The `spawn_` functions of this crate, like
+/// [`spawn_set_interval`](crate::spawn_set_interval), start a thread per timer. A `Scheduler` keeps all of its jobs in a priority
+/// queue ordered by their next deadline instead, and a single thread named
+/// `zila-scheduler` waits for the earliest one and calls its callback. Jobs can
+/// be added and removed at any time, even from within a callback if the
+/// scheduler is shared through an [`Arc`].
+///
+/// Callbacks run one after another, a slow callback delays every job that is
+/// due while it runs. By default a panicking callback takes the thread and
+/// every other job down with it, see [`Scheduler::panic_policy`].
+///
+/// The thread checks at least once a second whether the wall clock jumped,
+/// because it was stepped or the machine was suspended, and re-arms the jobs
+/// that follow calendar boundaries against the new wall time, see
+/// [`Scheduler::on_clock_jump`].
+///
+/// Dropping the scheduler removes every job and stops its thread.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use zila::Scheduler;
+///
+/// let scheduler = Scheduler::new();
+///
+/// let interval = scheduler.set_interval(|| {
+///     println!("every 10ms");
+/// }, Duration::from_millis(10));
+/// scheduler.set_timeout(|| {
+///     println!("once, after 25ms");
+/// }, Duration::from_millis(25));
+///
+/// std::thread::sleep(Duration::from_millis(50));
+/// assert!(scheduler.remove(interval));
+/// ```
+///
+/// *This type requires the following crate features to be activated: `scheduler`*
+pub struct Scheduler {
+    shared: Arc<Shared>,
+    thread: Option<JoinHandle<()>>,
+}
+
+struct Shared {
+    state: Mutex<State>,
+    clock: Arc<dyn Clock>,
+}
+
+#[derive(Default)]
+struct State {
+    queue: BinaryHeap<Reverse<(Instant, JobId)>>,
+    jobs: HashMap<JobId, Job>,
+    next_id: u64,
+    shutdown: bool,
+    /// `None` while the hook runs on the scheduler thread.
+    on_clock_jump: Option<JumpHook>,
+    panic_policy: PanicPolicy,
+    /// `None` while the hook runs on the scheduler thread.
+    on_panic: Option<PanicHook>,
+}
+
+struct Job {
+    kind: Kind,
+    /// Queue entries with another deadline are stale.
+    deadline: Instant,
+    /// `None` while the callback runs on the scheduler thread.
+    callback: Option<Callback>,
+}
+
+#[allow(dead_code)] // every variant is gated behind its own feature
+enum Kind {
+    Timeout(Duration),
+    Interval(Duration),
+    /// Follows the boundaries of a calendar schedule, `boundary` is the wall
+    /// time of the next one.
+    Boundary {
+        next_after: NextAfter,
+        boundary: DateTime<Utc>,
+    },
+}
+
+impl Kind {
+    /// Returns the deadline of a job that is armed now.
+    fn deadline(&self, clock: &dyn Clock) -> Instant {
+        match self {
+            Kind::Timeout(delay) | Kind::Interval(delay) => clock.instant() + *delay,
+            Kind::Boundary { boundary, .. } => {
+                let remaining = (*boundary - clock.now()).to_std().unwrap_or(Duration::ZERO);
+                clock.instant() + remaining
+            }
+        }
+    }
+
+    /// Returns `true` if a job whose deadline passed can run, the monotonic
+    /// clock that deadlines are measured on may run ahead of the wall clock.
+    fn is_due(&self, clock: &dyn Clock) -> bool {
+        match self {
+            Kind::Timeout(_) | Kind::Interval(_) => true,
+            Kind::Boundary { boundary, .. } => clock.now() >= *boundary,
+        }
+    }
+
+    /// Moves a job that just ran to its next boundary, `started` is the wall
+    /// time its callback started at. Returns `false` if the job is done.
+    fn advance(&mut self, started: DateTime<Utc>) -> bool {
+        match self {
+            Kind::Timeout(_) => false,
+            Kind::Interval(_) => true,
+            Kind::Boundary {
+                next_after,
+                boundary,
+            } => {
+                // `started` is at or after the boundary that fired, so it never
+                // fires twice, the boundaries that passed while the callback
+                // ran are called once right away
+                match next_after(&started) {
+                    Some(next) => {
+                        *boundary = next;
+                        true
+                    }
+                    None => false,
+                }
+            }
+        }
+    }
+}
+
+impl Scheduler {
+    /// Creates a scheduler without any jobs and starts its thread.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the OS fails to create a thread.
+    pub fn new() -> Scheduler {
+        Scheduler::with_clock(SystemClock)
+    }
+
+    /// Creates a scheduler that reads the time from `clock` and sleeps on it,
+    /// instead of the [`SystemClock`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the OS fails to create a thread.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use std::sync::mpsc;
+    /// use std::time::Duration;
+    /// use zila::{ManualClock, Scheduler};
+    ///
+    /// let clock = ManualClock::new(Utc.with_ymd_and_hms(2023, 5, 8, 12, 0, 0).unwrap());
+    /// let scheduler = Scheduler::with_clock(clock.clone());
+    /// let (sender, receiver) = mpsc::channel();
+    /// scheduler.call_every_day_in(Utc, move || sender.send(()).unwrap());
+    ///
+    /// clock.advance(Duration::from_secs(12 * 60 * 60));
+    /// receiver.recv().unwrap();
+    /// ```
+    pub fn with_clock<C: Clock>(clock: C) -> Scheduler {
+        let shared = Arc::new(Shared {
+            state: Mutex::new(State::default()),
+            clock: Arc::new(clock),
+        });
+        let thread = thread::Builder::new()
+            .name("zila-scheduler".to_owned())
+            .spawn({
+                let shared = shared.clone();
+                move || shared.run()
+            })
+            .expect("failed to spawn thread");
+        Scheduler {
+            shared,
+            thread: Some(thread),
+        }
+    }
+
+    /// calls the function once after the specified duration
+    ///
+    /// *This method requires the following crate features to be activated: `timeout`*
+    #[cfg(feature = "timeout")]
+    pub fn set_timeout<F>(&self, callback: F, duration: Duration) -> JobId
+    where
+        F: FnMut() + Send + 'static,
+    {
+        self.add(Kind::Timeout(duration), Box::new(callback))
+    }
+
+    /// calls the function in the specified intervals
+    ///
+    /// *This method requires the following crate features to be activated: `interval`*
+    #[cfg(feature = "interval")]
+    pub fn set_interval<F>(&self, callback: F, duration: Duration) -> JobId
+    where
+        F: FnMut() + Send + 'static,
+    {
+        self.add(Kind::Interval(duration), Box::new(callback))
+    }
+
+    /// calls the function every day
+    ///
+    /// *This method requires the following crate features to be activated: `day`*
+    #[cfg(feature = "day")]
+    pub fn call_every_day<F>(&self, callback: F) -> JobId
+    where
+        F: FnMut() + Send + 'static,
+    {
+        self.call_every_day_in(Local, callback)
+    }
+
+    /// calls the function every day at midnight in the given time zone
+    ///
+    /// *This method requires the following crate features to be activated: `day`*
+    #[cfg(feature = "day")]
+    pub fn call_every_day_in<Tz, F>(&self, tz: Tz, callback: F) -> JobId
+    where
+        Tz: TimeZone + Send + 'static,
+        F: FnMut() + Send + 'static,
+    {
+        self.add_boundary(tz, crate::EveryDay, Box::new(callback))
+    }
+
+    /// calls the function every hour
+    ///
+    /// *This method requires the following crate features to be activated: `hour`*
+    #[cfg(feature = "hour")]
+    pub fn call_every_hour<F>(&self, callback: F) -> JobId
+    where
+        F: FnMut() + Send + 'static,
+    {
+        self.call_every_hour_in(Local, callback)
+    }
+
+    /// calls the function every hour in the given time zone
+    ///
+    /// *This method requires the following crate features to be activated: `hour`*
+    #[cfg(feature = "hour")]
+    pub fn call_every_hour_in<Tz, F>(&self, tz: Tz, callback: F) -> JobId
+    where
+        Tz: TimeZone + Send + 'static,
+        F: FnMut() + Send + 'static,
+    {
+        self.add_boundary(tz, crate::EveryHour, Box::new(callback))
+    }
+
+    /// calls the function every minute
+    ///
+    /// *This method requires the following crate features to be activated: `minute`*
+    #[cfg(feature = "minute")]
+    pub fn call_every_minute<F>(&self, callback: F) -> JobId
+    where
+        F: FnMut() + Send + 'static,
+    {
+        self.call_every_minute_in(Local, callback)
+    }
+
+    /// calls the function every minute in the given time zone
+    ///
+    /// *This method requires the following crate features to be activated: `minute`*
+    #[cfg(feature = "minute")]
+    pub fn call_every_minute_in<Tz, F>(&self, tz: Tz, callback: F) -> JobId
+    where
+        Tz: TimeZone + Send + 'static,
+        F: FnMut() + Send + 'static,
+    {
+        self.add_boundary(tz, crate::EveryMinute, Box::new(callback))
+    }
+
+    /// calls the function every second
+    ///
+    /// *This method requires the following crate features to be activated: `second`*
+    #[cfg(feature = "second")]
+    pub fn call_every_second<F>(&self, callback: F) -> JobId
+    where
+        F: FnMut() + Send + 'static,
+    {
+        self.call_every_second_in(Local, callback)
+    }
+
+    /// calls the function every second in the given time zone
+    ///
+    /// *This method requires the following crate features to be activated: `second`*
+    #[cfg(feature = "second")]
+    pub fn call_every_second_in<Tz, F>(&self, tz: Tz, callback: F) -> JobId
+    where
+        Tz: TimeZone + Send + 'static,
+        F: FnMut() + Send + 'static,
+    {
+        self.add_boundary(tz, crate::EverySecond, Box::new(callback))
+    }
+
+    /// calls the function at the start of every week
+    ///
+    /// *This method requires the following crate features to be activated: `week`*
+    #[cfg(feature = "week")]
+    pub fn call_every_week<F>(&self, start: Weekday, callback: F) -> JobId
+    where
+        F: FnMut() + Send + 'static,
+    {
+        self.call_every_week_in(start, Local, callback)
+    }
+
+    /// calls the function at the start of every week in the given time zone
+    ///
+    /// *This method requires the following crate features to be activated: `week`*
+    #[cfg(feature = "week")]
+    pub fn call_every_week_in<Tz, F>(&self, start: Weekday, tz: Tz, callback: F) -> JobId
+    where
+        Tz: TimeZone + Send + 'static,
+        F: FnMut() + Send + 'static,
+    {
+        let schedule = crate::EveryWeek::starting(start);
+        self.add_boundary(tz, schedule, Box::new(callback))
+    }
+
+    /// calls the function at the start of every month
+    ///
+    /// *This method requires the following crate features to be activated: `month`*
+    #[cfg(feature = "month")]
+    pub fn call_every_month<F>(&self, callback: F) -> JobId
+    where
+        F: FnMut() + Send + 'static,
+    {
+        self.call_every_month_in(Local, callback)
+    }
+
+    /// calls the function at the start of every month in the given time zone
+    ///
+    /// *This method requires the following crate features to be activated: `month`*
+    #[cfg(feature = "month")]
+    pub fn call_every_month_in<Tz, F>(&self, tz: Tz, callback: F) -> JobId
+    where
+        Tz: TimeZone + Send + 'static,
+        F: FnMut() + Send + 'static,
+    {
+        self.add_boundary(tz, crate::EveryMonth, Box::new(callback))
+    }
+
+    /// calls the function at the start of every quarter
+    ///
+    /// *This method requires the following crate features to be activated: `quarter`*
+    #[cfg(feature = "quarter")]
+    pub fn call_every_quarter<F>(&self, callback: F) -> JobId
+    where
+        F: FnMut() + Send + 'static,
+    {
+        self.call_every_quarter_in(Local, callback)
+    }
+
+    /// calls the function at the start of every quarter in the given time zone
+    ///
+    /// *This method requires the following crate features to be activated: `quarter`*
+    #[cfg(feature = "quarter")]
+    pub fn call_every_quarter_in<Tz, F>(&self, tz: Tz, callback: F) -> JobId
+    where
+        Tz: TimeZone + Send + 'static,
+        F: FnMut() + Send + 'static,
+    {
+        self.add_boundary(tz, crate::EveryQuarter, Box::new(callback))
+    }
+
+    /// calls the function at the start of every year
+    ///
+    /// *This method requires the following crate features to be activated: `year`*
+    #[cfg(feature = "year")]
+    pub fn call_every_year<F>(&self, callback: F) -> JobId
+    where
+        F: FnMut() + Send + 'static,
+    {
+        self.call_every_year_in(Local, callback)
+    }
+
+    /// calls the function at the start of every year in the given time zone
+    ///
+    /// *This method requires the following crate features to be activated: `year`*
+    #[cfg(feature = "year")]
+    pub fn call_every_year_in<Tz, F>(&self, tz: Tz, callback: F) -> JobId
+    where
+        Tz: TimeZone + Send + 'static,
+        F: FnMut() + Send + 'static,
+    {
+        self.add_boundary(tz, crate::EveryYear, Box::new(callback))
+    }
+
+    /// Calls `hook` every time the scheduler notices that the wall clock
+    /// jumped, replacing the previous hook.
+    ///
+    /// The hook runs on the scheduler thread, after the jobs that follow
+    /// calendar boundaries were re-armed.
+    pub fn on_clock_jump<F>(&self, hook: F)
+    where
+        F: FnMut(ClockJump) + Send + 'static,
+    {
+        self.shared.lock().on_clock_jump = Some(Box::new(hook));
+    }
+
+    /// Sets what the scheduler does when the callback of a job panics, the
+    /// default is [`PanicPolicy::Propagate`].
+    ///
+    /// [`PanicPolicy::Restart`] keeps the job scheduled and
+    /// [`PanicPolicy::Stop`] removes it, the other jobs keep running either
+    /// way. [`PanicPolicy::Propagate`] unwinds the scheduler thread, which
+    /// stops every job.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use zila::{PanicPolicy, Scheduler};
+    ///
+    /// let scheduler = Scheduler::new();
+    /// scheduler.panic_policy(PanicPolicy::Stop);
+    /// scheduler.on_panic(|job, panic| {
+    ///     eprintln!("{:?} panicked: {:?}", job, panic.message());
+    /// });
+    /// let job = scheduler.set_interval(|| panic!("oops"), Duration::from_millis(1));
+    ///
+    /// std::thread::sleep(Duration::from_millis(50));
+    /// assert!(!scheduler.contains(job));
+    /// ```
+    pub fn panic_policy(&self, policy: PanicPolicy) {
+        self.shared.lock().panic_policy = policy;
+    }
+
+    /// Calls `hook` with the job and the panic every time the callback of a
+    /// job panics, replacing the previous hook.
+    ///
+    /// The hook runs on the scheduler thread, before the
+    /// [`PanicPolicy`] applies.
+    pub fn on_panic<F>(&self, hook: F)
+    where
+        F: FnMut(JobId, &CaughtPanic) + Send + 'static,
+    {
+        self.shared.lock().on_panic = Some(Box::new(hook));
+    }
+
+    /// Removes a job, returns `false` if the job had already finished or was
+    /// removed before.
+    ///
+    /// A callback that is already running is not interrupted, the job is not
+    /// called again once it returns.
+    pub fn remove(&self, id: JobId) -> bool {
+        // stale queue entries are skipped by the scheduler thread
+        self.shared.lock().jobs.remove(&id).is_some()
+    }
+
+    /// Returns `true` if the job is still scheduled.
+    pub fn contains(&self, id: JobId) -> bool {
+        self.shared.lock().jobs.contains_key(&id)
+    }
+
+    /// Returns the number of scheduled jobs.
+    pub fn len(&self) -> usize {
+        self.shared.lock().jobs.len()
+    }
+
+    /// Returns `true` if there are no scheduled jobs.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds a job that is called at the boundaries of `schedule` in `tz`.
+    #[cfg(any(
+        feature = "day",
+        feature = "hour",
+        feature = "minute",
+        feature = "second",
+        feature = "week",
+        feature = "month",
+        feature = "quarter",
+        feature = "year"
+    ))]
+    fn add_boundary<Tz, S>(&self, tz: Tz, schedule: S, callback: Callback) -> JobId
+    where
+        Tz: TimeZone + Send + 'static,
+        S: Schedule + Send + 'static,
+    {
+        let next_after: NextAfter = Box::new(move |time| {
+            let next = schedule.next_after(&time.with_timezone(&tz))?;
+            Some(next.with_timezone(&Utc))
+        });
+        // the first boundary may be right now
+        let now = self.shared.clock.now() - chrono::Duration::nanoseconds(1);
+        let boundary = next_after(&now).expect("date out of range");
+        let kind = Kind::Boundary {
+            next_after,
+            boundary,
+        };
+        self.add(kind, callback)
+    }
+
+    #[allow(dead_code)] // every caller is gated behind its own feature
+    fn add(&self, kind: Kind, callback: Callback) -> JobId {
+        let deadline = kind.deadline(&*self.shared.clock);
+        let mut state = self.shared.lock();
+        let id = JobId(state.next_id);
+        state.next_id += 1;
+        state.jobs.insert(
+            id,
+            Job {
+                kind,
+                deadline,
+                callback: Some(callback),
+            },
+        );
+        state.queue.push(Reverse((deadline, id)));
+        drop(state);
+        self.wake();
+        id
+    }
+
+    /// Wakes the scheduler thread, to pick up a new job or to shut down.
+    fn wake(&self) {
+        if let Some(thread) = &self.thread {
+            thread.thread().unpark();
+        }
+    }
+}
+
+impl Default for Scheduler {
+    fn default() -> Scheduler {
+        Scheduler::new()
+    }
+}
+
+impl Drop for Scheduler {
+    fn drop(&mut self) {
+        {
+            let mut state = self.shared.lock();
+            state.shutdown = true;
+            state.jobs.clear();
+        }
+        self.wake();
+        if let Some(thread) = self.thread.take() {
+            // a callback that owns the last reference can't wait for itself
+            if thread.thread().id() != thread::current().id() {
+                let _ = thread.join();
+            }
+        }
+    }
+}
+
+impl fmt::Debug for Scheduler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Scheduler")
+            .field("jobs", &self.len())
+            .finish()
+    }
+}
+
+impl Shared {
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn run(&self) {
+        let clock = &*self.clock;
+        let mut detector = Detector::new(clock.instant(), clock.now());
+        let mut state = self.lock();
+        loop {
+            if state.shutdown {
+                return;
+            }
+            if let Some(jump) = detector.check(clock.instant(), clock.now()) {
+                state = self.clock_jumped(state, jump);
+                continue;
+            }
+            let now = clock.instant();
+            let Some(&Reverse((deadline, id))) = state.queue.peek() else {
+                state = self.sleep_until(state, now + CHECK_INTERVAL);
+                continue;
+            };
+            if deadline > now {
+                state = self.sleep_until(state, deadline.min(now + CHECK_INTERVAL));
+                continue;
+            }
+            state.queue.pop();
+            let State { queue, jobs, .. } = &mut *state;
+            let Some(job) = jobs.get_mut(&id).filter(|job| job.deadline == deadline) else {
+                continue;
+            };
+            if !job.kind.is_due(clock) {
+                job.deadline = job.kind.deadline(clock);
+                queue.push(Reverse((job.deadline, id)));
+                continue;
+            }
+            let Some(mut callback) = job.callback.take() else {
+                continue;
+            };
+
+            let started = clock.now();
+            drop(state);
+            let result = panic::catch_unwind(AssertUnwindSafe(&mut callback));
+            state = self.lock();
+            if let Err(payload) = result {
+                let policy;
+                (state, policy) = self.panicked(state, id, payload);
+                if policy == PanicPolicy::Stop {
+                    state.jobs.remove(&id);
+                    continue;
+                }
+            }
+
+            // the job might have been removed while its callback was running
+            let Some(job) = state.jobs.get_mut(&id) else {
+                continue;
+            };
+            if job.kind.advance(started) {
+                job.callback = Some(callback);
+                job.deadline = job.kind.deadline(clock);
+                let deadline = job.deadline;
+                state.queue.push(Reverse((deadline, id)));
+            } else {
+                state.jobs.remove(&id);
+            }
+        }
+    }
+
+    /// Sleeps on the clock until `deadline`, or until a [`Scheduler`] wakes
+    /// the thread up.
+    fn sleep_until<'a>(
+        &'a self,
+        state: MutexGuard<'a, State>,
+        deadline: Instant,
+    ) -> MutexGuard<'a, State> {
+        drop(state);
+        self.clock.sleep_until(deadline);
+        self.lock()
+    }
+
+    /// Calls the hook with a panic of the callback of `id`, unwinds the thread
+    /// if the panic has to propagate, returns the policy otherwise.
+    fn panicked<'a>(
+        &'a self,
+        mut state: MutexGuard<'a, State>,
+        id: JobId,
+        payload: Box<dyn Any + Send + 'static>,
+    ) -> (MutexGuard<'a, State>, PanicPolicy) {
+        let panic = CaughtPanic::new(payload);
+        if let Some(mut hook) = state.on_panic.take() {
+            drop(state);
+            hook(id, &panic);
+            state = self.lock();
+            // the hook might have been replaced while it was running
+            state.on_panic.get_or_insert(hook);
+        }
+        let policy = state.panic_policy;
+        if policy == PanicPolicy::Propagate {
+            drop(state);
+            panic::resume_unwind(panic.into_payload());
+        }
+        (state, policy)
+    }
+
+    /// Re-arms the jobs that follow calendar boundaries and calls the hook.
+    ///
+    /// After a backward jump the boundaries that already passed are
+    /// forgotten, after a forward jump the ones that were jumped over are
+    /// called once right away.
+    fn clock_jumped<'a>(
+        &'a self,
+        mut state: MutexGuard<'a, State>,
+        jump: ClockJump,
+    ) -> MutexGuard<'a, State> {
+        let clock = &*self.clock;
+        let State { queue, jobs, .. } = &mut *state;
+        let mut done = Vec::new();
+        for (id, job) in jobs.iter_mut() {
+            let Kind::Boundary {
+                next_after,
+                boundary,
+            } = &mut job.kind
+            else {
+                continue;
+            };
+            if jump.is_backward() {
+                let now = clock.now() - chrono::Duration::nanoseconds(1);
+                match next_after(&now) {
+                    Some(next) => *boundary = next,
+                    None => {
+                        done.push(*id);
+                        continue;
+                    }
+                }
+            }
+            job.deadline = job.kind.deadline(clock);
+            queue.push(Reverse((job.deadline, *id)));
+        }
+        for id in done {
+            jobs.remove(&id);
+        }
+        let Some(mut hook) = state.on_clock_jump.take() else {
+            return state;
+        };
+        drop(state);
+        hook(jump);
+        let mut state = self.lock();
+        // the hook might have been replaced while it was running
+        state.on_clock_jump.get_or_insert(hook);
+        state
+    }
+}
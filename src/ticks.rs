@@ -0,0 +1,150 @@
+//! Ticks as a [`Stream`](futures_core::Stream), for async code that runs its
+//! own event loop.
+//!
+//! The async functions of this crate take the callback and keep it until the
+//! timer ends. The streams of this module yield a [`Tick`](crate::Tick) at
+//! the same boundaries instead, so schedules can be merged, filtered, taken
+//! and zipped with stream combinators or awaited in a `tokio::select!` next
+//! to other events. Any other timer becomes a stream with
+//! [`Timer::stream`], like `Timer::every_hour_in(tz).stream()`.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use futures_util::StreamExt;
+//! use zila::ticks;
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let mut hours = ticks::every_hour();
+//! let mut days = ticks::every_day();
+//! loop {
+//!     tokio::select! {
+//!         Some(tick) = hours.next() => println!("hour {} started", tick.scheduled()),
+//!         Some(_) = days.next() => println!("rotating the logs"),
+//!         _ = tokio::signal::ctrl_c() => break,
+//!     }
+//! }
+//! # }
+//! ```
+//!
+//! *This module requires the following crate features to be activated: `ticks`*
+
+#[cfg(feature = "cron")]
+use crate::Cron;
+use crate::{Ticks, Timer};
+#[cfg(feature = "interval")]
+use std::time::Duration;
+
+/// yields a tick at the start of every hour
+///
+/// The ticks are aligned to the boundaries
+/// [`duration_to_next_hour`](crate::duration_to_next_hour) computes, in the
+/// local time zone.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use futures_util::StreamExt;
+/// use zila::ticks;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let mut hours = ticks::every_hour().take(24);
+/// while let Some(tick) = hours.next().await {
+///     println!("{:?} late", tick.lateness());
+/// }
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `ticks`, `hour`*
+#[cfg(feature = "hour")]
+pub fn every_hour() -> Ticks {
+    Timer::every_hour().stream()
+}
+
+/// yields a tick at every midnight
+///
+/// The ticks are aligned to the boundaries
+/// [`duration_to_next_day`](crate::duration_to_next_day) computes, in the
+/// local time zone.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use futures_util::StreamExt;
+/// use zila::ticks;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let mut weekdays = ticks::every_day().filter(|tick| {
+///     use chrono::{Datelike, Local, Weekday};
+///     let day = tick.scheduled().with_timezone(&Local).weekday();
+///     std::future::ready(!matches!(day, Weekday::Sat | Weekday::Sun))
+/// });
+/// while let Some(_) = weekdays.next().await {
+///     println!("Good morning");
+/// }
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `ticks`, `day`*
+#[cfg(feature = "day")]
+pub fn every_day() -> Ticks {
+    Timer::every_day().stream()
+}
+
+/// yields a tick every `period`
+///
+/// The first tick comes `period` after the call. The ticks are due at fixed
+/// deadlines, like [`Timer::fixed_rate`], a consumer that falls behind gets
+/// the ticks it missed right away, see
+/// [`MissedTickBehavior::Burst`](crate::MissedTickBehavior::Burst).
+///
+/// # Example
+///
+/// ```rust
+/// use futures_util::StreamExt;
+/// use std::time::Duration;
+/// use zila::ticks;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let ticks = ticks::interval(Duration::from_millis(10));
+/// assert_eq!(ticks.take(3).count().await, 3);
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `ticks`, `interval`*
+#[cfg(feature = "interval")]
+pub fn interval(period: Duration) -> Ticks {
+    Timer::interval(period).fixed_rate().stream()
+}
+
+/// yields a tick every time the cron expression matches
+///
+/// The ticks are aligned to the matches
+/// [`duration_to_next_cron`](crate::duration_to_next_cron) computes, in the
+/// local time zone. The stream ends once the expression never matches again.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use futures_util::StreamExt;
+/// use zila::{ticks, Cron};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let cron = Cron::parse("30 9 * * MON-FRI").unwrap();
+/// let mut mornings = ticks::cron(cron);
+/// while let Some(_) = mornings.next().await {
+///     println!("Good morning");
+/// }
+/// # }
+/// ```
+///
+/// *This function requires the following crate features to be activated: `ticks`, `cron`*
+#[cfg(feature = "cron")]
+pub fn cron(cron: Cron) -> Ticks {
+    Timer::cron(cron).stream()
+}
@@ -0,0 +1,2156 @@
+//! A builder for timers that need more control than the free functions offer.
+
+use crate::clock::{Clock, Sleep, SystemClock};
+use crate::handle::{self, Canceller, TaskHandle, TimerHandle, Token};
+use crate::jump::{ClockJump, Detector, CHECK_INTERVAL};
+use crate::policy::{CaughtPanic, ErrorPolicy, PanicPolicy};
+#[cfg(feature = "interval")]
+use crate::Aligned;
+#[cfg(feature = "cron")]
+use crate::Cron;
+#[cfg(feature = "day")]
+use crate::EveryDay;
+#[cfg(feature = "hour")]
+use crate::EveryHour;
+#[cfg(feature = "minute")]
+use crate::EveryMinute;
+#[cfg(feature = "month")]
+use crate::EveryMonth;
+#[cfg(feature = "quarter")]
+use crate::EveryQuarter;
+#[cfg(feature = "second")]
+use crate::EverySecond;
+#[cfg(feature = "week")]
+use crate::EveryWeek;
+#[cfg(feature = "year")]
+use crate::EveryYear;
+#[cfg(feature = "rrule")]
+use crate::Recurrence;
+use crate::Schedule;
+#[cfg(feature = "week")]
+use chrono::Weekday;
+use chrono::{DateTime, Utc};
+use chrono::{Local, TimeZone};
+#[cfg(feature = "ticks")]
+use futures_core::Stream;
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::fmt;
+use std::future::{self, Future};
+use std::ops::ControlFlow;
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::pin;
+#[cfg(feature = "ticks")]
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::task::{Context, Poll};
+use std::thread::{self, Thread};
+use std::time::{Duration, Instant};
+use tokio::task::JoinSet;
+
+type NextFn = Box<dyn Fn(&DateTime<Utc>) -> Option<DateTime<Utc>> + Send + 'static>;
+type JumpHook = Box<dyn FnMut(ClockJump) + Send + 'static>;
+type PanicHook = Box<dyn FnMut(&CaughtPanic) + Send + 'static>;
+type TimeoutHook = Box<dyn FnMut(&TimedOut) + Send + 'static>;
+
+/// Configures a timer before starting it.
+///
+/// The free functions of this crate, like [`set_interval`](crate::set_interval)
+/// or [`call_every_hour`](crate::call_every_hour), pick sensible defaults. A
+/// `Timer` starts the same kinds of timers but lets you change how they behave
+/// first, then [`run`](Timer::run) or [`run_async`](Timer::run_async) runs it
+/// on the current thread or task, and [`spawn`](Timer::spawn) or
+/// [`spawn_async`](Timer::spawn_async) starts it in the background. The
+/// callback receives a [`Tick`] describing the invocation.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use zila::Timer;
+///
+/// let handle = Timer::interval(Duration::from_millis(10))
+///     .fixed_rate()
+///     .spawn(|_| {
+///         println!("every 10ms, no matter how long this takes");
+///     });
+///
+/// std::thread::sleep(Duration::from_millis(50));
+/// handle.cancel();
+/// ```
+///
+/// *This type requires at least one of the following crate features to be activated: `timeout`,
+/// `interval`, `day`, `hour`, `minute`, `second`, `week`, `month`, `quarter`, `year`, `cron`, `rrule`, `systemd`, `human`*
+#[must_use = "a `Timer` does nothing until it is run or spawned"]
+pub struct Timer {
+    name: &'static str,
+    kind: Kind,
+    missed_tick_behavior: MissedTickBehavior,
+    overlap_policy: OverlapPolicy,
+    on_clock_jump: Option<JumpHook>,
+    panics: Panics,
+    timeouts: Timeouts,
+    clock: Arc<dyn Clock>,
+    token: Token,
+}
+
+#[allow(dead_code)] // every variant is gated behind its own feature
+enum Kind {
+    /// `None` once the timer fired.
+    Timeout(Option<Duration>),
+    Interval {
+        period: Duration,
+        rate: Rate,
+    },
+    Calendar(NextFn),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Rate {
+    FixedDelay,
+    FixedRate,
+}
+
+/// What a timer does when it falls behind its schedule.
+///
+/// A timer misses ticks when its callback takes longer than the period, or
+/// when the process doesn't run for a while, like when the machine sleeps. In
+/// every case the first missed tick fires as soon as possible, the behaviors
+/// differ in what happens to the others. The number of ticks that were dropped
+/// is reported by [`Tick::skipped`].
+///
+/// Only fixed-rate intervals and calendar timers can fall behind, a fixed-delay
+/// interval always waits a whole period after its callback returns.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use zila::{MissedTickBehavior, Timer};
+///
+/// Timer::every_second()
+///     .missed_tick_behavior(MissedTickBehavior::Skip)
+///     .run(|tick| {
+///         if tick.skipped() > 0 {
+///             println!("missed {} seconds", tick.skipped());
+///         }
+///     });
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MissedTickBehavior {
+    /// Calls the callback for every missed tick, back to back, until the timer
+    /// has caught up. The default for fixed-rate intervals.
+    Burst,
+    /// Calls the callback once for all missed ticks and restarts the period
+    /// from there, every later tick is shifted by the delay. Calendar timers
+    /// can't shift their boundaries and behave like [`Skip`](Self::Skip).
+    Delay,
+    /// Calls the callback once for all missed ticks and continues with the
+    /// next tick of the original schedule. The default for calendar timers.
+    Skip,
+}
+
+/// What an async timer does when a tick is due while its callback is still
+/// running.
+///
+/// By default the timer awaits every callback before it waits for the next
+/// tick, so a callback that takes longer than the period shifts the schedule.
+/// With the other policies every callback runs on a task of its own, spawned
+/// on the runtime of the timer, and the timer keeps ticking on schedule. The
+/// ticks that are dropped are added to [`Tick::skipped`] of the next call.
+///
+/// When the timer ends, because a callback returned [`ControlFlow::Break`] or
+/// the timer was cancelled, the callbacks that are still running are dropped
+/// at their next `.await` point. When the schedule ends, the timer waits for
+/// them first.
+///
+/// The policy only applies to [`Timer::spawn_async`], a timer started with
+/// [`Timer::spawn`] calls its callback on its own thread, one call at a time.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use zila::{OverlapPolicy, Timer};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// Timer::every_second()
+///     .overlap_policy(OverlapPolicy::Concurrent(4))
+///     .spawn_async(|_| async {
+///         // a poll that takes 3 seconds still starts every second
+///         tokio::time::sleep(Duration::from_secs(3)).await;
+///     })
+///     .await
+///     .unwrap();
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum OverlapPolicy {
+    /// Awaits the callback before waiting for the next tick. The default.
+    #[default]
+    Serial,
+    /// Drops the ticks that are due while the callback runs.
+    SkipIfRunning,
+    /// Runs one callback at a time and keeps up to the given number of ticks
+    /// that are due while it runs, the ticks beyond are dropped. The queued
+    /// ticks run back to back.
+    Queue(usize),
+    /// Runs up to the given number of callbacks at the same time, the ticks
+    /// that are due while all of them run are dropped. A limit of 0 is
+    /// treated as 1, like [`SkipIfRunning`](Self::SkipIfRunning).
+    Concurrent(usize),
+}
+
+/// Describes an invocation of a [`Timer`] callback.
+///
+/// The callbacks of the free functions take no arguments, a [`Timer`] starts
+/// the same timers with a callback that receives the tick, like
+/// [`Timer::every_hour`] for [`call_every_hour`](crate::call_every_hour).
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use chrono::Local;
+/// use zila::Timer;
+///
+/// Timer::every_hour().spawn(|tick| {
+///     // the hour that just ended, even if the callback runs late
+///     let end = tick.scheduled().with_timezone(&Local);
+///     println!("aggregating the hour before {}, {:?} late", end, tick.lateness());
+/// });
+/// ```
+#[derive(Clone, Debug)]
+pub struct Tick {
+    sequence: u64,
+    scheduled: DateTime<Utc>,
+    actual: DateTime<Utc>,
+    lateness: Duration,
+    skipped: u64,
+    token: CancellationToken,
+}
+
+impl Tick {
+    /// Returns the number of times the callback was called before, the first
+    /// call is 0.
+    ///
+    /// Skipped ticks are not counted, see [`skipped`](Tick::skipped).
+    pub fn sequence(&self) -> u64 {
+        self.sequence
+    }
+
+    /// Returns the instant the tick was due.
+    ///
+    /// For calendar timers this is the boundary the tick belongs to, like the
+    /// start of the hour for [`Timer::every_hour`], no matter how late the
+    /// callback runs. When ticks were skipped, it's the latest of them. The
+    /// deadlines of intervals are measured on the monotonic clock, they are
+    /// mapped onto the wall clock at the time of the call.
+    pub fn scheduled(&self) -> DateTime<Utc> {
+        self.scheduled
+    }
+
+    /// Returns the wall clock time at which the timer woke up to call the
+    /// callback.
+    pub fn actual(&self) -> DateTime<Utc> {
+        self.actual
+    }
+
+    /// Returns how long after [`scheduled`](Tick::scheduled) the callback
+    /// is called, zero if it's on time.
+    pub fn lateness(&self) -> Duration {
+        self.lateness
+    }
+
+    /// Returns the number of ticks that were dropped right before this one
+    /// because the timer fell behind, see [`MissedTickBehavior`].
+    pub fn skipped(&self) -> u64 {
+        self.skipped
+    }
+
+    /// Returns `true` once the call ran past the execution timeout of the
+    /// timer, see [`Timer::execution_timeout`].
+    pub fn is_cancelled(&self) -> bool {
+        self.token.is_cancelled()
+    }
+
+    /// Returns the token that is cancelled when the call runs past the
+    /// execution timeout of the timer, to hand it to other threads.
+    pub fn cancellation_token(&self) -> &CancellationToken {
+        &self.token
+    }
+}
+
+/// Tells a callback that it ran past the execution timeout of its timer.
+///
+/// A sync callback can't be interrupted, it has to check the token from time
+/// to time and return early once it is cancelled, see
+/// [`Timer::execution_timeout`]. Clones share the same state. The token of a
+/// timer without an execution timeout is never cancelled.
+#[derive(Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Option<Arc<AtomicBool>>,
+}
+
+impl CancellationToken {
+    fn new() -> CancellationToken {
+        CancellationToken {
+            cancelled: Some(Arc::new(AtomicBool::new(false))),
+        }
+    }
+
+    /// Returns `true` once the call ran past its deadline.
+    pub fn is_cancelled(&self) -> bool {
+        match &self.cancelled {
+            Some(cancelled) => cancelled.load(Ordering::Acquire),
+            None => false,
+        }
+    }
+
+    fn cancel(&self) {
+        if let Some(cancelled) = &self.cancelled {
+            cancelled.store(true, Ordering::Release);
+        }
+    }
+}
+
+impl fmt::Debug for CancellationToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CancellationToken")
+            .field("cancelled", &self.is_cancelled())
+            .finish()
+    }
+}
+
+/// A call of a [`Timer`] callback that ran past its execution timeout.
+///
+/// Passed to the hook set with [`Timer::on_timeout`].
+#[derive(Clone, Debug)]
+pub struct TimedOut {
+    tick: Tick,
+    limit: Duration,
+}
+
+impl TimedOut {
+    /// Returns the tick the callback was called with.
+    pub fn tick(&self) -> &Tick {
+        &self.tick
+    }
+
+    /// Returns the execution timeout the call ran past.
+    pub fn limit(&self) -> Duration {
+        self.limit
+    }
+}
+
+/// The return type of a timer callback, decides whether the timer goes on.
+///
+/// A callback that returns `()` runs until the timer is cancelled or its
+/// schedule ends. A callback that returns a [`ControlFlow`] stops the timer
+/// with [`ControlFlow::Break`], the value is returned by [`Timer::run`], by
+/// joining the [`TimerHandle`](crate::TimerHandle) or by awaiting the
+/// [`TaskHandle`](crate::TaskHandle) as `Some(value)`, or as `None` when the
+/// schedule ended on its own, like a [`Once`](crate::Once) that fired.
+///
+/// A callback that returns a `Result<(), E>` stops the timer with its first
+/// error, the timer then ends with `Err(error)`, and with `Ok(())` when the
+/// schedule ended. [`Timer::try_spawn`] and the `try_` functions choose what
+/// happens to errors with an [`ErrorPolicy`].
+///
+/// # Example
+///
+/// polling until a file shows up:
+///
+/// ```rust
+/// use std::ops::ControlFlow;
+/// use std::path::Path;
+/// use std::time::Duration;
+/// use zila::set_interval;
+///
+/// let length = set_interval(|| {
+///     match std::fs::metadata(Path::new("Cargo.toml")) {
+///         Ok(metadata) => ControlFlow::Break(metadata.len()),
+///         Err(_) => ControlFlow::Continue(()),
+///     }
+/// }, Duration::from_millis(10));
+/// assert!(length.is_some());
+/// ```
+pub trait Flow: Send + 'static {
+    /// The value the timer ends with.
+    type Output: Send + 'static;
+
+    /// Returns [`ControlFlow::Break`] with the value to end the timer with,
+    /// or [`ControlFlow::Continue`] to wait for the next tick.
+    fn branch(self) -> ControlFlow<Self::Output>;
+
+    /// Returns the value a timer ends with when its schedule has no more
+    /// ticks.
+    fn exhausted() -> Self::Output;
+}
+
+impl Flow for () {
+    type Output = ();
+
+    fn branch(self) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
+
+    fn exhausted() {}
+}
+
+impl<B: Send + 'static> Flow for ControlFlow<B> {
+    type Output = Option<B>;
+
+    fn branch(self) -> ControlFlow<Option<B>> {
+        match self {
+            ControlFlow::Continue(()) => ControlFlow::Continue(()),
+            ControlFlow::Break(value) => ControlFlow::Break(Some(value)),
+        }
+    }
+
+    fn exhausted() -> Option<B> {
+        None
+    }
+}
+
+impl<E: Send + 'static> Flow for Result<(), E> {
+    type Output = Result<(), E>;
+
+    fn branch(self) -> ControlFlow<Result<(), E>> {
+        match self {
+            Ok(()) => ControlFlow::Continue(()),
+            Err(error) => ControlFlow::Break(Err(error)),
+        }
+    }
+
+    fn exhausted() -> Result<(), E> {
+        Ok(())
+    }
+}
+
+impl Timer {
+    /// Creates a timer that calls its callback once, `delay` after it starts,
+    /// like [`set_timeout`](crate::set_timeout).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use zila::Timer;
+    ///
+    /// let handle = Timer::timeout(Duration::from_secs(60)).spawn(|_| {
+    ///     println!("never printed");
+    /// });
+    /// handle.cancel();
+    /// assert!(handle.join().unwrap_err().is_cancelled());
+    /// ```
+    ///
+    /// *This function requires the following crate features to be activated: `timeout`*
+    #[cfg(feature = "timeout")]
+    pub fn timeout(delay: Duration) -> Timer {
+        Timer {
+            name: "zila-set-timeout",
+            kind: Kind::Timeout(Some(delay)),
+            missed_tick_behavior: MissedTickBehavior::Burst,
+            overlap_policy: OverlapPolicy::Serial,
+            on_clock_jump: None,
+            panics: Panics::default(),
+            timeouts: Timeouts::default(),
+            clock: Arc::new(SystemClock),
+            token: Token::default(),
+        }
+    }
+
+    /// Creates a timer that calls its callback every `period`.
+    ///
+    /// The timer starts in fixed-delay mode, just like
+    /// [`set_interval`](crate::set_interval).
+    ///
+    /// *This function requires the following crate features to be activated: `interval`*
+    #[cfg(feature = "interval")]
+    pub fn interval(period: Duration) -> Timer {
+        Timer {
+            name: "zila-set-interval",
+            kind: Kind::Interval {
+                period,
+                rate: Rate::FixedDelay,
+            },
+            missed_tick_behavior: MissedTickBehavior::Burst,
+            overlap_policy: OverlapPolicy::Serial,
+            on_clock_jump: None,
+            panics: Panics::default(),
+            timeouts: Timeouts::default(),
+            clock: Arc::new(SystemClock),
+            token: Token::default(),
+        }
+    }
+
+    /// Creates a timer that calls its callback at every multiple of `period`
+    /// since the Unix epoch, like
+    /// [`call_every_aligned`](crate::call_every_aligned).
+    ///
+    /// Use [`Timer::schedule`] with an [`Aligned`] schedule to measure the
+    /// multiples from midnight instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is zero.
+    ///
+    /// *This function requires the following crate features to be activated: `interval`*
+    #[cfg(feature = "interval")]
+    pub fn aligned(period: Duration) -> Timer {
+        Timer::calendar("zila-call-every-aligned", Utc, Aligned::new(period))
+    }
+
+    /// Creates a timer that calls its callback every day at midnight, like
+    /// [`call_every_day`](crate::call_every_day).
+    ///
+    /// *This function requires the following crate features to be activated: `day`*
+    #[cfg(feature = "day")]
+    pub fn every_day() -> Timer {
+        Timer::every_day_in(Local)
+    }
+
+    /// Creates a timer that calls its callback every day at midnight in the
+    /// given time zone, like [`call_every_day_in`](crate::call_every_day_in).
+    ///
+    /// *This function requires the following crate features to be activated: `day`*
+    #[cfg(feature = "day")]
+    pub fn every_day_in<Tz>(tz: Tz) -> Timer
+    where
+        Tz: TimeZone + Send + 'static,
+    {
+        Timer::calendar("zila-call-every-day", tz, EveryDay)
+    }
+
+    /// Creates a timer that calls its callback every hour, like
+    /// [`call_every_hour`](crate::call_every_hour).
+    ///
+    /// *This function requires the following crate features to be activated: `hour`*
+    #[cfg(feature = "hour")]
+    pub fn every_hour() -> Timer {
+        Timer::every_hour_in(Local)
+    }
+
+    /// Creates a timer that calls its callback every hour in the given time
+    /// zone, like [`call_every_hour_in`](crate::call_every_hour_in).
+    ///
+    /// *This function requires the following crate features to be activated: `hour`*
+    #[cfg(feature = "hour")]
+    pub fn every_hour_in<Tz>(tz: Tz) -> Timer
+    where
+        Tz: TimeZone + Send + 'static,
+    {
+        Timer::calendar("zila-call-every-hour", tz, EveryHour)
+    }
+
+    /// Creates a timer that calls its callback every minute, like
+    /// [`call_every_minute`](crate::call_every_minute).
+    ///
+    /// *This function requires the following crate features to be activated: `minute`*
+    #[cfg(feature = "minute")]
+    pub fn every_minute() -> Timer {
+        Timer::every_minute_in(Local)
+    }
+
+    /// Creates a timer that calls its callback every minute in the given time
+    /// zone, like [`call_every_minute_in`](crate::call_every_minute_in).
+    ///
+    /// *This function requires the following crate features to be activated: `minute`*
+    #[cfg(feature = "minute")]
+    pub fn every_minute_in<Tz>(tz: Tz) -> Timer
+    where
+        Tz: TimeZone + Send + 'static,
+    {
+        Timer::calendar("zila-call-every-minute", tz, EveryMinute)
+    }
+
+    /// Creates a timer that calls its callback every second, like
+    /// [`call_every_second`](crate::call_every_second).
+    ///
+    /// *This function requires the following crate features to be activated: `second`*
+    #[cfg(feature = "second")]
+    pub fn every_second() -> Timer {
+        Timer::every_second_in(Local)
+    }
+
+    /// Creates a timer that calls its callback every second in the given time
+    /// zone, like [`call_every_second_in`](crate::call_every_second_in).
+    ///
+    /// *This function requires the following crate features to be activated: `second`*
+    #[cfg(feature = "second")]
+    pub fn every_second_in<Tz>(tz: Tz) -> Timer
+    where
+        Tz: TimeZone + Send + 'static,
+    {
+        Timer::calendar("zila-call-every-second", tz, EverySecond)
+    }
+
+    /// Creates a timer that calls its callback at the start of every week, like
+    /// [`call_every_week`](crate::call_every_week).
+    ///
+    /// *This function requires the following crate features to be activated: `week`*
+    #[cfg(feature = "week")]
+    pub fn every_week(start: Weekday) -> Timer {
+        Timer::every_week_in(start, Local)
+    }
+
+    /// Creates a timer that calls its callback at the start of every week in
+    /// the given time zone, like [`call_every_week_in`](crate::call_every_week_in).
+    ///
+    /// *This function requires the following crate features to be activated: `week`*
+    #[cfg(feature = "week")]
+    pub fn every_week_in<Tz>(start: Weekday, tz: Tz) -> Timer
+    where
+        Tz: TimeZone + Send + 'static,
+    {
+        Timer::calendar("zila-call-every-week", tz, EveryWeek::starting(start))
+    }
+
+    /// Creates a timer that calls its callback at the start of every month, like
+    /// [`call_every_month`](crate::call_every_month).
+    ///
+    /// *This function requires the following crate features to be activated: `month`*
+    #[cfg(feature = "month")]
+    pub fn every_month() -> Timer {
+        Timer::every_month_in(Local)
+    }
+
+    /// Creates a timer that calls its callback at the start of every month in
+    /// the given time zone, like [`call_every_month_in`](crate::call_every_month_in).
+    ///
+    /// *This function requires the following crate features to be activated: `month`*
+    #[cfg(feature = "month")]
+    pub fn every_month_in<Tz>(tz: Tz) -> Timer
+    where
+        Tz: TimeZone + Send + 'static,
+    {
+        Timer::calendar("zila-call-every-month", tz, EveryMonth)
+    }
+
+    /// Creates a timer that calls its callback at the start of every quarter, like
+    /// [`call_every_quarter`](crate::call_every_quarter).
+    ///
+    /// *This function requires the following crate features to be activated: `quarter`*
+    #[cfg(feature = "quarter")]
+    pub fn every_quarter() -> Timer {
+        Timer::every_quarter_in(Local)
+    }
+
+    /// Creates a timer that calls its callback at the start of every quarter in
+    /// the given time zone, like [`call_every_quarter_in`](crate::call_every_quarter_in).
+    ///
+    /// *This function requires the following crate features to be activated: `quarter`*
+    #[cfg(feature = "quarter")]
+    pub fn every_quarter_in<Tz>(tz: Tz) -> Timer
+    where
+        Tz: TimeZone + Send + 'static,
+    {
+        Timer::calendar("zila-call-every-quarter", tz, EveryQuarter)
+    }
+
+    /// Creates a timer that calls its callback at the start of every year, like
+    /// [`call_every_year`](crate::call_every_year).
+    ///
+    /// *This function requires the following crate features to be activated: `year`*
+    #[cfg(feature = "year")]
+    pub fn every_year() -> Timer {
+        Timer::every_year_in(Local)
+    }
+
+    /// Creates a timer that calls its callback at the start of every year in
+    /// the given time zone, like [`call_every_year_in`](crate::call_every_year_in).
+    ///
+    /// *This function requires the following crate features to be activated: `year`*
+    #[cfg(feature = "year")]
+    pub fn every_year_in<Tz>(tz: Tz) -> Timer
+    where
+        Tz: TimeZone + Send + 'static,
+    {
+        Timer::calendar("zila-call-every-year", tz, EveryYear)
+    }
+
+    /// Creates a timer that calls its callback every time the cron expression
+    /// matches, like [`call_every_cron`](crate::call_every_cron).
+    ///
+    /// *This function requires the following crate features to be activated: `cron`*
+    #[cfg(feature = "cron")]
+    pub fn cron(cron: Cron) -> Timer {
+        Timer::cron_in(cron, Local)
+    }
+
+    /// Creates a timer that calls its callback every time the cron expression
+    /// matches in the given time zone, like
+    /// [`call_every_cron_in`](crate::call_every_cron_in).
+    ///
+    /// *This function requires the following crate features to be activated: `cron`*
+    #[cfg(feature = "cron")]
+    pub fn cron_in<Tz>(cron: Cron, tz: Tz) -> Timer
+    where
+        Tz: TimeZone + Send + 'static,
+    {
+        Timer::calendar("zila-call-every-cron", tz, cron)
+    }
+
+    /// Creates a timer that calls its callback at every occurrence of the
+    /// recurrence, like [`call_every_recurrence`](crate::call_every_recurrence).
+    ///
+    /// *This function requires the following crate features to be activated: `rrule`*
+    #[cfg(feature = "rrule")]
+    pub fn recurrence(recurrence: Recurrence) -> Timer {
+        Timer::recurrence_in(recurrence, Local)
+    }
+
+    /// Creates a timer that calls its callback at every occurrence of the
+    /// recurrence with its floating times in the given time zone, like
+    /// [`call_every_recurrence_in`](crate::call_every_recurrence_in).
+    ///
+    /// *This function requires the following crate features to be activated: `rrule`*
+    #[cfg(feature = "rrule")]
+    pub fn recurrence_in<Tz>(recurrence: Recurrence, tz: Tz) -> Timer
+    where
+        Tz: TimeZone + Send + 'static,
+    {
+        Timer::calendar("zila-call-every-recurrence", tz, recurrence)
+    }
+
+    /// Creates a timer that calls its callback every time the schedule fires,
+    /// like [`run`](crate::run).
+    pub fn schedule<S>(schedule: S) -> Timer
+    where
+        S: Schedule + Send + 'static,
+    {
+        Timer::schedule_in(schedule, Local)
+    }
+
+    /// Creates a timer that calls its callback every time the schedule fires
+    /// in the given time zone, like [`run_in`](crate::run_in).
+    pub fn schedule_in<S, Tz>(schedule: S, tz: Tz) -> Timer
+    where
+        S: Schedule + Send + 'static,
+        Tz: TimeZone + Send + 'static,
+    {
+        Timer::calendar("zila-run", tz, schedule)
+    }
+
+    /// Creates a calendar timer that follows `schedule` in `tz`.
+    fn calendar<S, Tz>(name: &'static str, tz: Tz, schedule: S) -> Timer
+    where
+        S: Schedule + Send + 'static,
+        Tz: TimeZone + Send + 'static,
+    {
+        let next = move |time: &DateTime<Utc>| {
+            let next = schedule.next_after(&time.with_timezone(&tz))?;
+            Some(next.with_timezone(&Utc))
+        };
+        Timer {
+            name,
+            kind: Kind::Calendar(Box::new(next)),
+            missed_tick_behavior: MissedTickBehavior::Skip,
+            overlap_policy: OverlapPolicy::Serial,
+            on_clock_jump: None,
+            panics: Panics::default(),
+            timeouts: Timeouts::default(),
+            clock: Arc::new(SystemClock),
+            token: Token::default(),
+        }
+    }
+
+    /// Waits `period` after each callback returns before calling it again.
+    ///
+    /// The time between two calls is `period` plus the time the callback takes,
+    /// so the ticks drift further apart the longer the callback runs. This is
+    /// the default for intervals, calendar timers ignore it.
+    pub fn fixed_delay(self) -> Timer {
+        self.rate(Rate::FixedDelay)
+    }
+
+    /// Calls the callback at fixed deadlines, `start + n * period`, measured
+    /// on a monotonic clock.
+    ///
+    /// The time the callback takes doesn't shift the following ticks, a metric
+    /// sampler with a period of 10 seconds runs every 10 seconds and not every
+    /// 10.3. What happens when the callback takes longer than `period` is up
+    /// to the [`MissedTickBehavior`]. Calendar timers always run at fixed
+    /// deadlines and ignore it.
+    pub fn fixed_rate(self) -> Timer {
+        self.rate(Rate::FixedRate)
+    }
+
+    fn rate(mut self, new: Rate) -> Timer {
+        if let Kind::Interval { rate, .. } = &mut self.kind {
+            *rate = new;
+        }
+        self
+    }
+
+    /// Sets what the timer does when it falls behind its schedule.
+    ///
+    /// Defaults to [`MissedTickBehavior::Burst`] for intervals and to
+    /// [`MissedTickBehavior::Skip`] for calendar timers.
+    pub fn missed_tick_behavior(mut self, behavior: MissedTickBehavior) -> Timer {
+        self.missed_tick_behavior = behavior;
+        self
+    }
+
+    /// Calls `hook` every time the timer notices that the wall clock jumped.
+    ///
+    /// Calendar timers wait for a wall time but sleep on a monotonic clock,
+    /// they check at least once a second whether the wall clock was stepped or
+    /// the machine was suspended. When the clock jumped backward the timer is
+    /// re-armed against the new wall time. When it jumped forward the
+    /// boundaries that were jumped over are handled like any other missed
+    /// ticks, see [`MissedTickBehavior`]. Intervals only follow the monotonic
+    /// clock and never call the hook.
+    ///
+    /// The hook runs on the timer's thread or task, right before the timer
+    /// waits again.
+    pub fn on_clock_jump<F>(mut self, hook: F) -> Timer
+    where
+        F: FnMut(ClockJump) + Send + 'static,
+    {
+        self.on_clock_jump = Some(Box::new(hook));
+        self
+    }
+
+    /// Sets what an async timer does when a tick is due while its callback is
+    /// still running, the default is [`OverlapPolicy::Serial`].
+    pub fn overlap_policy(mut self, policy: OverlapPolicy) -> Timer {
+        self.overlap_policy = policy;
+        self
+    }
+
+    /// Sets what the timer does when its callback panics, the default is
+    /// [`PanicPolicy::Propagate`].
+    ///
+    /// Every panic is passed to the hook set with
+    /// [`on_panic`](Timer::on_panic) first, see [`PanicPolicy`].
+    pub fn panic_policy(mut self, policy: PanicPolicy) -> Timer {
+        self.panics.policy = policy;
+        self
+    }
+
+    /// Calls `hook` with every panic of the callback, before the
+    /// [`PanicPolicy`] applies.
+    ///
+    /// The hook runs on the timer's thread or task, after the panic unwound
+    /// the callback.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use zila::{PanicPolicy, Timer};
+    ///
+    /// let handle = Timer::interval(Duration::from_millis(1))
+    ///     .panic_policy(PanicPolicy::Stop)
+    ///     .on_panic(|panic| assert_eq!(panic.message(), Some("oops")))
+    ///     .spawn(|tick| {
+    ///         if tick.sequence() == 0 {
+    ///             panic!("oops");
+    ///         }
+    ///     });
+    /// assert!(handle.join().is_ok());
+    /// ```
+    pub fn on_panic<F>(mut self, hook: F) -> Timer
+    where
+        F: FnMut(&CaughtPanic) + Send + 'static,
+    {
+        self.panics.hook = Some(Box::new(hook));
+        self
+    }
+
+    /// Gives every call of the callback `limit` to finish.
+    ///
+    /// The future of an async callback is dropped when the deadline passes,
+    /// the timer goes on with the next tick. A sync callback can't be
+    /// stopped, a watchdog thread cancels the token of its [`Tick`] instead,
+    /// the callback should check [`Tick::is_cancelled`] and return early.
+    /// Either way, the call is reported to the hook set with
+    /// [`on_timeout`](Timer::on_timeout), nothing is printed without one.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use std::time::Duration;
+    /// use zila::Timer;
+    ///
+    /// Timer::every_hour()
+    ///     .execution_timeout(Duration::from_secs(10 * 60))
+    ///     .spawn(|tick| {
+    ///         for chunk in 0..1000 {
+    ///             if tick.is_cancelled() {
+    ///                 // the rest waits for the next hour
+    ///                 break;
+    ///             }
+    ///             println!("compacting chunk {}", chunk);
+    ///         }
+    ///     });
+    /// ```
+    pub fn execution_timeout(mut self, limit: Duration) -> Timer {
+        self.timeouts.limit = Some(limit);
+        self
+    }
+
+    /// Calls `hook` with every call of the callback that runs past the
+    /// [`execution_timeout`](Timer::execution_timeout).
+    ///
+    /// The hook of an async timer runs on its task, after the future was
+    /// dropped. The hook of a sync timer runs on the watchdog thread, right
+    /// when the deadline passes, while the callback is still running.
+    pub fn on_timeout<F>(mut self, hook: F) -> Timer
+    where
+        F: FnMut(&TimedOut) + Send + 'static,
+    {
+        self.timeouts.hook = Some(Box::new(hook));
+        self
+    }
+
+    /// Reads the time from `clock` and sleeps on it, instead of the
+    /// [`SystemClock`].
+    ///
+    /// Tests can pass a [`ManualClock`](crate::ManualClock) to run a timer
+    /// through days of simulated time in milliseconds, see [`Clock`].
+    pub fn clock<C: Clock>(mut self, clock: C) -> Timer {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Returns a [`Canceller`] that stops the timer from another thread, once
+    /// it was started with [`run`](Timer::run) or [`spawn`](Timer::spawn).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use zila::Timer;
+    ///
+    /// let timer = Timer::interval(Duration::from_millis(10));
+    /// let canceller = timer.canceller();
+    /// std::thread::spawn(move || {
+    ///     std::thread::sleep(Duration::from_millis(50));
+    ///     canceller.cancel();
+    /// });
+    /// timer.run(|_| println!("Hi"));
+    /// ```
+    pub fn canceller(&self) -> Canceller {
+        Canceller::new(self.token.clone())
+    }
+
+    /// Runs the timer on the current thread, blocking it until the callback
+    /// returns [`ControlFlow::Break`], the schedule ends or the timer is
+    /// cancelled by its [`Canceller`].
+    ///
+    /// Returns the value the timer ended with, see [`Flow`]. A cancelled
+    /// timer returns like one whose schedule ended.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::ops::ControlFlow;
+    /// use std::time::Duration;
+    /// use zila::Timer;
+    ///
+    /// let lateness = Timer::interval(Duration::from_millis(10))
+    ///     .fixed_rate()
+    ///     .run(|tick| match tick.sequence() {
+    ///         3 => ControlFlow::Break(tick.lateness()),
+    ///         _ => ControlFlow::Continue(()),
+    ///     });
+    /// assert!(lateness.is_some());
+    /// ```
+    pub fn run<F, R>(self, callback: F) -> R::Output
+    where
+        F: FnMut(Tick) -> R,
+        R: Flow,
+    {
+        let token = self.token.clone();
+        token.bind(thread::current());
+        self.blocking()
+            .run(&token, callback)
+            .unwrap_or_else(R::exhausted)
+    }
+
+    /// Starts the timer on a background thread named after the function that
+    /// created it, like `zila-set-interval` or `zila-call-every-hour`.
+    ///
+    /// The timer stops when the callback returns [`ControlFlow::Break`], see
+    /// [`Flow`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use std::time::Duration;
+    /// use zila::Timer;
+    ///
+    /// let mut samples = Vec::new();
+    /// Timer::interval(Duration::from_secs(10))
+    ///     .fixed_rate()
+    ///     .spawn(move |_| {
+    ///         samples.push(std::time::Instant::now());
+    ///     })
+    ///     .join()
+    ///     .unwrap();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the OS fails to create a thread.
+    pub fn spawn<F, R>(self, callback: F) -> TimerHandle<R::Output>
+    where
+        F: FnMut(Tick) -> R + Send + 'static,
+        R: Flow,
+    {
+        let token = self.token.clone();
+        // read the clock before spawning, the timer starts now
+        let blocking = self.blocking();
+        handle::spawn(blocking.name, token, move |token| {
+            blocking.run(token, callback)
+        })
+    }
+
+    /// Reads the clock and takes the parts a timer running on a thread needs.
+    fn blocking(self) -> Blocking {
+        let Timer {
+            name,
+            kind,
+            missed_tick_behavior,
+            on_clock_jump,
+            panics,
+            timeouts,
+            clock,
+            ..
+        } = self;
+        Blocking {
+            name,
+            detector: Detector::new(clock.instant(), clock.now()),
+            ticker: Ticker::new(kind, missed_tick_behavior, &*clock),
+            on_clock_jump,
+            panics,
+            timeouts,
+            clock,
+        }
+    }
+
+    /// Runs the timer on the current task, the future completes when the
+    /// callback returns [`ControlFlow::Break`] or the schedule ends.
+    ///
+    /// Unlike [`spawn_async`](Timer::spawn_async), neither the callback nor
+    /// its futures have to be `Send`. Every future is awaited before the
+    /// timer waits for the next tick, the [`OverlapPolicy`] doesn't apply.
+    /// Dropping the future stops the timer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::ops::ControlFlow;
+    /// use std::rc::Rc;
+    /// use std::time::Duration;
+    /// use zila::Timer;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let name = Rc::new("zila");
+    /// Timer::interval(Duration::from_millis(10))
+    ///     .run_async(|tick| {
+    ///         let name = name.clone();
+    ///         async move {
+    ///             println!("{} ticked", name);
+    ///             match tick.sequence() {
+    ///                 2 => ControlFlow::Break(()),
+    ///                 _ => ControlFlow::Continue(()),
+    ///             }
+    ///         }
+    ///     })
+    ///     .await;
+    /// # }
+    /// ```
+    pub async fn run_async<F, Fut, R>(self, callback: F) -> R::Output
+    where
+        F: FnMut(Tick) -> Fut,
+        Fut: Future<Output = R>,
+        R: Flow,
+    {
+        let Timer {
+            kind,
+            missed_tick_behavior,
+            on_clock_jump,
+            panics,
+            timeouts,
+            clock,
+            ..
+        } = self;
+        let mut ticks = Ticks::new(kind, missed_tick_behavior, on_clock_jump, clock.clone());
+        let mut runner = Runner::new(callback, OverlapPolicy::Serial, panics, timeouts, clock);
+        while let Some(tick) = future::poll_fn(|cx| ticks.poll_tick(cx)).await {
+            if let ControlFlow::Break(output) = runner.serial(tick).await {
+                return output;
+            }
+        }
+        R::exhausted()
+    }
+
+    /// Starts the timer on the current tokio runtime.
+    ///
+    /// Intervals measure their deadlines with [`tokio::time::Instant`], so they
+    /// follow the runtime clock, even when it is paused in tests. The timer
+    /// stops when the future returns [`ControlFlow::Break`], see [`Flow`].
+    ///
+    /// Every future is awaited before the timer waits for the next tick,
+    /// unless an [`OverlapPolicy`] runs them on tasks of their own.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use std::time::Duration;
+    /// use zila::Timer;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// Timer::interval(Duration::from_secs(10))
+    ///     .fixed_rate()
+    ///     .spawn_async(|tick| async move {
+    ///         println!("skipped {} ticks", tick.skipped());
+    ///     })
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside of a tokio runtime.
+    pub fn spawn_async<F, Fut, R>(self, callback: F) -> TaskHandle<R::Output>
+    where
+        F: FnMut(Tick) -> Fut + Send + 'static,
+        Fut: Future<Output = R> + Send + 'static,
+        R: Flow,
+    {
+        let Timer {
+            kind,
+            missed_tick_behavior,
+            overlap_policy,
+            mut on_clock_jump,
+            panics,
+            timeouts,
+            clock,
+            ..
+        } = self;
+        let mut detector = Detector::new(clock.instant(), clock.now());
+        let mut ticker = Ticker::new(kind, missed_tick_behavior, &*clock);
+        handle::spawn_async(async move {
+            let mut runner = Runner::new(callback, overlap_policy, panics, timeouts, clock.clone());
+            let clock = &*clock;
+            let ended = async {
+                'ticks: while let Some((deadline, pending)) = ticker.next(clock) {
+                    match deadline {
+                        Deadline::At(instant) => {
+                            runner.during(sleep_until_async(clock, instant)).await?
+                        }
+                        Deadline::Never => runner.during(future::pending()).await?,
+                        Deadline::Wall(time) => loop {
+                            if let Some(jump) = detector.check(clock.instant(), clock.now()) {
+                                if let Some(hook) = &mut on_clock_jump {
+                                    hook(jump);
+                                }
+                                if jump.is_backward() {
+                                    ticker.rearm(clock);
+                                    continue 'ticks;
+                                }
+                            }
+                            let remaining = until(clock, time);
+                            if remaining.is_zero() {
+                                break;
+                            }
+                            let deadline = clock.instant() + remaining.min(CHECK_INTERVAL);
+                            // ends early when the clock moves, to check for jumps
+                            runner.during(clock.sleep_until_async(deadline)).await?;
+                        },
+                    }
+                    let pending = ticker.catch_up(pending, clock);
+                    runner.tick(pending.fire(clock, runner.sequence)).await?;
+                }
+                ControlFlow::Continue(())
+            }
+            .await;
+            match ended {
+                ControlFlow::Break(output) => output,
+                ControlFlow::Continue(()) => runner.finish().await,
+            }
+        })
+    }
+
+    /// Runs the timer on the current thread, like [`run`](Timer::run), with a
+    /// callback that can fail.
+    ///
+    /// What happens to an error is decided by `policy`. When the policy stops
+    /// the timer, it returns `Err(error)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use zila::{ErrorPolicy, Timer};
+    ///
+    /// Timer::every_minute()
+    ///     .try_run(|_| std::fs::remove_file("lock"), ErrorPolicy::Log)
+    ///     .unwrap();
+    /// ```
+    pub fn try_run<F, E>(self, mut callback: F, mut policy: ErrorPolicy<E>) -> Result<(), E>
+    where
+        F: FnMut(Tick) -> Result<(), E>,
+        E: fmt::Display + Send + 'static,
+    {
+        self.run(move |tick| policy.check(callback(tick)))
+    }
+
+    /// Runs the timer on the current task, like
+    /// [`run_async`](Timer::run_async), with a callback that can fail.
+    ///
+    /// What happens to an error is decided by `policy`. When the policy stops
+    /// the timer, it returns `Err(error)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use zila::{ErrorPolicy, Timer};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// Timer::every_hour()
+    ///     .try_run_async(|_| async { std::fs::remove_dir_all("tmp") }, ErrorPolicy::Log)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub async fn try_run_async<F, Fut, E>(
+        self,
+        mut callback: F,
+        policy: ErrorPolicy<E>,
+    ) -> Result<(), E>
+    where
+        F: FnMut(Tick) -> Fut,
+        Fut: Future<Output = Result<(), E>>,
+        E: fmt::Display + Send + 'static,
+    {
+        let policy = RefCell::new(policy);
+        let policy = &policy;
+        self.run_async(move |tick| {
+            let result = callback(tick);
+            async move {
+                let result = result.await;
+                policy.borrow_mut().check(result)
+            }
+        })
+        .await
+    }
+
+    /// Starts the timer on a background thread, like [`spawn`](Timer::spawn),
+    /// with a callback that can fail.
+    ///
+    /// What happens to an error is decided by `policy`. When the policy stops
+    /// the timer, [`TimerHandle::join`] returns `Ok(Err(error))`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use zila::{ErrorPolicy, Timer};
+    ///
+    /// Timer::every_minute()
+    ///     .try_spawn(|_| std::fs::remove_file("lock"), ErrorPolicy::Log)
+    ///     .join()
+    ///     .unwrap()
+    ///     .unwrap();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the OS fails to create a thread.
+    pub fn try_spawn<F, E>(
+        self,
+        mut callback: F,
+        mut policy: ErrorPolicy<E>,
+    ) -> TimerHandle<Result<(), E>>
+    where
+        F: FnMut(Tick) -> Result<(), E> + Send + 'static,
+        E: fmt::Display + Send + 'static,
+    {
+        self.spawn(move |tick| policy.check(callback(tick)))
+    }
+
+    /// Starts the timer on the current tokio runtime, like
+    /// [`spawn_async`](Timer::spawn_async), with a callback that can fail.
+    ///
+    /// What happens to an error is decided by `policy`. When the policy stops
+    /// the timer, awaiting the [`TaskHandle`] returns `Ok(Err(error))`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use zila::{ErrorPolicy, Timer};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let policy = ErrorPolicy::on_error(|error: &std::io::Error| {
+    ///     eprintln!("cleanup failed: {}", error);
+    /// });
+    /// Timer::every_hour()
+    ///     .try_spawn_async(|_| async { std::fs::remove_dir_all("tmp") }, policy)
+    ///     .await
+    ///     .unwrap()
+    ///     .unwrap();
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside of a tokio runtime.
+    pub fn try_spawn_async<F, Fut, E>(
+        self,
+        mut callback: F,
+        policy: ErrorPolicy<E>,
+    ) -> TaskHandle<Result<(), E>>
+    where
+        F: FnMut(Tick) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<(), E>> + Send + 'static,
+        E: fmt::Display + Send + 'static,
+    {
+        let policy = Arc::new(Mutex::new(policy));
+        self.spawn_async(move |tick| {
+            let result = callback(tick);
+            let policy = policy.clone();
+            async move {
+                let result = result.await;
+                let mut policy = policy.lock().unwrap_or_else(PoisonError::into_inner);
+                policy.check(result)
+            }
+        })
+    }
+
+    /// Turns the timer into a [`Stream`] that yields a [`Tick`] every time
+    /// the timer would call its callback.
+    ///
+    /// Nothing runs in the background, the stream waits for the next tick
+    /// when it is polled and stops when it is dropped, so it composes with
+    /// `tokio::select!` and stream combinators. The stream ends with the
+    /// schedule. A consumer that falls behind misses ticks like a slow
+    /// callback would, see [`MissedTickBehavior`]. The settings of the
+    /// callback, like the [`OverlapPolicy`] or the panic policy, don't apply.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_util::StreamExt;
+    /// use std::time::Duration;
+    /// use zila::Timer;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let ticks = Timer::interval(Duration::from_millis(10))
+    ///     .fixed_rate()
+    ///     .stream();
+    /// let sequences: Vec<u64> = ticks.take(3).map(|tick| tick.sequence()).collect().await;
+    /// assert_eq!(sequences, [0, 1, 2]);
+    /// # }
+    /// ```
+    ///
+    /// *This function requires the following crate features to be activated: `ticks`*
+    #[cfg(feature = "ticks")]
+    pub fn stream(self) -> Ticks {
+        let Timer {
+            kind,
+            missed_tick_behavior,
+            on_clock_jump,
+            clock,
+            ..
+        } = self;
+        Ticks::new(kind, missed_tick_behavior, on_clock_jump, clock)
+    }
+}
+
+/// A timer that runs on a thread, built before the thread starts.
+struct Blocking {
+    name: &'static str,
+    ticker: Ticker,
+    detector: Detector,
+    on_clock_jump: Option<JumpHook>,
+    panics: Panics,
+    timeouts: Timeouts,
+    clock: Arc<dyn Clock>,
+}
+
+impl Blocking {
+    /// Runs the timer on the current thread, returns `None` when it stops
+    /// because of a cancellation.
+    fn run<F, R>(mut self, token: &Token, mut callback: F) -> Option<R::Output>
+    where
+        F: FnMut(Tick) -> R,
+        R: Flow,
+    {
+        let watchdog = Watchdog::spawn(self.timeouts, self.clock.clone());
+        let clock = &*self.clock;
+        let mut sequence = 0;
+        'ticks: while let Some((deadline, pending)) = self.ticker.next(clock) {
+            let ready = match deadline {
+                Deadline::At(instant) => sleep_until(clock, token, instant),
+                Deadline::Never => sleep_until(clock, token, far_future(clock)),
+                Deadline::Wall(time) => loop {
+                    if let Some(jump) = self.detector.check(clock.instant(), clock.now()) {
+                        if let Some(hook) = &mut self.on_clock_jump {
+                            hook(jump);
+                        }
+                        if jump.is_backward() {
+                            self.ticker.rearm(clock);
+                            continue 'ticks;
+                        }
+                    }
+                    let remaining = until(clock, time);
+                    if remaining.is_zero() {
+                        break true;
+                    }
+                    let deadline = clock.instant() + remaining.min(CHECK_INTERVAL);
+                    if !sleep_until(clock, token, deadline) {
+                        break false;
+                    }
+                },
+            };
+            if !ready {
+                return None;
+            }
+            let mut tick = self.ticker.catch_up(pending, clock).fire(clock, sequence);
+            if let Some(watchdog) = &watchdog {
+                watchdog.arm(&mut tick);
+            }
+            let result = panic::catch_unwind(AssertUnwindSafe(|| callback(tick)));
+            if let Some(watchdog) = &watchdog {
+                watchdog.disarm();
+            }
+            match result {
+                Ok(flow) => {
+                    if let ControlFlow::Break(output) = flow.branch() {
+                        return Some(output);
+                    }
+                }
+                Err(payload) => {
+                    if !self.panics.caught(payload) {
+                        break;
+                    }
+                }
+            }
+            sequence += 1;
+        }
+        Some(R::exhausted())
+    }
+}
+
+/// A [`Stream`] of the ticks of a timer, created by [`Timer::stream`] or by
+/// the functions of the [`ticks`](crate::ticks) module.
+///
+/// *This type requires the following crate features to be activated: `ticks`*
+#[must_use = "streams do nothing unless polled"]
+pub struct Ticks {
+    ticker: Ticker,
+    detector: Detector,
+    on_clock_jump: Option<JumpHook>,
+    clock: Arc<dyn Clock>,
+    /// The next deadline and the tick that is due then.
+    next: Option<(Deadline, Pending)>,
+    /// The sleep until the instant the stream wakes up next.
+    sleep: Option<(Instant, Sleep)>,
+    sequence: u64,
+}
+
+impl Ticks {
+    fn new(
+        kind: Kind,
+        missed_tick_behavior: MissedTickBehavior,
+        on_clock_jump: Option<JumpHook>,
+        clock: Arc<dyn Clock>,
+    ) -> Ticks {
+        Ticks {
+            detector: Detector::new(clock.instant(), clock.now()),
+            ticker: Ticker::new(kind, missed_tick_behavior, &*clock),
+            on_clock_jump,
+            clock,
+            next: None,
+            sleep: None,
+            sequence: 0,
+        }
+    }
+
+    /// Waits for the next tick, `None` once the schedule ended.
+    fn poll_tick(&mut self, cx: &mut Context<'_>) -> Poll<Option<Tick>> {
+        let clock = &*self.clock;
+        loop {
+            let deadline = match &self.next {
+                Some((deadline, _)) => deadline,
+                None => match self.ticker.next(clock) {
+                    Some(next) => &self.next.insert(next).0,
+                    None => return Poll::Ready(None),
+                },
+            };
+            // the instant to wake up at, `None` once the tick is due
+            let wake = match *deadline {
+                Deadline::At(instant) => (clock.instant() < instant).then_some(instant),
+                Deadline::Never => Some(far_future(clock)),
+                Deadline::Wall(time) => {
+                    if let Some(jump) = self.detector.check(clock.instant(), clock.now()) {
+                        if let Some(hook) = &mut self.on_clock_jump {
+                            hook(jump);
+                        }
+                        if jump.is_backward() {
+                            self.ticker.rearm(clock);
+                            self.next = None;
+                            continue;
+                        }
+                    }
+                    let remaining = until(clock, time);
+                    // wakes up early, to check for jumps
+                    (!remaining.is_zero()).then(|| clock.instant() + remaining.min(CHECK_INTERVAL))
+                }
+            };
+            let Some(wake) = wake else {
+                self.sleep = None;
+                let tick = self.next.take().map(|(_, pending)| {
+                    self.ticker
+                        .catch_up(pending, clock)
+                        .fire(clock, self.sequence)
+                });
+                self.sequence += 1;
+                return Poll::Ready(tick);
+            };
+            let sleep = match &mut self.sleep {
+                Some((at, sleep)) if *at == wake => sleep,
+                slot => &mut slot.insert((wake, clock.sleep_until_async(wake))).1,
+            };
+            if sleep.as_mut().poll(cx).is_pending() {
+                return Poll::Pending;
+            }
+            // the sleep may end early, the deadline is checked again
+            self.sleep = None;
+        }
+    }
+}
+
+#[cfg(feature = "ticks")]
+impl Stream for Ticks {
+    type Item = Tick;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Tick>> {
+        self.get_mut().poll_tick(cx)
+    }
+}
+
+impl fmt::Debug for Ticks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Ticks")
+            .field("sequence", &self.sequence)
+            .finish_non_exhaustive()
+    }
+}
+
+impl fmt::Debug for Timer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("Timer");
+        debug.field("name", &self.name);
+        match &self.kind {
+            Kind::Timeout(Some(delay)) => {
+                debug.field("delay", delay);
+            }
+            Kind::Interval { period, rate } => {
+                debug.field("period", period).field("rate", rate);
+            }
+            _ => {}
+        }
+        debug
+            .field("missed_tick_behavior", &self.missed_tick_behavior)
+            .field("overlap_policy", &self.overlap_policy)
+            .field("on_clock_jump", &self.on_clock_jump.is_some())
+            .field("panic_policy", &self.panics.policy)
+            .field("on_panic", &self.panics.hook.is_some())
+            .field("execution_timeout", &self.timeouts.limit)
+            .field("on_timeout", &self.timeouts.hook.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+/// The panic policy and hook of a timer.
+#[derive(Default)]
+struct Panics {
+    policy: PanicPolicy,
+    hook: Option<PanicHook>,
+}
+
+impl Panics {
+    /// Reports a panic of the callback, returns `false` if the timer has to
+    /// stop.
+    fn caught(&mut self, payload: Box<dyn Any + Send + 'static>) -> bool {
+        let panic = CaughtPanic::new(payload);
+        if let Some(hook) = &mut self.hook {
+            hook(&panic);
+        }
+        match self.policy {
+            PanicPolicy::Restart => true,
+            PanicPolicy::Stop => false,
+            PanicPolicy::Propagate => panic::resume_unwind(panic.into_payload()),
+        }
+    }
+}
+
+/// The execution timeout of a timer and its hook.
+#[derive(Default)]
+struct Timeouts {
+    limit: Option<Duration>,
+    hook: Option<TimeoutHook>,
+}
+
+impl Timeouts {
+    /// Reports a call that ran past the execution timeout.
+    fn report(&mut self, timed_out: &TimedOut) {
+        if let Some(hook) = &mut self.hook {
+            hook(timed_out);
+        }
+    }
+}
+
+/// Watches the calls of a sync timer from a thread of its own and reports
+/// the ones that run past the execution timeout.
+struct Watchdog {
+    limit: Duration,
+    watch: Arc<Mutex<Watch>>,
+    clock: Arc<dyn Clock>,
+    thread: Thread,
+}
+
+#[derive(Default)]
+struct Watch {
+    /// The deadline of the running call and the report for it.
+    call: Option<(Instant, TimedOut)>,
+    /// Set once the timer ended.
+    done: bool,
+}
+
+impl Watchdog {
+    /// Starts the watchdog thread, `None` without an execution timeout.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the OS fails to create a thread.
+    fn spawn(mut timeouts: Timeouts, clock: Arc<dyn Clock>) -> Option<Watchdog> {
+        let limit = timeouts.limit?;
+        let watch = Arc::new(Mutex::new(Watch::default()));
+        let thread = thread::Builder::new()
+            .name("zila-watchdog".to_owned())
+            .spawn({
+                let watch = watch.clone();
+                let clock = clock.clone();
+                move || {
+                    while let Some(timed_out) = wait(&watch, &*clock) {
+                        timed_out.tick.token.cancel();
+                        timeouts.report(&timed_out);
+                    }
+                }
+            })
+            .expect("failed to spawn thread");
+        Some(Watchdog {
+            limit,
+            watch,
+            clock,
+            thread: thread.thread().clone(),
+        })
+    }
+
+    /// Starts watching the call with `tick` and gives it a token to cancel.
+    fn arm(&self, tick: &mut Tick) {
+        tick.token = CancellationToken::new();
+        // a call without a deadline that fits never times out
+        let Some(deadline) = self.clock.instant().checked_add(self.limit) else {
+            return;
+        };
+        let timed_out = TimedOut {
+            tick: tick.clone(),
+            limit: self.limit,
+        };
+        lock(&self.watch).call = Some((deadline, timed_out));
+        self.thread.unpark();
+    }
+
+    /// Stops watching the call once it returned.
+    fn disarm(&self) {
+        lock(&self.watch).call = None;
+    }
+}
+
+impl Drop for Watchdog {
+    fn drop(&mut self) {
+        lock(&self.watch).done = true;
+        self.thread.unpark();
+    }
+}
+
+/// Blocks the watchdog thread until a call runs past its deadline, `None`
+/// once the timer ended.
+fn wait(watch: &Mutex<Watch>, clock: &dyn Clock) -> Option<TimedOut> {
+    loop {
+        let mut guard = lock(watch);
+        if guard.done {
+            return None;
+        }
+        let Some((deadline, _)) = &guard.call else {
+            drop(guard);
+            // woken by the next call or the end of the timer
+            thread::park();
+            continue;
+        };
+        let deadline = *deadline;
+        if clock.instant() >= deadline {
+            return guard.call.take().map(|(_, timed_out)| timed_out);
+        }
+        drop(guard);
+        clock.sleep_until(deadline);
+    }
+}
+
+fn lock(watch: &Mutex<Watch>) -> MutexGuard<'_, Watch> {
+    watch.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Calls the callback of an async timer according to its [`OverlapPolicy`].
+struct Runner<F, R> {
+    callback: F,
+    policy: OverlapPolicy,
+    panics: Panics,
+    timeouts: Timeouts,
+    clock: Arc<dyn Clock>,
+    /// The number of times the callback was called.
+    sequence: u64,
+    /// The callbacks that run on tasks of their own, empty when serial.
+    tasks: JoinSet<Result<R, TimedOut>>,
+    /// The ticks waiting for the running callback, with [`OverlapPolicy::Queue`].
+    queue: VecDeque<Tick>,
+    /// The ticks that were dropped since the last call.
+    skipped: u64,
+}
+
+impl<F, Fut, R> Runner<F, R>
+where
+    F: FnMut(Tick) -> Fut,
+    Fut: Future<Output = R>,
+    R: Flow,
+{
+    fn new(
+        callback: F,
+        policy: OverlapPolicy,
+        panics: Panics,
+        timeouts: Timeouts,
+        clock: Arc<dyn Clock>,
+    ) -> Runner<F, R> {
+        Runner {
+            callback,
+            policy,
+            panics,
+            timeouts,
+            clock,
+            sequence: 0,
+            tasks: JoinSet::new(),
+            queue: VecDeque::new(),
+            skipped: 0,
+        }
+    }
+
+    /// Calls the callback with `tick` and awaits it, breaks with the output
+    /// of the timer if it has to stop.
+    async fn serial(&mut self, tick: Tick) -> ControlFlow<R::Output> {
+        let result = catch_unwind_async(|| self.call(tick)).await;
+        self.returned(result)
+    }
+
+    /// Calls the callback with `tick`, the future it returns gives up once
+    /// the call runs past the execution timeout.
+    fn call(&mut self, mut tick: Tick) -> impl Future<Output = Result<R, TimedOut>> {
+        tick.sequence = self.sequence;
+        self.sequence += 1;
+        let limit = self.timeouts.limit;
+        let deadline = limit.and_then(|limit| self.clock.instant().checked_add(limit));
+        if deadline.is_some() {
+            tick.token = CancellationToken::new();
+        }
+        let future = (self.callback)(tick.clone());
+        let clock = self.clock.clone();
+        async move {
+            let (Some(limit), Some(deadline)) = (limit, deadline) else {
+                return Ok(future.await);
+            };
+            let mut future = pin!(future);
+            let mut sleep = pin!(sleep_until_async(&*clock, deadline));
+            // `None` once the deadline passed
+            let output = future::poll_fn(|cx| match future.as_mut().poll(cx) {
+                Poll::Ready(output) => Poll::Ready(Some(output)),
+                Poll::Pending => sleep.as_mut().poll(cx).map(|()| None),
+            });
+            match output.await {
+                Some(output) => Ok(output),
+                None => {
+                    tick.token.cancel();
+                    Err(TimedOut { tick, limit })
+                }
+            }
+        }
+    }
+
+    /// Handles the result of a callback.
+    fn returned(&mut self, result: thread::Result<Result<R, TimedOut>>) -> ControlFlow<R::Output> {
+        match result {
+            Ok(Ok(flow)) => flow.branch(),
+            Ok(Err(timed_out)) => {
+                self.timeouts.report(&timed_out);
+                ControlFlow::Continue(())
+            }
+            Err(payload) => {
+                if self.panics.caught(payload) {
+                    ControlFlow::Continue(())
+                } else {
+                    ControlFlow::Break(R::exhausted())
+                }
+            }
+        }
+    }
+}
+
+impl<F, Fut, R> Runner<F, R>
+where
+    F: FnMut(Tick) -> Fut + 'static,
+    Fut: Future<Output = R> + Send + 'static,
+    R: Flow,
+{
+    /// Handles a tick that is due, breaks with the output of the timer if it
+    /// has to stop.
+    async fn tick(&mut self, mut tick: Tick) -> ControlFlow<R::Output> {
+        let limit = match self.policy {
+            OverlapPolicy::Serial => return self.serial(tick).await,
+            OverlapPolicy::SkipIfRunning | OverlapPolicy::Queue(_) => 1,
+            OverlapPolicy::Concurrent(limit) => limit.max(1),
+        };
+        while let Some(result) = self.tasks.try_join_next() {
+            self.finished(result)?;
+        }
+        let queued = match self.policy {
+            OverlapPolicy::Queue(max) => self.queue.len() < max,
+            _ => false,
+        };
+        if self.tasks.len() >= limit && !queued {
+            self.skipped += tick.skipped + 1;
+            return ControlFlow::Continue(());
+        }
+        tick.skipped += std::mem::take(&mut self.skipped);
+        if self.tasks.len() < limit {
+            return self.start(tick);
+        }
+        self.queue.push_back(tick);
+        ControlFlow::Continue(())
+    }
+
+    /// Awaits `future` and handles the callbacks that finish in the meantime.
+    async fn during<T>(&mut self, future: impl Future<Output = T>) -> ControlFlow<R::Output, T> {
+        let mut future = pin!(future);
+        loop {
+            let finished = future::poll_fn(|cx| {
+                if let Poll::Ready(value) = future.as_mut().poll(cx) {
+                    return Poll::Ready(Ok(value));
+                }
+                match self.tasks.poll_join_next(cx) {
+                    Poll::Ready(Some(result)) => Poll::Ready(Err(result)),
+                    // woken by `future` once there are no tasks
+                    Poll::Ready(None) | Poll::Pending => Poll::Pending,
+                }
+            })
+            .await;
+            match finished {
+                Ok(value) => return ControlFlow::Continue(value),
+                Err(result) => self.finished(result)?,
+            }
+        }
+    }
+
+    /// Waits for the callbacks that are still running or queued after the
+    /// schedule ended.
+    async fn finish(&mut self) -> R::Output {
+        while let Some(result) = self.tasks.join_next().await {
+            if let ControlFlow::Break(output) = self.finished(result) {
+                return output;
+            }
+        }
+        R::exhausted()
+    }
+
+    /// Calls the callback and spawns the future it returns.
+    fn start(&mut self, tick: Tick) -> ControlFlow<R::Output> {
+        match panic::catch_unwind(AssertUnwindSafe(|| self.call(tick))) {
+            Ok(future) => {
+                self.tasks.spawn(future);
+                ControlFlow::Continue(())
+            }
+            Err(payload) => self.returned(Err(payload)),
+        }
+    }
+
+    /// Handles a callback that finished on its own task and starts the next
+    /// queued one.
+    fn finished(
+        &mut self,
+        result: Result<Result<R, TimedOut>, tokio::task::JoinError>,
+    ) -> ControlFlow<R::Output> {
+        match result {
+            Ok(flow) => self.returned(Ok(flow))?,
+            Err(error) if error.is_panic() => self.returned(Err(error.into_panic()))?,
+            // the tasks are only aborted when the runner is dropped
+            Err(_) => {}
+        }
+        match self.queue.pop_front() {
+            Some(tick) => self.start(tick),
+            None => ControlFlow::Continue(()),
+        }
+    }
+}
+
+/// Calls `callback` and awaits the future it returns, catching the panics of
+/// both.
+async fn catch_unwind_async<F, Fut>(callback: F) -> thread::Result<Fut::Output>
+where
+    F: FnOnce() -> Fut,
+    Fut: Future,
+{
+    let mut future = pin!(panic::catch_unwind(AssertUnwindSafe(callback))?);
+    future::poll_fn(
+        |cx| match panic::catch_unwind(AssertUnwindSafe(|| future.as_mut().poll(cx))) {
+            Ok(poll) => poll.map(Ok),
+            Err(payload) => Poll::Ready(Err(payload)),
+        },
+    )
+    .await
+}
+
+fn until(clock: &dyn Clock, time: DateTime<Utc>) -> Duration {
+    (time - clock.now()).to_std().unwrap_or(Duration::ZERO)
+}
+
+/// An instant far enough in the future to never be reached.
+fn far_future(clock: &dyn Clock) -> Instant {
+    clock.instant() + Duration::from_secs(60 * 60 * 24 * 365 * 30)
+}
+
+/// Sleeps on `clock` until `deadline`, returns `false` if the token got
+/// cancelled in the meantime.
+fn sleep_until(clock: &dyn Clock, token: &Token, deadline: Instant) -> bool {
+    loop {
+        if token.is_cancelled() {
+            return false;
+        }
+        if clock.instant() >= deadline {
+            return true;
+        }
+        clock.sleep_until(deadline);
+    }
+}
+
+/// Sleeps on `clock` until `deadline`, [`Clock::sleep_until_async`] may end
+/// early.
+async fn sleep_until_async(clock: &dyn Clock, deadline: Instant) {
+    while clock.instant() < deadline {
+        clock.sleep_until_async(deadline).await;
+    }
+}
+
+/// When the next tick is due.
+enum Deadline {
+    /// At an instant of the monotonic clock.
+    At(Instant),
+    /// At a wall time, used by calendar timers.
+    Wall(DateTime<Utc>),
+    /// Too far in the future to be represented.
+    Never,
+}
+
+/// A tick waiting for its deadline.
+struct Pending {
+    scheduled: Scheduled,
+    skipped: u64,
+}
+
+/// The instant a tick was due.
+enum Scheduled {
+    Instant(Instant),
+    Wall(DateTime<Utc>),
+}
+
+impl Pending {
+    fn new(scheduled: Scheduled, skipped: u64) -> Pending {
+        Pending { scheduled, skipped }
+    }
+
+    /// Returns the tick to pass to the callback, right after the deadline.
+    fn fire(self, clock: &dyn Clock, sequence: u64) -> Tick {
+        let actual = clock.now();
+        let scheduled = match self.scheduled {
+            Scheduled::Wall(time) => time,
+            Scheduled::Instant(deadline) => {
+                let late = clock.instant().saturating_duration_since(deadline);
+                actual - chrono::Duration::from_std(late).unwrap_or(chrono::Duration::MAX)
+            }
+        };
+        Tick {
+            sequence,
+            scheduled,
+            actual,
+            lateness: (actual - scheduled).to_std().unwrap_or(Duration::ZERO),
+            skipped: self.skipped,
+            token: CancellationToken::default(),
+        }
+    }
+}
+
+/// Computes the deadlines of a running timer.
+struct Ticker {
+    kind: Kind,
+    missed_tick_behavior: MissedTickBehavior,
+    /// The last deadline of an interval.
+    last_instant: Instant,
+    /// The last deadline of a calendar timer.
+    last_wall: DateTime<Utc>,
+}
+
+impl Ticker {
+    fn new(kind: Kind, missed_tick_behavior: MissedTickBehavior, clock: &dyn Clock) -> Ticker {
+        Ticker {
+            kind,
+            missed_tick_behavior,
+            last_instant: clock.instant(),
+            // the first boundary may be right now
+            last_wall: clock.now() - chrono::Duration::nanoseconds(1),
+        }
+    }
+
+    /// Forgets the boundaries of a calendar timer that already passed, after
+    /// the wall clock jumped backward.
+    fn rearm(&mut self, clock: &dyn Clock) {
+        self.last_wall = clock.now() - chrono::Duration::nanoseconds(1);
+    }
+
+    /// Returns the next deadline and the tick that is due then, `None` once
+    /// the timer is done.
+    fn next(&mut self, clock: &dyn Clock) -> Option<(Deadline, Pending)> {
+        let now = clock.instant();
+        let wall = clock.now();
+        let behavior = self.missed_tick_behavior;
+        let (deadline, pending) = match &mut self.kind {
+            Kind::Timeout(delay) => {
+                let delay = delay.take()?;
+                // measured from the start of the timer
+                return Some(match self.last_instant.checked_add(delay) {
+                    Some(deadline) => (
+                        Deadline::At(deadline),
+                        Pending::new(Scheduled::Instant(deadline), 0),
+                    ),
+                    None => (Deadline::Never, Pending::new(Scheduled::Wall(wall), 0)),
+                });
+            }
+            Kind::Interval { period, rate } => {
+                let period = *period;
+                if *rate == Rate::FixedDelay || period.is_zero() {
+                    return Some(match now.checked_add(period) {
+                        Some(deadline) => (
+                            Deadline::At(deadline),
+                            Pending::new(Scheduled::Instant(deadline), 0),
+                        ),
+                        None => (Deadline::Never, Pending::new(Scheduled::Wall(wall), 0)),
+                    });
+                }
+                let Some(deadline) = self.last_instant.checked_add(period) else {
+                    return Some((Deadline::Never, Pending::new(Scheduled::Wall(wall), 0)));
+                };
+                if deadline > now || behavior == MissedTickBehavior::Burst {
+                    self.last_instant = deadline;
+                    (
+                        Deadline::At(deadline),
+                        Pending::new(Scheduled::Instant(deadline), 0),
+                    )
+                } else {
+                    // the number of deadlines in (last, now]
+                    let missed = (now - self.last_instant).as_nanos() / period.as_nanos();
+                    let behind = Duration::from_nanos((missed * period.as_nanos()) as u64);
+                    let latest = self.last_instant + behind;
+                    self.last_instant = match behavior {
+                        MissedTickBehavior::Delay => now,
+                        _ => latest,
+                    };
+                    (
+                        Deadline::At(now),
+                        Pending::new(Scheduled::Instant(latest), missed as u64 - 1),
+                    )
+                }
+            }
+            Kind::Calendar(next) => {
+                let deadline = next(&self.last_wall)?;
+                if deadline > wall || behavior == MissedTickBehavior::Burst {
+                    self.last_wall = deadline;
+                    (
+                        Deadline::Wall(deadline),
+                        Pending::new(Scheduled::Wall(deadline), 0),
+                    )
+                } else {
+                    let mut latest = deadline;
+                    let mut missed = 1;
+                    while let Some(next) = next(&latest).filter(|next| *next <= wall) {
+                        latest = next;
+                        missed += 1;
+                    }
+                    self.last_wall = latest;
+                    (
+                        Deadline::At(now),
+                        Pending::new(Scheduled::Wall(latest), missed - 1),
+                    )
+                }
+            }
+        };
+        Some((deadline, pending))
+    }
+
+    /// Adds the ticks that passed while the timer slept to `pending`, right
+    /// before it fires. The process may have been suspended, or the clock
+    /// jumped, over more than one deadline.
+    fn catch_up(&mut self, mut pending: Pending, clock: &dyn Clock) -> Pending {
+        let behavior = self.missed_tick_behavior;
+        if behavior == MissedTickBehavior::Burst {
+            return pending;
+        }
+        match (&self.kind, &mut pending.scheduled) {
+            (Kind::Interval { period, rate }, Scheduled::Instant(deadline))
+                if *rate == Rate::FixedRate && !period.is_zero() =>
+            {
+                let now = clock.instant();
+                // the number of deadlines in (deadline, now]
+                let missed =
+                    now.saturating_duration_since(*deadline).as_nanos() / period.as_nanos();
+                if missed > 0 {
+                    let behind = Duration::from_nanos((missed * period.as_nanos()) as u64);
+                    *deadline += behind;
+                    pending.skipped += missed as u64;
+                    self.last_instant = match behavior {
+                        MissedTickBehavior::Delay => now,
+                        _ => *deadline,
+                    };
+                }
+            }
+            (Kind::Calendar(next), Scheduled::Wall(latest)) => {
+                let wall = clock.now();
+                while let Some(next) = next(latest).filter(|next| *next <= wall) {
+                    *latest = next;
+                    pending.skipped += 1;
+                }
+                self.last_wall = *latest;
+            }
+            _ => {}
+        }
+        pending
+    }
+}
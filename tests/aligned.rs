@@ -0,0 +1,96 @@
+use chrono::{DateTime, TimeZone, Utc};
+use chrono_tz::Europe::Berlin;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use zila::{duration_to_next_multiple, Aligned, Clock, ManualClock, Schedule, Timer};
+
+fn utc(h: u32, m: u32, s: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2023, 5, 9, h, m, s).unwrap()
+}
+
+#[test]
+fn multiples_of_the_epoch() {
+    let every_5_minutes = Aligned::new(Duration::from_secs(5 * 60));
+    let times: Vec<_> = every_5_minutes
+        .iter_after(utc(13, 47, 12))
+        .take(3)
+        .collect();
+    assert_eq!(times, [utc(13, 50, 0), utc(13, 55, 0), utc(14, 0, 0)]);
+    assert_eq!(
+        every_5_minutes.next_after(&utc(13, 50, 0)),
+        Some(utc(13, 55, 0))
+    );
+}
+
+#[test]
+fn multiples_of_the_epoch_ignore_the_time_zone() {
+    let every_15_minutes = Aligned::new(Duration::from_secs(15 * 60));
+    let time = utc(13, 47, 12).with_timezone(&Berlin);
+    let next = every_15_minutes.next_after(&time).unwrap();
+    assert_eq!(next, utc(14, 0, 0));
+}
+
+#[test]
+fn sub_second_periods() {
+    let every_250ms = Aligned::new(Duration::from_millis(250));
+    let time = utc(13, 0, 0) + chrono::Duration::milliseconds(600);
+    let times: Vec<_> = every_250ms.iter_after(time).take(2).collect();
+    assert_eq!(
+        times,
+        [
+            utc(13, 0, 0) + chrono::Duration::milliseconds(750),
+            utc(13, 0, 1),
+        ]
+    );
+}
+
+#[test]
+fn multiples_of_midnight_restart_every_day() {
+    let every_7_minutes = Aligned::from_midnight(Duration::from_secs(7 * 60));
+    let time = Berlin.with_ymd_and_hms(2023, 5, 9, 23, 55, 0).unwrap();
+    let times: Vec<_> = every_7_minutes.iter_after(time).take(3).collect();
+    assert_eq!(
+        times,
+        [
+            // 23:55 is the last multiple of 7 minutes before midnight
+            Berlin.with_ymd_and_hms(2023, 5, 10, 0, 0, 0).unwrap(),
+            Berlin.with_ymd_and_hms(2023, 5, 10, 0, 7, 0).unwrap(),
+            Berlin.with_ymd_and_hms(2023, 5, 10, 0, 14, 0).unwrap(),
+        ]
+    );
+}
+
+#[test]
+fn duration_to_a_multiple_is_at_most_a_period() {
+    let period = Duration::from_secs(15 * 60);
+    assert!(duration_to_next_multiple(period) <= period);
+}
+
+#[test]
+#[should_panic]
+fn zero_periods_panic() {
+    Aligned::new(Duration::ZERO);
+}
+
+#[test]
+fn aligned_timers_run_on_the_marks() {
+    let clock = ManualClock::new(utc(13, 47, 12));
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let handle = Timer::aligned(Duration::from_secs(5 * 60))
+        .clock(clock.clone())
+        .spawn({
+            let calls = calls.clone();
+            let clock = clock.clone();
+            move |_| calls.lock().unwrap().push(clock.now())
+        });
+    let start = Duration::from_secs(2 * 60 + 48);
+    for (count, step) in [(1, start), (2, Duration::from_secs(5 * 60))] {
+        clock.advance(step);
+        while calls.lock().unwrap().len() < count {
+            std::thread::yield_now();
+        }
+    }
+    handle.cancel();
+    let calls = calls.lock().unwrap();
+    assert_eq!(calls[..], [utc(13, 50, 0), utc(13, 55, 0)]);
+}
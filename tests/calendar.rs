@@ -0,0 +1,250 @@
+use chrono::{DateTime, Duration, TimeZone, Weekday};
+use chrono_tz::{America::Sao_Paulo, Europe::Berlin, Tz};
+use std::time::Duration as StdDuration;
+use zila::{
+    duration_to_next, duration_to_next_month, duration_to_next_week, Daily, EveryMonth,
+    EveryQuarter, EveryWeek, EveryYear, Hourly, Schedule,
+};
+
+fn local(tz: Tz, year: i32, month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Tz> {
+    tz.with_ymd_and_hms(year, month, day, hour, minute, 0)
+        .earliest()
+        .unwrap()
+}
+
+fn minutes(minutes: u64) -> StdDuration {
+    StdDuration::from_secs(minutes * 60)
+}
+
+fn next<S: Schedule>(schedule: S, time: DateTime<Tz>) -> DateTime<Tz> {
+    schedule.next_after(&time).unwrap()
+}
+
+#[test]
+fn weeks_start_on_the_given_day() {
+    // 2023-05-10 is a Wednesday
+    let time = local(Berlin, 2023, 5, 10, 13, 45);
+    let monday = EveryWeek::starting(Weekday::Mon);
+    assert_eq!(next(monday, time), local(Berlin, 2023, 5, 15, 0, 0));
+    let sunday = EveryWeek::starting(Weekday::Sun);
+    assert_eq!(next(sunday, time), local(Berlin, 2023, 5, 14, 0, 0));
+    let thursday = EveryWeek::starting(Weekday::Thu);
+    assert_eq!(next(thursday, time), local(Berlin, 2023, 5, 11, 0, 0));
+    let wednesday = EveryWeek::starting(Weekday::Wed);
+    assert_eq!(next(wednesday, time), local(Berlin, 2023, 5, 17, 0, 0));
+}
+
+#[test]
+fn the_start_of_a_week_starts_the_next_one() {
+    let monday = local(Berlin, 2023, 5, 15, 0, 0);
+    let next = next(EveryWeek::default(), monday);
+    assert_eq!(next, local(Berlin, 2023, 5, 22, 0, 0));
+}
+
+#[test]
+fn months_have_different_lengths() {
+    let times: Vec<_> = EveryMonth
+        .iter_after(local(Berlin, 2023, 12, 15, 12, 0))
+        .take(4)
+        .collect();
+    assert_eq!(
+        times,
+        [
+            local(Berlin, 2024, 1, 1, 0, 0),
+            local(Berlin, 2024, 2, 1, 0, 0),
+            local(Berlin, 2024, 3, 1, 0, 0),
+            local(Berlin, 2024, 4, 1, 0, 0),
+        ]
+    );
+    // a leap year February, and March loses an hour to daylight saving time
+    assert_eq!(times[2] - times[1], Duration::days(29));
+    assert_eq!(times[3] - times[2], Duration::days(31) - Duration::hours(1));
+}
+
+#[test]
+fn quarters_start_in_january_april_july_and_october() {
+    let times: Vec<_> = EveryQuarter
+        .iter_after(local(Berlin, 2023, 5, 9, 13, 45))
+        .take(3)
+        .collect();
+    assert_eq!(
+        times,
+        [
+            local(Berlin, 2023, 7, 1, 0, 0),
+            local(Berlin, 2023, 10, 1, 0, 0),
+            local(Berlin, 2024, 1, 1, 0, 0),
+        ]
+    );
+}
+
+#[test]
+fn years_start_on_new_year() {
+    let time = local(Berlin, 2023, 12, 31, 23, 59);
+    assert_eq!(next(EveryYear, time), local(Berlin, 2024, 1, 1, 0, 0));
+    let time = local(Berlin, 2024, 1, 1, 0, 0);
+    assert_eq!(next(EveryYear, time), local(Berlin, 2025, 1, 1, 0, 0));
+}
+
+#[test]
+fn skipped_midnight_starts_at_the_transition() {
+    // Sao Paulo sprang forward from 00:00 to 01:00 on 2018-11-04, a Sunday
+    let time = local(Sao_Paulo, 2018, 11, 3, 22, 0);
+    let next = next(EveryWeek::starting(Weekday::Sun), time);
+    assert_eq!(next, local(Sao_Paulo, 2018, 11, 4, 1, 0));
+}
+
+#[test]
+fn durations_are_at_most_a_period() {
+    assert!(duration_to_next_week(Weekday::Mon) <= Duration::hours(7 * 24 + 1).to_std().unwrap());
+    assert!(duration_to_next_month() <= Duration::hours(31 * 24 + 1).to_std().unwrap());
+}
+
+#[test]
+fn hourly_at_an_offset() {
+    let quarter_past = Hourly::at(minutes(15)).unwrap();
+    let time = local(Berlin, 2023, 5, 9, 13, 45);
+    assert_eq!(
+        next(quarter_past.clone(), time),
+        local(Berlin, 2023, 5, 9, 14, 15)
+    );
+    let time = local(Berlin, 2023, 5, 9, 13, 10);
+    assert_eq!(next(quarter_past, time), local(Berlin, 2023, 5, 9, 13, 15));
+}
+
+#[test]
+fn hourly_at_many_offsets() {
+    let halves = Hourly::at_each([minutes(30), minutes(0), minutes(30)]).unwrap();
+    let times: Vec<_> = halves
+        .iter_after(local(Berlin, 2023, 5, 9, 13, 0))
+        .take(3)
+        .collect();
+    assert_eq!(
+        times,
+        [
+            local(Berlin, 2023, 5, 9, 13, 30),
+            local(Berlin, 2023, 5, 9, 14, 0),
+            local(Berlin, 2023, 5, 9, 14, 30),
+        ]
+    );
+}
+
+#[test]
+fn daily_at_many_times() {
+    let meals = Daily::at_each([minutes(8 * 60), minutes(12 * 60), minutes(18 * 60)]).unwrap();
+    let times: Vec<_> = meals
+        .iter_after(local(Berlin, 2023, 5, 9, 13, 45))
+        .take(3)
+        .collect();
+    assert_eq!(
+        times,
+        [
+            local(Berlin, 2023, 5, 9, 18, 0),
+            local(Berlin, 2023, 5, 10, 8, 0),
+            local(Berlin, 2023, 5, 10, 12, 0),
+        ]
+    );
+}
+
+#[test]
+fn offsets_must_fit_into_the_period() {
+    let error = Hourly::at(minutes(60)).unwrap_err();
+    assert_eq!(error.offset(), Some(minutes(60)));
+    assert_eq!(error.period(), minutes(60));
+    assert!(Daily::at(minutes(24 * 60)).is_err());
+    assert!(Daily::at(StdDuration::from_millis(1500)).is_err());
+    assert!(Daily::at_each([minutes(0), minutes(25 * 60)]).is_err());
+    assert!(Daily::at(minutes(24 * 60 - 1)).is_ok());
+
+    let error = Daily::at_each(Vec::new()).unwrap_err();
+    assert_eq!(error.offset(), None);
+    assert_eq!(error.period(), minutes(24 * 60));
+    assert!(Hourly::at_each(Vec::new()).is_err());
+}
+
+#[test]
+fn daily_time_skipped_by_spring_forward() {
+    // Berlin sprang forward from 02:00 to 03:00 on 2023-03-26
+    let half_past_two = Daily::at(minutes(150)).unwrap();
+    let times: Vec<_> = half_past_two
+        .iter_after(local(Berlin, 2023, 3, 25, 12, 0))
+        .take(3)
+        .collect();
+    assert_eq!(
+        times,
+        [
+            local(Berlin, 2023, 3, 26, 3, 0),
+            local(Berlin, 2023, 3, 27, 2, 30),
+            local(Berlin, 2023, 3, 28, 2, 30),
+        ]
+    );
+}
+
+#[test]
+fn daily_time_repeated_by_fall_back() {
+    // Berlin fell back from 03:00 to 02:00 on 2023-10-29
+    let half_past_two = Daily::at(minutes(150)).unwrap();
+    let times: Vec<_> = half_past_two
+        .iter_after(local(Berlin, 2023, 10, 28, 12, 0))
+        .take(2)
+        .collect();
+    assert_eq!(times[0].naive_utc().to_string(), "2023-10-29 00:30:00");
+    assert_eq!(times[1], local(Berlin, 2023, 10, 30, 2, 30));
+    assert_eq!(times[1] - times[0], Duration::hours(25));
+}
+
+#[test]
+fn hourly_offset_repeats_when_falling_back() {
+    let quarter_past = Hourly::at(minutes(15)).unwrap();
+    let times: Vec<_> = quarter_past
+        .iter_after(local(Berlin, 2023, 10, 29, 1, 30))
+        .take(3)
+        .map(|time| time.naive_utc().to_string())
+        .collect();
+    assert_eq!(
+        times,
+        [
+            "2023-10-29 00:15:00",
+            "2023-10-29 01:15:00",
+            "2023-10-29 02:15:00"
+        ]
+    );
+}
+
+#[test]
+fn hourly_offset_skipped_by_spring_forward() {
+    // Berlin sprang forward from 02:00 to 03:00 on 2023-03-26
+    let quarter_past = Hourly::at(minutes(15)).unwrap();
+    let times: Vec<_> = quarter_past
+        .iter_after(local(Berlin, 2023, 3, 26, 1, 0))
+        .take(3)
+        .collect();
+    assert_eq!(
+        times,
+        [
+            local(Berlin, 2023, 3, 26, 1, 15),
+            local(Berlin, 2023, 3, 26, 3, 15),
+            local(Berlin, 2023, 3, 26, 4, 15),
+        ]
+    );
+
+    let quarters = Hourly::at_each([minutes(15), minutes(45)]).unwrap();
+    let times: Vec<_> = quarters
+        .iter_after(local(Berlin, 2023, 3, 26, 1, 30))
+        .take(3)
+        .collect();
+    assert_eq!(
+        times,
+        [
+            local(Berlin, 2023, 3, 26, 1, 45),
+            local(Berlin, 2023, 3, 26, 3, 15),
+            local(Berlin, 2023, 3, 26, 3, 45),
+        ]
+    );
+}
+
+#[test]
+fn duration_to_an_offset_is_at_most_a_period() {
+    let daily = Daily::at(minutes(150)).unwrap();
+    let day = Duration::hours(25).to_std().unwrap();
+    assert!(duration_to_next(&daily).unwrap() <= day);
+}
@@ -0,0 +1,304 @@
+use chrono::{DateTime, Duration as ChronoDuration, TimeZone, Utc};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use zila::{Clock, ClockJump, ManualClock, MissedTickBehavior, Sleep, Tick, Timer, TimerHandle};
+
+type Calls = Vec<(DateTime<Utc>, Tick)>;
+
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+fn monday_noon() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2023, 5, 8, 12, 0, 0).unwrap()
+}
+
+/// Waits until `calls` holds at least `count` entries and returns them.
+fn wait_for<T: Clone>(calls: &Mutex<Vec<T>>, count: usize) -> Vec<T> {
+    loop {
+        let calls = calls.lock().unwrap();
+        if calls.len() >= count {
+            return calls.clone();
+        }
+        drop(calls);
+        std::thread::yield_now();
+    }
+}
+
+/// Spawns `timer` on a thread and records the wall time of `clock` and the
+/// tick of every call.
+fn record(timer: Timer, clock: &ManualClock) -> (TimerHandle, Arc<Mutex<Calls>>) {
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let handle = timer.clock(clock.clone()).spawn({
+        let calls = calls.clone();
+        let clock = clock.clone();
+        move |tick| calls.lock().unwrap().push((clock.now(), tick))
+    });
+    (handle, calls)
+}
+
+/// A [`ManualClock`] that counts the times a timer went to sleep on it.
+#[derive(Clone)]
+struct Parking {
+    clock: ManualClock,
+    sleeps: Arc<AtomicUsize>,
+}
+
+impl Parking {
+    fn new(time: DateTime<Utc>) -> Parking {
+        Parking {
+            clock: ManualClock::new(time),
+            sleeps: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Waits until a timer went to sleep on the clock, its next deadline is
+    /// known by then.
+    fn wait_parked(&self) {
+        while self.sleeps.load(Ordering::SeqCst) == 0 {
+            std::thread::yield_now();
+        }
+    }
+}
+
+impl Clock for Parking {
+    fn now(&self) -> DateTime<Utc> {
+        self.clock.now()
+    }
+
+    fn instant(&self) -> Instant {
+        self.clock.instant()
+    }
+
+    fn sleep_until(&self, deadline: Instant) {
+        self.sleeps.fetch_add(1, Ordering::SeqCst);
+        self.clock.sleep_until(deadline);
+    }
+
+    fn sleep_until_async(&self, deadline: Instant) -> Sleep {
+        self.sleeps.fetch_add(1, Ordering::SeqCst);
+        self.clock.sleep_until_async(deadline)
+    }
+}
+
+/// Spawns `timer`, waits until it sleeps and moves the clock by `duration`,
+/// then returns the ticks of the timer once it made `count` calls and went
+/// back to sleep.
+fn suspend(timer: Timer, duration: Duration, count: usize) -> Vec<Tick> {
+    let clock = Parking::new(monday_noon());
+    // the ticks and the number of sleeps before each call
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let handle = timer.clock(clock.clone()).spawn({
+        let calls = calls.clone();
+        let sleeps = clock.sleeps.clone();
+        move |tick| {
+            let sleeps = sleeps.load(Ordering::SeqCst);
+            calls.lock().unwrap().push((tick, sleeps));
+        }
+    });
+    clock.wait_parked();
+    clock.clock.advance(duration);
+    let (_, sleeps) = wait_for(&calls, count)[count - 1];
+    // a second call for the same suspend would come before the next sleep
+    while clock.sleeps.load(Ordering::SeqCst) == sleeps {
+        std::thread::yield_now();
+    }
+    handle.cancel();
+    let calls = calls.lock().unwrap();
+    calls.iter().map(|(tick, _)| tick.clone()).collect()
+}
+
+#[test]
+fn a_week_of_daily_ticks() {
+    let clock = ManualClock::new(monday_noon());
+    let timer = Timer::every_day_in(Utc).missed_tick_behavior(MissedTickBehavior::Burst);
+    let (handle, calls) = record(timer, &clock);
+    for _ in 0..7 {
+        clock.advance(DAY);
+    }
+    let calls = wait_for(&calls, 7);
+    handle.cancel();
+    assert!(handle.join().unwrap_err().is_cancelled());
+    assert_eq!(calls.len(), 7);
+    assert!(calls.iter().all(|(_, tick)| tick.skipped() == 0));
+}
+
+#[test]
+fn skip_reports_the_missed_days() {
+    let timer = Timer::every_day_in(Utc).missed_tick_behavior(MissedTickBehavior::Skip);
+    let calls = suspend(timer, 7 * DAY, 1);
+    let ticks: Vec<_> = calls
+        .iter()
+        .map(|tick| (tick.scheduled(), tick.skipped()))
+        .collect();
+    let midnight = Utc.with_ymd_and_hms(2023, 5, 15, 0, 0, 0).unwrap();
+    assert_eq!(ticks, [(midnight, 6)]);
+}
+
+#[test]
+fn skip_calls_once_after_a_suspend() {
+    let timer = Timer::interval(Duration::from_secs(60))
+        .fixed_rate()
+        .missed_tick_behavior(MissedTickBehavior::Skip);
+    let calls = suspend(timer, Duration::from_secs(10 * 60), 1);
+    let ticks: Vec<_> = calls
+        .iter()
+        .map(|tick| (tick.scheduled(), tick.skipped()))
+        .collect();
+    assert_eq!(ticks, [(monday_noon() + ChronoDuration::minutes(10), 9)]);
+}
+
+#[test]
+fn delay_calls_once_after_a_suspend() {
+    let timer = Timer::interval(Duration::from_secs(60))
+        .fixed_rate()
+        .missed_tick_behavior(MissedTickBehavior::Delay);
+    let calls = suspend(timer, Duration::from_secs(10 * 60 + 30), 1);
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].skipped(), 9);
+}
+
+#[test]
+fn intervals_follow_the_monotonic_clock() {
+    let clock = ManualClock::new(monday_noon());
+    let (handle, calls) = record(
+        Timer::interval(Duration::from_secs(60)).fixed_rate(),
+        &clock,
+    );
+    clock.advance(Duration::from_secs(59));
+    std::thread::sleep(Duration::from_millis(20));
+    assert!(calls.lock().unwrap().is_empty());
+    clock.advance(Duration::from_secs(1));
+    let calls = wait_for(&calls, 1);
+    handle.cancel();
+    assert_eq!(calls[0].0, monday_noon() + ChronoDuration::minutes(1));
+}
+
+#[test]
+fn ticks_know_the_boundary_they_belong_to() {
+    let clock = ManualClock::new(monday_noon() + ChronoDuration::minutes(30));
+    let (handle, calls) = record(Timer::every_hour_in(Utc), &clock);
+    clock.advance(Duration::from_secs(50 * 60));
+    wait_for(&calls, 1);
+    clock.advance(Duration::from_secs(40 * 60));
+    let calls = wait_for(&calls, 2);
+    handle.cancel();
+
+    let late = &calls[0].1;
+    assert_eq!(late.sequence(), 0);
+    assert_eq!(late.scheduled(), monday_noon() + ChronoDuration::hours(1));
+    assert_eq!(late.actual(), calls[0].0);
+    assert_eq!(late.lateness(), Duration::from_secs(20 * 60));
+
+    let on_time = &calls[1].1;
+    assert_eq!(on_time.sequence(), 1);
+    assert_eq!(
+        on_time.scheduled(),
+        monday_noon() + ChronoDuration::hours(2)
+    );
+    assert_eq!(on_time.lateness(), Duration::ZERO);
+}
+
+#[test]
+fn burst_ticks_report_their_own_deadlines() {
+    let clock = ManualClock::new(monday_noon());
+    let (handle, calls) = record(
+        Timer::interval(Duration::from_secs(10)).fixed_rate(),
+        &clock,
+    );
+    clock.advance(Duration::from_secs(35));
+    let calls = wait_for(&calls, 3);
+    handle.cancel();
+    let ticks: Vec<_> = calls
+        .iter()
+        .map(|(_, tick)| (tick.sequence(), tick.scheduled(), tick.lateness()))
+        .collect();
+    let at = |seconds| monday_noon() + ChronoDuration::seconds(seconds);
+    assert_eq!(
+        ticks,
+        [
+            (0, at(10), Duration::from_secs(25)),
+            (1, at(20), Duration::from_secs(15)),
+            (2, at(30), Duration::from_secs(5)),
+        ]
+    );
+}
+
+#[test]
+fn setting_the_clock_back_is_a_jump() {
+    let clock = ManualClock::new(monday_noon());
+    let jumps = Arc::new(Mutex::new(Vec::<ClockJump>::new()));
+    let timer = Timer::every_hour_in(Utc).on_clock_jump({
+        let jumps = jumps.clone();
+        move |jump| jumps.lock().unwrap().push(jump)
+    });
+    let (handle, calls) = record(timer, &clock);
+    clock.set(monday_noon() - ChronoDuration::minutes(90));
+    let jumps = wait_for(&jumps, 1);
+    assert!(jumps[0].is_backward());
+    assert_eq!(jumps[0].offset(), ChronoDuration::minutes(-90));
+
+    // the timer re-armed for 11:00, not 13:00
+    clock.advance(Duration::from_secs(30 * 60));
+    let calls = wait_for(&calls, 1);
+    handle.cancel();
+    assert_eq!(calls[0].0, monday_noon() - ChronoDuration::hours(1));
+}
+
+#[tokio::test]
+async fn async_timers_use_the_clock() {
+    let clock = ManualClock::new(monday_noon() + ChronoDuration::seconds(30));
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let handle = Timer::every_minute_in(Utc)
+        .clock(clock.clone())
+        .missed_tick_behavior(MissedTickBehavior::Burst)
+        .spawn_async({
+            let calls = calls.clone();
+            move |tick| {
+                calls.lock().unwrap().push(tick);
+                async {}
+            }
+        });
+    clock.advance(Duration::from_secs(3 * 60));
+    while calls.lock().unwrap().len() < 3 {
+        tokio::task::yield_now().await;
+    }
+    handle.cancel();
+    assert_eq!(calls.lock().unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn async_timers_call_once_after_a_suspend() {
+    let clock = Parking::new(monday_noon() + ChronoDuration::minutes(30));
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let handle = Timer::every_hour_in(Utc).clock(clock.clone()).spawn_async({
+        let calls = calls.clone();
+        let sleeps = clock.sleeps.clone();
+        move |tick| {
+            calls
+                .lock()
+                .unwrap()
+                .push((tick, sleeps.load(Ordering::SeqCst)));
+            async {}
+        }
+    });
+    while clock.sleeps.load(Ordering::SeqCst) == 0 {
+        tokio::task::yield_now().await;
+    }
+    clock.clock.advance(Duration::from_secs(5 * 60 * 60));
+    let sleeps = loop {
+        if let Some((_, sleeps)) = calls.lock().unwrap().first() {
+            break *sleeps;
+        }
+        tokio::task::yield_now().await;
+    };
+    while clock.sleeps.load(Ordering::SeqCst) == sleeps {
+        tokio::task::yield_now().await;
+    }
+    handle.cancel();
+    let calls = calls.lock().unwrap();
+    let ticks: Vec<_> = calls
+        .iter()
+        .map(|(tick, _)| (tick.scheduled(), tick.skipped()))
+        .collect();
+    assert_eq!(ticks, [(monday_noon() + ChronoDuration::hours(5), 4)]);
+}
@@ -0,0 +1,183 @@
+use chrono::{DateTime, TimeZone, Utc};
+use chrono_tz::{America::New_York, Europe::Berlin, Tz};
+use zila::{Cron, CronErrorKind};
+
+fn upcoming<Z: TimeZone>(cron: &str, mut time: DateTime<Z>, count: usize) -> Vec<DateTime<Z>> {
+    let cron = Cron::parse(cron).unwrap();
+    (0..count)
+        .map(|_| {
+            time = cron.next_after(&time).unwrap();
+            time.clone()
+        })
+        .collect()
+}
+
+fn berlin(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Tz> {
+    Berlin
+        .with_ymd_and_hms(year, month, day, hour, minute, 0)
+        .earliest()
+        .unwrap()
+}
+
+fn utc(year: i32, month: u32, day: u32, hour: u32, minute: u32, second: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(year, month, day, hour, minute, second)
+        .unwrap()
+}
+
+#[test]
+fn weekdays_at_half_past_nine() {
+    // Friday, 2023-05-12
+    let times = upcoming("30 9 * * MON-FRI", utc(2023, 5, 12, 9, 30, 0), 3);
+    assert_eq!(
+        times,
+        [
+            utc(2023, 5, 15, 9, 30, 0),
+            utc(2023, 5, 16, 9, 30, 0),
+            utc(2023, 5, 17, 9, 30, 0)
+        ]
+    );
+}
+
+#[test]
+fn seconds_steps_and_lists() {
+    let times = upcoming("*/20 0,30 * * * *", utc(2023, 1, 1, 0, 0, 40), 4);
+    assert_eq!(
+        times,
+        [
+            utc(2023, 1, 1, 0, 30, 0),
+            utc(2023, 1, 1, 0, 30, 20),
+            utc(2023, 1, 1, 0, 30, 40),
+            utc(2023, 1, 1, 1, 0, 0)
+        ]
+    );
+}
+
+#[test]
+fn day_of_month_or_day_of_week() {
+    // the 13th of every month and every Friday
+    let times = upcoming("0 0 13 * FRI", utc(2023, 1, 1, 0, 0, 0), 4);
+    assert_eq!(
+        times,
+        [
+            utc(2023, 1, 6, 0, 0, 0),
+            utc(2023, 1, 13, 0, 0, 0),
+            utc(2023, 1, 20, 0, 0, 0),
+            utc(2023, 1, 27, 0, 0, 0)
+        ]
+    );
+}
+
+#[test]
+fn leap_days_and_impossible_dates() {
+    let times = upcoming("0 0 29 FEB *", utc(2023, 1, 1, 0, 0, 0), 2);
+    assert_eq!(
+        times,
+        [utc(2024, 2, 29, 0, 0, 0), utc(2028, 2, 29, 0, 0, 0)]
+    );
+
+    let cron = Cron::parse("0 0 30 2 *").unwrap();
+    assert_eq!(cron.next_after(&utc(2023, 1, 1, 0, 0, 0)), None);
+}
+
+#[test]
+fn sunday_is_zero_and_seven() {
+    assert_eq!(
+        upcoming("0 0 * * 7", utc(2023, 1, 1, 0, 0, 0), 1),
+        upcoming("0 0 * * SUN", utc(2023, 1, 1, 0, 0, 0), 1),
+    );
+}
+
+#[test]
+fn skipped_local_times_fire_after_the_jump() {
+    // Berlin springs forward from 02:00 to 03:00 on 2023-03-26
+    let times = upcoming("30 2 * * *", berlin(2023, 3, 25, 12, 0), 3);
+    assert_eq!(
+        times,
+        [
+            berlin(2023, 3, 26, 3, 0),
+            berlin(2023, 3, 27, 2, 30),
+            berlin(2023, 3, 28, 2, 30)
+        ]
+    );
+}
+
+#[test]
+fn skipped_local_times_are_dropped_for_every_hour() {
+    // Berlin springs forward from 02:00 to 03:00 on 2023-03-26
+    let start = berlin(2023, 3, 26, 1, 0);
+    let times = upcoming("15 * * * *", start, 3);
+    assert_eq!(
+        times,
+        [
+            berlin(2023, 3, 26, 1, 15),
+            berlin(2023, 3, 26, 3, 15),
+            berlin(2023, 3, 26, 4, 15)
+        ]
+    );
+
+    let times = upcoming("*/15 * * * *", berlin(2023, 3, 26, 1, 30), 4);
+    assert_eq!(
+        times,
+        [
+            berlin(2023, 3, 26, 1, 45),
+            berlin(2023, 3, 26, 3, 0),
+            berlin(2023, 3, 26, 3, 15),
+            berlin(2023, 3, 26, 3, 30)
+        ]
+    );
+}
+
+#[test]
+fn repeated_local_times_fire_once_for_fixed_hours() {
+    // New York falls back from 02:00 to 01:00 on 2023-11-05
+    let start = New_York.with_ymd_and_hms(2023, 11, 4, 12, 0, 0).unwrap();
+    let times = upcoming("30 1 * * *", start, 2);
+    assert_eq!(times[0].naive_utc(), utc(2023, 11, 5, 5, 30, 0).naive_utc());
+    assert_eq!(times[1].naive_utc(), utc(2023, 11, 6, 6, 30, 0).naive_utc());
+}
+
+#[test]
+fn repeated_local_times_keep_firing_for_every_hour() {
+    let start = New_York.with_ymd_and_hms(2023, 11, 5, 0, 0, 0).unwrap();
+    let times: Vec<_> = upcoming("0 * * * *", start, 3)
+        .into_iter()
+        .map(|time| time.naive_utc())
+        .collect();
+    assert_eq!(
+        times,
+        [
+            utc(2023, 11, 5, 5, 0, 0).naive_utc(),
+            utc(2023, 11, 5, 6, 0, 0).naive_utc(),
+            utc(2023, 11, 5, 7, 0, 0).naive_utc()
+        ]
+    );
+}
+
+#[test]
+fn errors_point_at_the_offending_value() {
+    let error = Cron::parse("0 24 * * *").unwrap_err();
+    assert_eq!(error.position(), 2);
+    assert!(matches!(error.kind(), CronErrorKind::OutOfRange { .. }));
+    assert_eq!(
+        error.to_string(),
+        "invalid cron expression at position 2: hour `24` is not in 0-23"
+    );
+
+    let error = Cron::parse("0 9 * JAN,FOO *").unwrap_err();
+    assert_eq!(error.position(), 10);
+    assert!(matches!(error.kind(), CronErrorKind::InvalidValue { .. }));
+
+    let error = Cron::parse("*/0 * * * *").unwrap_err();
+    assert_eq!(error.position(), 2);
+    assert_eq!(error.kind(), &CronErrorKind::InvalidStep("0".to_owned()));
+
+    let error = Cron::parse("0 0 17-9 * *").unwrap_err();
+    assert_eq!(error.position(), 4);
+
+    let error = Cron::parse("* * *").unwrap_err();
+    assert_eq!(error.kind(), &CronErrorKind::FieldCount(3));
+
+    let error = Cron::parse("  @often").unwrap_err();
+    assert_eq!(error.position(), 2);
+    assert_eq!(error.kind(), &CronErrorKind::UnknownMacro);
+}
@@ -0,0 +1,80 @@
+use chrono::{DateTime, Duration, TimeZone};
+use chrono_tz::{
+    America::{New_York, Sao_Paulo},
+    Europe::Berlin,
+    Tz,
+};
+use zila::{duration_to_next_day, start_of_next_day};
+
+fn local(tz: Tz, year: i32, month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Tz> {
+    tz.with_ymd_and_hms(year, month, day, hour, minute, 0)
+        .earliest()
+        .unwrap()
+}
+
+/// Returns the start of the day after `time` and the length of the day of `time`.
+fn next_day(time: DateTime<Tz>) -> (DateTime<Tz>, Duration) {
+    let next = start_of_next_day(&time);
+    // days last between 23 and 25 hours, 30 hours ago was the day before
+    let start = start_of_next_day(&(next - Duration::hours(30)));
+    (next, next - start)
+}
+
+#[test]
+fn regular_day() {
+    let (next, length) = next_day(local(Berlin, 2023, 5, 9, 13, 45));
+    assert_eq!(next, local(Berlin, 2023, 5, 10, 0, 0));
+    assert_eq!(length, Duration::hours(24));
+}
+
+#[test]
+fn midnight_starts_the_next_day() {
+    let midnight = local(Berlin, 2023, 5, 10, 0, 0);
+    assert_eq!(
+        start_of_next_day(&midnight),
+        local(Berlin, 2023, 5, 11, 0, 0)
+    );
+}
+
+#[test]
+fn spring_forward_day_lasts_23_hours() {
+    let (next, length) = next_day(local(Berlin, 2023, 3, 26, 12, 0));
+    assert_eq!(next, local(Berlin, 2023, 3, 27, 0, 0));
+    assert_eq!(length, Duration::hours(23));
+
+    let (next, length) = next_day(local(New_York, 2023, 3, 12, 1, 30));
+    assert_eq!(next, local(New_York, 2023, 3, 13, 0, 0));
+    assert_eq!(length, Duration::hours(23));
+}
+
+#[test]
+fn fall_back_day_lasts_25_hours() {
+    let (next, length) = next_day(local(Berlin, 2023, 10, 29, 2, 30));
+    assert_eq!(next, local(Berlin, 2023, 10, 30, 0, 0));
+    assert_eq!(length, Duration::hours(25));
+
+    let (next, length) = next_day(local(New_York, 2023, 11, 5, 23, 59));
+    assert_eq!(next, local(New_York, 2023, 11, 6, 0, 0));
+    assert_eq!(length, Duration::hours(25));
+}
+
+#[test]
+fn skipped_midnight_starts_at_the_transition() {
+    // Sao Paulo sprang forward from 00:00 to 01:00 on 2018-11-04
+    let next = start_of_next_day(&local(Sao_Paulo, 2018, 11, 3, 22, 0));
+    assert_eq!(next, local(Sao_Paulo, 2018, 11, 4, 1, 0));
+    assert_eq!(next.naive_utc().to_string(), "2018-11-04 03:00:00");
+}
+
+#[test]
+fn fall_back_at_midnight() {
+    // Sao Paulo fell back from 00:00 to 23:00 on 2019-02-17
+    let (next, length) = next_day(local(Sao_Paulo, 2019, 2, 16, 23, 30));
+    assert_eq!(next.naive_utc().to_string(), "2019-02-17 03:00:00");
+    assert_eq!(length, Duration::hours(25));
+}
+
+#[test]
+fn duration_is_at_most_a_day() {
+    assert!(duration_to_next_day() <= Duration::hours(25).to_std().unwrap());
+}
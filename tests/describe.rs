@@ -0,0 +1,169 @@
+use chrono::{TimeZone, Utc, Weekday};
+use std::time::Duration;
+use zila::{
+    describe_next_run, describe_next_run_in, format_duration, Aligned, Cron, Daily, Every,
+    EveryDay, EveryHour, EveryMonth, EveryWeek, Hourly, Interval, OnCalendar, Once, Recurrence,
+    Schedule,
+};
+
+fn cron(expression: &str) -> String {
+    Cron::parse(expression).unwrap().describe()
+}
+
+fn on_calendar(event: &str) -> String {
+    OnCalendar::parse(event).unwrap().describe()
+}
+
+fn recurrence(text: &str) -> String {
+    Recurrence::parse(text).unwrap().describe()
+}
+
+#[test]
+fn built_in_schedules() {
+    let minutes = |minutes: u64| Duration::from_secs(minutes * 60);
+    assert_eq!(EveryDay.describe(), "every day at midnight");
+    assert_eq!(EveryHour.to_string(), "every hour");
+    assert_eq!(
+        EveryWeek::starting(Weekday::Sun).describe(),
+        "every Sunday at midnight"
+    );
+    assert_eq!(
+        EveryMonth.describe(),
+        "at midnight on the first day of every month"
+    );
+    let hourly = Hourly::at_each([minutes(0), minutes(30)]).unwrap();
+    assert_eq!(hourly.describe(), "every hour at minutes 0 and 30");
+    let hourly = Hourly::at(Duration::from_secs(15 * 60 + 30)).unwrap();
+    assert_eq!(hourly.describe(), "every hour at 15:30 past the hour");
+    let daily = Daily::at_each([minutes(8 * 60), minutes(12 * 60), minutes(18 * 60 + 5)]).unwrap();
+    assert_eq!(daily.to_string(), "every day at 08:00, 12:00 and 18:05");
+    assert_eq!(Interval::new(minutes(90)).describe(), "every 1h 30m");
+    assert_eq!(
+        Aligned::new(minutes(5)).describe(),
+        "every 5m counted from the Unix epoch"
+    );
+    assert_eq!(
+        Aligned::from_midnight(Duration::from_millis(250)).describe(),
+        "every 250ms counted from midnight"
+    );
+    let once = Once::at(Utc.with_ymd_and_hms(2023, 5, 9, 14, 0, 0).unwrap());
+    assert_eq!(once.describe(), "once at 2023-05-09 14:00:00 UTC");
+}
+
+#[test]
+fn time_zones() {
+    let hourly = Hourly::at(Duration::from_secs(15 * 60)).unwrap();
+    assert_eq!(
+        hourly.describe_in(chrono_tz::Europe::Berlin),
+        "every hour at minute 15 (Europe/Berlin)"
+    );
+    assert_eq!(EveryHour.describe_in(Utc), "every hour (UTC)");
+    // the time zone of the event wins
+    let event = OnCalendar::parse("daily Asia/Tokyo").unwrap();
+    assert_eq!(
+        event.describe_in(chrono_tz::Europe::Berlin),
+        "every day at 00:00 (Asia/Tokyo)"
+    );
+}
+
+#[test]
+fn cron_expressions() {
+    assert_eq!(cron("30 9 * * MON-FRI"), "at 09:30 on weekdays");
+    assert_eq!(cron("15 * * * *"), "every hour at minute 15");
+    assert_eq!(cron("*/15 * * * *"), "every 15 minutes");
+    assert_eq!(cron("* * * * *"), "every minute");
+    assert_eq!(cron("*/10 * * * * *"), "every 10 seconds");
+    assert_eq!(
+        cron("0 0,12 1 * *"),
+        "at 00:00 and 12:00 on day 1 of the month"
+    );
+    assert_eq!(cron("0 */2 * * *"), "every 2 hours");
+    assert_eq!(
+        cron("0 9-17 * * 1,3,5"),
+        "at minute 0 during hours 9 to 17 on Monday, Wednesday and Friday"
+    );
+    assert_eq!(
+        cron("0 0 1 * SUN"),
+        "at 00:00 on day 1 of the month or on Sunday"
+    );
+    assert_eq!(cron("@yearly"), "at 00:00 on day 1 of the month in January");
+    assert_eq!(
+        cron("0 8 * 6-8 SAT,SUN"),
+        "at 08:00 on weekends in June to August"
+    );
+}
+
+#[test]
+fn calendar_events() {
+    assert_eq!(on_calendar("Mon..Fri *-*-* 09:30"), "at 09:30 on weekdays");
+    assert_eq!(on_calendar("hourly"), "every hour at minute 0");
+    assert_eq!(
+        on_calendar("*-*~01 18:00 UTC"),
+        "at 18:00 on the last day of the month (UTC)"
+    );
+    assert_eq!(
+        on_calendar("2024,2026-01-01"),
+        "at 00:00 on day 1 of the month in January in 2024 and 2026"
+    );
+}
+
+#[test]
+fn recurrences() {
+    assert_eq!(
+        recurrence("DTSTART:20230509T090000\nRRULE:FREQ=MONTHLY;BYDAY=2TU"),
+        "every month on the 2nd Tuesday at 09:00"
+    );
+    assert_eq!(
+        recurrence("DTSTART:20230509T090000Z\nRRULE:FREQ=WEEKLY;INTERVAL=2;COUNT=10"),
+        "every 2 weeks on Tuesday at 09:00, 10 times, starting 2023-05-09 09:00 (UTC)"
+    );
+    assert_eq!(
+        recurrence("DTSTART:20230501T170000\nRRULE:FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1"),
+        "every month on the last weekday at 17:00"
+    );
+    assert_eq!(
+        recurrence("DTSTART:20230509T090000\nRRULE:FREQ=DAILY\nEXDATE:20230512T090000"),
+        "every day at 09:00, except at 2023-05-12 09:00"
+    );
+    assert_eq!(
+        recurrence("DTSTART:20230509T090000"),
+        "once at 2023-05-09 09:00"
+    );
+}
+
+#[test]
+fn phrases() {
+    let describe = |phrase: &str| Every::parse(phrase).unwrap().describe();
+    assert_eq!(describe("every 90 minutes"), "every 1h 30m");
+    assert_eq!(describe("every weekday at 9:30am"), "at 09:30 on weekdays");
+    assert_eq!(describe("every day at 3am"), "every day at 03:00");
+    assert_eq!(describe("every hour at :15"), "every hour at minute 15");
+    assert_eq!(describe("every week"), "every Monday at midnight");
+}
+
+#[test]
+fn durations() {
+    let duration = Duration::from_secs(3 * 60 * 60 + 12 * 60 + 40);
+    assert_eq!(format_duration(duration), "3h 12m");
+    assert_eq!(
+        format_duration(Duration::from_secs(2 * 24 * 60 * 60 + 5)),
+        "2d"
+    );
+    assert_eq!(format_duration(Duration::from_secs(45)), "45s");
+    assert_eq!(format_duration(Duration::from_millis(1250)), "1s 250ms");
+    assert_eq!(format_duration(Duration::ZERO), "0s");
+}
+
+#[test]
+fn next_run() {
+    let past = Once::at(Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).unwrap());
+    assert_eq!(describe_next_run(&past), "no next run");
+    let soon = Once::at(Utc::now() + chrono::Duration::hours(3) + chrono::Duration::minutes(12));
+    let description = describe_next_run_in(&soon, &chrono_tz::Europe::Berlin);
+    // a few nanoseconds passed since
+    assert!(
+        description == "next run in 3h 12m" || description == "next run in 3h 11m",
+        "{}",
+        description
+    );
+}
@@ -0,0 +1,188 @@
+use chrono::{DateTime, TimeZone, Utc};
+use std::time::Duration;
+use zila::{parse_duration, Every, HumanErrorKind, Schedule};
+
+fn utc(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(year, month, day, hour, minute, 0)
+        .unwrap()
+}
+
+fn upcoming(phrase: &str, time: DateTime<Utc>, count: usize) -> Vec<DateTime<Utc>> {
+    Every::parse(phrase)
+        .unwrap()
+        .iter_after(time)
+        .take(count)
+        .collect()
+}
+
+#[test]
+fn durations() {
+    assert_eq!(parse_duration("1h30m"), Ok(Duration::from_secs(5400)));
+    assert_eq!(parse_duration("250ms"), Ok(Duration::from_millis(250)));
+    assert_eq!(parse_duration("90 s"), Ok(Duration::from_secs(90)));
+    assert_eq!(
+        parse_duration("2 hours and 15 minutes"),
+        Ok(Duration::from_secs(8100))
+    );
+    assert_eq!(
+        parse_duration("1 day, 2h, 5us"),
+        Ok(Duration::new(93600, 5000))
+    );
+    assert_eq!(parse_duration("1W"), Ok(Duration::from_secs(604800)));
+}
+
+#[test]
+fn duration_errors() {
+    let error = parse_duration(" ").unwrap_err();
+    assert_eq!(error.kind(), &HumanErrorKind::Empty);
+
+    let error = parse_duration("1h30").unwrap_err();
+    assert_eq!(error.position(), 4);
+    assert_eq!(error.kind(), &HumanErrorKind::MissingUnit);
+
+    let error = parse_duration("3 months").unwrap_err();
+    assert_eq!(error.position(), 2);
+    assert_eq!(
+        error.kind(),
+        &HumanErrorKind::UnknownUnit("months".to_owned())
+    );
+
+    let error = parse_duration("5m later").unwrap_err();
+    assert_eq!(error.position(), 3);
+
+    let error = parse_duration("99999999999999999999999w").unwrap_err();
+    assert_eq!(error.kind(), &HumanErrorKind::Overflow);
+}
+
+#[test]
+fn periods() {
+    let times = upcoming("every 15 minutes", utc(2023, 5, 12, 9, 7), 2);
+    assert_eq!(times, [utc(2023, 5, 12, 9, 22), utc(2023, 5, 12, 9, 37)]);
+    let times = upcoming("Every 1h30m", utc(2023, 5, 12, 9, 0), 1);
+    assert_eq!(times, [utc(2023, 5, 12, 10, 30)]);
+}
+
+#[test]
+fn boundaries() {
+    // Friday, 2023-05-12
+    let time = utc(2023, 5, 12, 9, 7);
+    assert_eq!(upcoming("every minute", time, 1), [utc(2023, 5, 12, 9, 8)]);
+    assert_eq!(upcoming("every hour", time, 1), [utc(2023, 5, 12, 10, 0)]);
+    assert_eq!(upcoming("every day", time, 1), [utc(2023, 5, 13, 0, 0)]);
+    assert_eq!(upcoming("every week", time, 1), [utc(2023, 5, 15, 0, 0)]);
+    assert_eq!(upcoming("every month", time, 1), [utc(2023, 6, 1, 0, 0)]);
+    assert_eq!(
+        upcoming("every quarter", time, 2),
+        [utc(2023, 7, 1, 0, 0), utc(2023, 10, 1, 0, 0)]
+    );
+    assert_eq!(upcoming("every year", time, 1), [utc(2024, 1, 1, 0, 0)]);
+}
+
+#[test]
+fn times_of_day() {
+    let time = utc(2023, 5, 12, 9, 7);
+    assert_eq!(
+        upcoming("every day at 03:00", time, 2),
+        [utc(2023, 5, 13, 3, 0), utc(2023, 5, 14, 3, 0)]
+    );
+    assert_eq!(
+        upcoming("every monday at 9am", time, 2),
+        [utc(2023, 5, 15, 9, 0), utc(2023, 5, 22, 9, 0)]
+    );
+    assert_eq!(
+        upcoming("every weekday at 9:30 am and 5pm", time, 3),
+        [
+            utc(2023, 5, 12, 9, 30),
+            utc(2023, 5, 12, 17, 0),
+            utc(2023, 5, 15, 9, 30)
+        ]
+    );
+    assert_eq!(
+        upcoming("every tue, thu and saturday at noon", time, 2),
+        [utc(2023, 5, 13, 12, 0), utc(2023, 5, 16, 12, 0)]
+    );
+    assert_eq!(
+        upcoming("every weekend at 12am", time, 1),
+        [utc(2023, 5, 13, 0, 0)]
+    );
+    assert_eq!(upcoming("every sunday", time, 1), [utc(2023, 5, 14, 0, 0)]);
+}
+
+#[test]
+fn minutes_past_the_hour() {
+    let times = upcoming("every hour at :00 and :30", utc(2023, 5, 12, 9, 7), 3);
+    assert_eq!(
+        times,
+        [
+            utc(2023, 5, 12, 9, 30),
+            utc(2023, 5, 12, 10, 0),
+            utc(2023, 5, 12, 10, 30)
+        ]
+    );
+    let times = upcoming("every hour at 45", utc(2023, 5, 12, 9, 50), 1);
+    assert_eq!(times, [utc(2023, 5, 12, 10, 45)]);
+}
+
+#[test]
+fn daylight_saving_time() {
+    let berlin = chrono_tz::Europe::Berlin;
+    let every = Every::parse("every day at 02:30").unwrap();
+    // 02:30 doesn't exist on 2023-03-26 in Berlin
+    let time = berlin.with_ymd_and_hms(2023, 3, 25, 12, 0, 0).unwrap();
+    let times: Vec<_> = every.iter_after(time).take(2).collect();
+    assert_eq!(
+        times,
+        [
+            berlin.with_ymd_and_hms(2023, 3, 26, 3, 0, 0).unwrap(),
+            berlin.with_ymd_and_hms(2023, 3, 27, 2, 30, 0).unwrap()
+        ]
+    );
+    // the hour from 02:00 to 03:00 is skipped, and so is its quarter past
+    let every = Every::parse("every hour at :15").unwrap();
+    let time = berlin.with_ymd_and_hms(2023, 3, 26, 1, 0, 0).unwrap();
+    let times: Vec<_> = every.iter_after(time).take(2).collect();
+    assert_eq!(
+        times,
+        [
+            berlin.with_ymd_and_hms(2023, 3, 26, 1, 15, 0).unwrap(),
+            berlin.with_ymd_and_hms(2023, 3, 26, 3, 15, 0).unwrap()
+        ]
+    );
+}
+
+#[test]
+fn errors() {
+    let error = Every::parse("").unwrap_err();
+    assert_eq!(error.kind(), &HumanErrorKind::Empty);
+
+    let error = Every::parse("each day").unwrap_err();
+    assert_eq!(error.position(), 0);
+
+    let error = Every::parse("every day at 25:00").unwrap_err();
+    assert_eq!(error.position(), 13);
+    assert_eq!(
+        error.to_string(),
+        "invalid schedule at position 13: expected a time like 09:00 or 9am, found `25:00`"
+    );
+
+    let error = Every::parse("every monday at").unwrap_err();
+    assert!(matches!(error.kind(), HumanErrorKind::UnexpectedEnd { .. }));
+
+    let error = Every::parse("every fortnight").unwrap_err();
+    assert_eq!(error.position(), 6);
+
+    let error = Every::parse("every 0s").unwrap_err();
+    assert_eq!(error.kind(), &HumanErrorKind::ZeroPeriod);
+
+    let error = Every::parse("every month at 9am").unwrap_err();
+    assert_eq!(error.position(), 12);
+
+    let error = Every::parse("every hour at :75").unwrap_err();
+    assert_eq!(error.position(), 14);
+}
+
+#[test]
+fn display() {
+    let every: Every = " every day at 03:00 ".parse().unwrap();
+    assert_eq!(every.to_string(), "every day at 03:00");
+}
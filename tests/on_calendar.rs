@@ -0,0 +1,142 @@
+use chrono::{DateTime, TimeZone, Utc};
+use zila::{OnCalendar, OnCalendarErrorKind, Schedule};
+
+fn utc(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(year, month, day, hour, minute, 0)
+        .unwrap()
+}
+
+fn upcoming(event: &str, time: DateTime<Utc>, count: usize) -> Vec<DateTime<Utc>> {
+    OnCalendar::parse(event)
+        .unwrap()
+        .iter_after(time)
+        .take(count)
+        .collect()
+}
+
+#[test]
+fn weekdays_at_nine() {
+    // Friday, 2023-05-12
+    let times = upcoming("Mon..Fri *-*-* 09:00:00", utc(2023, 5, 12, 9, 0), 2);
+    assert_eq!(times, [utc(2023, 5, 15, 9, 0), utc(2023, 5, 16, 9, 0)]);
+}
+
+#[test]
+fn parts_can_be_left_out() {
+    let times = upcoming("*-*-01", utc(2023, 5, 12, 9, 0), 2);
+    assert_eq!(times, [utc(2023, 6, 1, 0, 0), utc(2023, 7, 1, 0, 0)]);
+    let times = upcoming("Sat,Sun", utc(2023, 5, 12, 9, 0), 2);
+    assert_eq!(times, [utc(2023, 5, 13, 0, 0), utc(2023, 5, 14, 0, 0)]);
+    let times = upcoming("18:30", utc(2023, 5, 12, 19, 0), 1);
+    assert_eq!(times, [utc(2023, 5, 13, 18, 30)]);
+}
+
+#[test]
+fn shorthands() {
+    let time = utc(2023, 5, 12, 9, 0);
+    assert_eq!(upcoming("weekly", time, 1), [utc(2023, 5, 15, 0, 0)]);
+    assert_eq!(upcoming("monthly", time, 1), [utc(2023, 6, 1, 0, 0)]);
+    assert_eq!(
+        upcoming("quarterly", time, 2),
+        [utc(2023, 7, 1, 0, 0), utc(2023, 10, 1, 0, 0)]
+    );
+    assert_eq!(upcoming("hourly", time, 1), [utc(2023, 5, 12, 10, 0)]);
+}
+
+#[test]
+fn repetitions_and_ranges() {
+    let times = upcoming("*:0/20", utc(2023, 5, 12, 9, 50), 3);
+    assert_eq!(
+        times,
+        [
+            utc(2023, 5, 12, 10, 0),
+            utc(2023, 5, 12, 10, 20),
+            utc(2023, 5, 12, 10, 40)
+        ]
+    );
+    let times = upcoming("*-*-* 8..9:00/30", utc(2023, 5, 12, 9, 45), 3);
+    assert_eq!(
+        times,
+        [
+            utc(2023, 5, 13, 8, 0),
+            utc(2023, 5, 13, 8, 30),
+            utc(2023, 5, 13, 9, 0)
+        ]
+    );
+}
+
+#[test]
+fn days_from_the_end_of_the_month() {
+    let times = upcoming("*-02~01", utc(2023, 5, 12, 9, 0), 2);
+    assert_eq!(times, [utc(2024, 2, 29, 0, 0), utc(2025, 2, 28, 0, 0)]);
+    // the last Monday of May
+    let times = upcoming("Mon *-05~07/1", utc(2023, 1, 1, 0, 0), 2);
+    assert_eq!(times, [utc(2023, 5, 29, 0, 0), utc(2024, 5, 27, 0, 0)]);
+}
+
+#[test]
+fn years() {
+    let times = upcoming("2024,2026-01-01", utc(2023, 5, 12, 9, 0), 3);
+    assert_eq!(times, [utc(2024, 1, 1, 0, 0), utc(2026, 1, 1, 0, 0)]);
+    let times = upcoming("25-12-24 18:00", utc(2023, 5, 12, 9, 0), 2);
+    assert_eq!(times, [utc(2025, 12, 24, 18, 0)]);
+}
+
+#[test]
+fn time_zones() {
+    // Berlin is 2 hours ahead of UTC in summer
+    let times = upcoming("*-*-* 09:00 Europe/Berlin", utc(2023, 5, 12, 9, 0), 1);
+    assert_eq!(times, [utc(2023, 5, 13, 7, 0)]);
+    let event = OnCalendar::parse("daily UTC").unwrap();
+    let time = utc(2023, 5, 12, 9, 0).with_timezone(&chrono_tz::Asia::Tokyo);
+    assert_eq!(event.next_after(&time).unwrap(), utc(2023, 5, 13, 0, 0));
+}
+
+#[test]
+fn hours_skipped_by_daylight_saving_time() {
+    // Berlin springs forward from 02:00 to 03:00 on 2023-03-26, at 01:00 UTC
+    let times = upcoming("*-*-* *:15 Europe/Berlin", utc(2023, 3, 26, 0, 0), 3);
+    assert_eq!(
+        times,
+        [
+            utc(2023, 3, 26, 0, 15),
+            utc(2023, 3, 26, 1, 15),
+            utc(2023, 3, 26, 2, 15)
+        ]
+    );
+    // a named hour fires right after the jump instead
+    let times = upcoming("*-*-* 02:15 Europe/Berlin", utc(2023, 3, 26, 0, 0), 1);
+    assert_eq!(times, [utc(2023, 3, 26, 1, 0)]);
+}
+
+#[test]
+fn errors() {
+    let error = OnCalendar::parse("  ").unwrap_err();
+    assert_eq!(error.kind(), &OnCalendarErrorKind::Empty);
+
+    let error = OnCalendar::parse("*-13-01").unwrap_err();
+    assert_eq!(error.position(), 2);
+    assert!(matches!(
+        error.kind(),
+        OnCalendarErrorKind::OutOfRange { field: "month", .. }
+    ));
+
+    let error = OnCalendar::parse("Mon..Fri 09:00 Mars/Olympus").unwrap_err();
+    assert_eq!(error.position(), 15);
+    assert_eq!(
+        error.kind(),
+        &OnCalendarErrorKind::UnknownTimeZone("Mars/Olympus".to_owned())
+    );
+
+    let error = OnCalendar::parse("09:00 UTC extra").unwrap_err();
+    assert_eq!(
+        error.kind(),
+        &OnCalendarErrorKind::UnexpectedToken("extra".to_owned())
+    );
+
+    let error = OnCalendar::parse("*:0/0").unwrap_err();
+    assert_eq!(
+        error.kind(),
+        &OnCalendarErrorKind::InvalidStep("0".to_owned())
+    );
+}
@@ -0,0 +1,263 @@
+use chrono::{DateTime, TimeZone, Utc};
+use chrono_tz::Europe::Berlin;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use zila::{ManualClock, Recurrence, RecurrenceErrorKind, Timer};
+
+fn utc(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(year, month, day, hour, minute, 0)
+        .unwrap()
+}
+
+fn occurrences(text: &str, count: usize) -> Vec<DateTime<Utc>> {
+    Recurrence::parse(text)
+        .unwrap()
+        .occurrences(&Utc)
+        .take(count)
+        .collect()
+}
+
+#[test]
+fn last_work_day_of_the_month() {
+    let times = occurrences(
+        "DTSTART:19970929T090000\n\
+         RRULE:FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1",
+        4,
+    );
+    assert_eq!(
+        times,
+        [
+            utc(1997, 9, 30, 9, 0),
+            utc(1997, 10, 31, 9, 0),
+            utc(1997, 11, 28, 9, 0),
+            utc(1997, 12, 31, 9, 0),
+        ]
+    );
+}
+
+#[test]
+fn every_other_week_on_tuesday_and_thursday() {
+    let times = occurrences(
+        "DTSTART:20230509T090000\n\
+         RRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=TU,TH",
+        4,
+    );
+    assert_eq!(
+        times,
+        [
+            utc(2023, 5, 9, 9, 0),
+            utc(2023, 5, 11, 9, 0),
+            utc(2023, 5, 23, 9, 0),
+            utc(2023, 5, 25, 9, 0),
+        ]
+    );
+}
+
+#[test]
+fn monday_of_week_number_20() {
+    let times = occurrences(
+        "DTSTART:19970512T090000\n\
+         RRULE:FREQ=YEARLY;BYWEEKNO=20;BYDAY=MO",
+        3,
+    );
+    assert_eq!(
+        times,
+        [
+            utc(1997, 5, 12, 9, 0),
+            utc(1998, 5, 11, 9, 0),
+            utc(1999, 5, 17, 9, 0),
+        ]
+    );
+}
+
+#[test]
+fn days_of_the_year_every_third_year() {
+    let times = occurrences(
+        "DTSTART:19970101T090000\n\
+         RRULE:FREQ=YEARLY;INTERVAL=3;COUNT=10;BYYEARDAY=1,100,200",
+        5,
+    );
+    assert_eq!(
+        times,
+        [
+            utc(1997, 1, 1, 9, 0),
+            utc(1997, 4, 10, 9, 0),
+            utc(1997, 7, 19, 9, 0),
+            utc(2000, 1, 1, 9, 0),
+            // 2000 is a leap year
+            utc(2000, 4, 9, 9, 0),
+        ]
+    );
+}
+
+#[test]
+fn months_without_the_day_are_skipped() {
+    let times = occurrences(
+        "DTSTART:20230131T090000\n\
+         RRULE:FREQ=MONTHLY",
+        3,
+    );
+    assert_eq!(
+        times,
+        [
+            utc(2023, 1, 31, 9, 0),
+            utc(2023, 3, 31, 9, 0),
+            utc(2023, 5, 31, 9, 0),
+        ]
+    );
+    let times = occurrences(
+        "DTSTART:20230101T000000\n\
+         RRULE:FREQ=YEARLY;BYMONTH=2;BYMONTHDAY=29",
+        2,
+    );
+    assert_eq!(times, [utc(2024, 2, 29, 0, 0), utc(2028, 2, 29, 0, 0)]);
+}
+
+#[test]
+fn minutes_restricted_to_hours() {
+    let times = occurrences(
+        "DTSTART:19970902T090000\n\
+         RRULE:FREQ=MINUTELY;INTERVAL=20;BYHOUR=9,10",
+        7,
+    );
+    assert_eq!(
+        times,
+        [
+            utc(1997, 9, 2, 9, 0),
+            utc(1997, 9, 2, 9, 20),
+            utc(1997, 9, 2, 9, 40),
+            utc(1997, 9, 2, 10, 0),
+            utc(1997, 9, 2, 10, 20),
+            utc(1997, 9, 2, 10, 40),
+            utc(1997, 9, 3, 9, 0),
+        ]
+    );
+}
+
+#[test]
+fn until_is_inclusive() {
+    let times = occurrences(
+        "DTSTART:20230509T090000Z\n\
+         RRULE:FREQ=DAILY;UNTIL=20230512T090000Z",
+        10,
+    );
+    assert_eq!(times.len(), 4);
+    assert_eq!(times[3], utc(2023, 5, 12, 9, 0));
+}
+
+#[test]
+fn dates_are_added_and_removed() {
+    let times = occurrences(
+        "DTSTART:20230508T090000\n\
+         RRULE:FREQ=WEEKLY;COUNT=3\n\
+         EXDATE:20230515T090000\n\
+         RDATE:20230510T120000,20230601T090000",
+        10,
+    );
+    assert_eq!(
+        times,
+        [
+            utc(2023, 5, 8, 9, 0),
+            utc(2023, 5, 10, 12, 0),
+            utc(2023, 5, 22, 9, 0),
+            utc(2023, 6, 1, 9, 0),
+        ]
+    );
+}
+
+#[test]
+fn time_zones() {
+    // Berlin sprang forward on 2023-03-26
+    let times = occurrences(
+        "DTSTART;TZID=Europe/Berlin:20230325T090000\n\
+         RRULE:FREQ=DAILY;COUNT=3",
+        3,
+    );
+    assert_eq!(
+        times,
+        [
+            utc(2023, 3, 25, 8, 0),
+            utc(2023, 3, 26, 7, 0),
+            utc(2023, 3, 27, 7, 0),
+        ]
+    );
+
+    // floating times follow the given time zone
+    let recurrence = Recurrence::parse("DTSTART:20230325T090000\nRRULE:FREQ=DAILY").unwrap();
+    let first = recurrence.occurrences(&Berlin).next().unwrap();
+    assert_eq!(first, utc(2023, 3, 25, 8, 0));
+}
+
+#[test]
+fn start_without_rules_is_the_only_occurrence() {
+    let times = occurrences("DTSTART:20230509T090000", 10);
+    assert_eq!(times, [utc(2023, 5, 9, 9, 0)]);
+}
+
+#[test]
+fn errors() {
+    let error = Recurrence::parse("RRULE:FREQ=DAILY").unwrap_err();
+    assert_eq!(error.kind(), &RecurrenceErrorKind::MissingStart);
+
+    let error =
+        Recurrence::parse("DTSTART:20230509T090000\nRRULE:FREQ=MONTHLY;BYDAY=2XX").unwrap_err();
+    assert_eq!(error.position(), 50);
+    assert!(matches!(
+        error.kind(),
+        RecurrenceErrorKind::InvalidValue {
+            name: "weekday",
+            ..
+        }
+    ));
+
+    let error =
+        Recurrence::parse("DTSTART:20230509T090000\nRRULE:FREQ=MONTHLY;BYMONTHDAY=0").unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        RecurrenceErrorKind::OutOfRange {
+            name: "BYMONTHDAY",
+            ..
+        }
+    ));
+
+    let error =
+        Recurrence::parse("DTSTART:20230509T090000\nRRULE:FREQ=DAILY;COUNT=3;UNTIL=20230601")
+            .unwrap_err();
+    assert!(matches!(error.kind(), RecurrenceErrorKind::Conflict(_)));
+
+    let error =
+        Recurrence::parse("DTSTART:20230509T090000\nRRULE:FREQ=WEEKLY;BYDAY=1MO").unwrap_err();
+    assert!(matches!(error.kind(), RecurrenceErrorKind::Conflict(_)));
+
+    let error = Recurrence::parse("DTSTART:20230509T090000\nRRULE:BYDAY=MO").unwrap_err();
+    assert_eq!(error.kind(), &RecurrenceErrorKind::MissingFrequency);
+}
+
+#[test]
+fn display_keeps_the_source() {
+    let text = "DTSTART:20230509T090000\nRRULE:FREQ=MONTHLY;BYDAY=2TU;COUNT=10";
+    assert_eq!(Recurrence::parse(text).unwrap().to_string(), text);
+}
+
+#[test]
+fn timers_finish_after_the_last_occurrence() {
+    let clock = ManualClock::new(utc(2023, 5, 9, 8, 0));
+    let recurrence = Recurrence::parse(
+        "DTSTART:20230509T090000Z\n\
+         RRULE:FREQ=HOURLY;COUNT=3",
+    )
+    .unwrap();
+    let calls = Arc::new(Mutex::new(0));
+    let handle = Timer::recurrence(recurrence).clock(clock.clone()).spawn({
+        let calls = calls.clone();
+        move |_| *calls.lock().unwrap() += 1
+    });
+    for count in 1..=3 {
+        clock.advance(Duration::from_secs(60 * 60));
+        while *calls.lock().unwrap() < count {
+            std::thread::yield_now();
+        }
+    }
+    handle.join().unwrap();
+    assert_eq!(*calls.lock().unwrap(), 3);
+}
@@ -0,0 +1,113 @@
+use chrono::{DateTime, DurationRound, TimeZone, Utc};
+use chrono_tz::Europe::Berlin;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use zila::{
+    Clock, Cron, EveryDay, EveryHour, EveryMinute, EverySecond, Interval, ManualClock, Once,
+    Schedule, Timer,
+};
+
+fn utc(h: u32, m: u32, s: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2023, 5, 9, h, m, s).unwrap()
+}
+
+/// Fires every quarter of an hour.
+struct Quarterly;
+
+impl Schedule for Quarterly {
+    fn next_after<Tz: TimeZone>(&self, time: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        let quarter = chrono::Duration::minutes(15);
+        Some(time.clone().duration_trunc(quarter).ok()? + quarter)
+    }
+}
+
+#[test]
+fn boundaries() {
+    let time = utc(13, 45, 30);
+    assert_eq!(EverySecond.next_after(&time), Some(utc(13, 45, 31)));
+    assert_eq!(EveryMinute.next_after(&time), Some(utc(13, 46, 0)));
+    assert_eq!(EveryHour.next_after(&time), Some(utc(14, 0, 0)));
+    let midnight = Utc.with_ymd_and_hms(2023, 5, 10, 0, 0, 0).unwrap();
+    assert_eq!(EveryDay.next_after(&time), Some(midnight));
+}
+
+#[test]
+fn boundaries_follow_the_time_zone() {
+    let time = utc(13, 45, 30).with_timezone(&Berlin);
+    let next = EveryDay.next_after(&time).unwrap();
+    assert_eq!(next, Utc.with_ymd_and_hms(2023, 5, 9, 22, 0, 0).unwrap());
+}
+
+#[test]
+fn iter_after_lists_the_upcoming_times() {
+    let times: Vec<_> = EveryHour.iter_after(utc(13, 0, 0)).take(3).collect();
+    assert_eq!(times, [utc(14, 0, 0), utc(15, 0, 0), utc(16, 0, 0)]);
+
+    let interval = Interval::new(Duration::from_secs(90));
+    let times: Vec<_> = interval.iter_after(utc(13, 0, 0)).take(2).collect();
+    assert_eq!(times, [utc(13, 1, 30), utc(13, 3, 0)]);
+}
+
+#[test]
+fn once_fires_once() {
+    let once = Once::at(utc(14, 0, 0));
+    assert_eq!(
+        once.iter_after(utc(13, 0, 0)).collect::<Vec<_>>(),
+        [utc(14, 0, 0)]
+    );
+    assert_eq!(once.next_after(&utc(14, 0, 0)), None);
+}
+
+#[test]
+fn cron_is_a_schedule() {
+    let cron = Cron::parse("0 9 * * *").unwrap();
+    let times: Vec<_> = cron.iter_after(utc(13, 0, 0)).take(1).collect();
+    assert_eq!(times, [Utc.with_ymd_and_hms(2023, 5, 10, 9, 0, 0).unwrap()]);
+}
+
+#[test]
+fn upcoming_starts_now() {
+    let times = EverySecond.upcoming(3);
+    assert_eq!(times.len(), 3);
+    assert!(times
+        .windows(2)
+        .all(|w| w[1] - w[0] == chrono::Duration::seconds(1)));
+    assert!(times[0] >= chrono::Local::now() - chrono::Duration::seconds(1));
+}
+
+#[test]
+fn custom_schedules_run() {
+    let clock = ManualClock::new(utc(13, 50, 0));
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let handle = Timer::schedule_in(Quarterly, Utc)
+        .clock(clock.clone())
+        .spawn({
+            let calls = calls.clone();
+            let clock = clock.clone();
+            move |_| calls.lock().unwrap().push(clock.now())
+        });
+    for count in 1..=3 {
+        clock.advance(Duration::from_secs(15 * 60));
+        while calls.lock().unwrap().len() < count {
+            std::thread::yield_now();
+        }
+    }
+    handle.cancel();
+    let calls = calls.lock().unwrap();
+    assert_eq!(calls[..], [utc(14, 5, 0), utc(14, 20, 0), utc(14, 35, 0)]);
+}
+
+#[test]
+fn schedules_that_end_finish_the_timer() {
+    let clock = ManualClock::new(utc(13, 0, 0));
+    let calls = Arc::new(Mutex::new(0));
+    let handle = Timer::schedule_in(Once::at(utc(14, 0, 0)), Utc)
+        .clock(clock.clone())
+        .spawn({
+            let calls = calls.clone();
+            move |_| *calls.lock().unwrap() += 1
+        });
+    clock.advance(Duration::from_secs(2 * 60 * 60));
+    handle.join().unwrap();
+    assert_eq!(*calls.lock().unwrap(), 1);
+}
@@ -0,0 +1,153 @@
+use chrono::{DateTime, TimeZone, Utc};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use zila::{Clock, ManualClock, PanicPolicy, Scheduler};
+
+fn monday_noon() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2023, 5, 8, 12, 0, 0).unwrap()
+}
+
+/// Receives the next message of a callback, fails instead of hanging.
+fn next<T>(receiver: &Receiver<T>) -> T {
+    receiver.recv_timeout(Duration::from_secs(5)).unwrap()
+}
+
+#[test]
+fn runs_jobs_in_deadline_order() {
+    let clock = ManualClock::new(monday_noon());
+    let scheduler = Scheduler::with_clock(clock.clone());
+    let (sender, receiver) = mpsc::channel();
+
+    for (name, millis) in [("c", 60), ("a", 20), ("b", 40)] {
+        let sender = sender.clone();
+        scheduler.set_timeout(
+            move || sender.send(name).unwrap(),
+            Duration::from_millis(millis),
+        );
+    }
+
+    clock.advance(Duration::from_millis(60));
+    let order: Vec<_> = (0..3).map(|_| next(&receiver)).collect();
+    assert_eq!(order, ["a", "b", "c"]);
+    while !scheduler.is_empty() {
+        std::thread::yield_now();
+    }
+}
+
+#[test]
+fn removed_jobs_stop_running() {
+    let clock = ManualClock::new(monday_noon());
+    let scheduler = Scheduler::with_clock(clock.clone());
+    let (sender, receiver) = mpsc::channel();
+
+    let id = scheduler.set_interval(
+        {
+            let sender = sender.clone();
+            move || sender.send("interval").unwrap()
+        },
+        Duration::from_millis(10),
+    );
+    clock.advance(Duration::from_millis(10));
+    assert_eq!(next(&receiver), "interval");
+
+    // the callback may still be running, it is not called again either way
+    assert!(scheduler.remove(id));
+    assert!(!scheduler.contains(id));
+    assert!(!scheduler.remove(id));
+
+    // due after the next tick the interval would have had
+    scheduler.set_timeout(
+        move || sender.send("timeout").unwrap(),
+        Duration::from_millis(50),
+    );
+    clock.advance(Duration::from_millis(100));
+    assert_eq!(next(&receiver), "timeout");
+}
+
+#[test]
+fn jobs_can_be_added_from_callbacks() {
+    let clock = ManualClock::new(monday_noon());
+    let scheduler = Arc::new(Scheduler::with_clock(clock.clone()));
+    let (sender, receiver) = mpsc::channel();
+
+    scheduler.set_timeout(
+        {
+            let scheduler = Arc::downgrade(&scheduler);
+            move || {
+                if let Some(scheduler) = scheduler.upgrade() {
+                    let inner = sender.clone();
+                    scheduler.set_timeout(
+                        move || inner.send("inner").unwrap(),
+                        Duration::from_millis(10),
+                    );
+                }
+                sender.send("outer").unwrap();
+            }
+        },
+        Duration::from_millis(10),
+    );
+
+    clock.advance(Duration::from_millis(10));
+    assert_eq!(next(&receiver), "outer");
+    clock.advance(Duration::from_millis(10));
+    assert_eq!(next(&receiver), "inner");
+}
+
+#[test]
+fn a_panicking_job_leaves_the_others_alone() {
+    let clock = ManualClock::new(monday_noon());
+    let scheduler = Scheduler::with_clock(clock.clone());
+    scheduler.panic_policy(PanicPolicy::Stop);
+    let panics = Arc::new(Mutex::new(Vec::new()));
+    scheduler.on_panic({
+        let panics = panics.clone();
+        move |id, panic| {
+            panics
+                .lock()
+                .unwrap()
+                .push((id, panic.message().map(str::to_owned)))
+        }
+    });
+    let (sender, receiver) = mpsc::channel();
+
+    let broken = scheduler.set_interval(|| panic!("broken"), Duration::from_millis(5));
+    let healthy =
+        scheduler.set_interval(move || sender.send(()).unwrap(), Duration::from_millis(10));
+
+    // the broken job panics before the healthy one is due
+    clock.advance(Duration::from_millis(10));
+    next(&receiver);
+    assert!(!scheduler.contains(broken));
+    assert!(scheduler.contains(healthy));
+    assert_eq!(
+        *panics.lock().unwrap(),
+        [(broken, Some("broken".to_owned()))]
+    );
+}
+
+#[test]
+fn boundaries_wait_for_the_wall_clock() {
+    let midnight = Utc.with_ymd_and_hms(2023, 5, 9, 0, 0, 0).unwrap();
+    let clock = ManualClock::new(midnight - chrono::Duration::seconds(1));
+    let scheduler = Scheduler::with_clock(clock.clone());
+    let (sender, receiver) = mpsc::channel();
+    scheduler.call_every_day_in(Utc, {
+        let clock = clock.clone();
+        move || sender.send(clock.now()).unwrap()
+    });
+
+    // the monotonic clock reaches the deadline before the wall clock does
+    clock.set(midnight - chrono::Duration::milliseconds(1500));
+    clock.advance(Duration::from_secs(1));
+    assert!(receiver.recv_timeout(Duration::from_millis(50)).is_err());
+
+    clock.advance(Duration::from_millis(500));
+    assert_eq!(receiver.recv_timeout(Duration::from_secs(5)), Ok(midnight));
+    assert!(receiver.recv_timeout(Duration::from_millis(50)).is_err());
+
+    clock.advance(Duration::from_secs(24 * 60 * 60));
+    let next = midnight + chrono::Duration::days(1);
+    assert_eq!(receiver.recv_timeout(Duration::from_secs(5)), Ok(next));
+    assert!(receiver.recv_timeout(Duration::from_millis(50)).is_err());
+}
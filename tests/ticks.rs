@@ -0,0 +1,58 @@
+use chrono::{TimeZone, Utc};
+use futures_util::StreamExt;
+use std::time::Duration;
+use tokio::time::{sleep, Instant};
+use zila::{ticks, ManualClock, Once, Timer};
+
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+#[tokio::test(start_paused = true)]
+async fn intervals_tick_at_fixed_deadlines() {
+    let start = Instant::now();
+    let calls: Vec<_> = ticks::interval(Duration::from_millis(10))
+        .take(3)
+        .map(|tick| (start.elapsed().as_millis() as u64, tick.sequence()))
+        .collect()
+        .await;
+    assert_eq!(calls, [(10, 0), (20, 1), (30, 2)]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn slow_consumers_get_the_missed_ticks() {
+    let start = Instant::now();
+    let mut ticks = ticks::interval(Duration::from_millis(10));
+    let mut calls = Vec::new();
+    while let Some(tick) = ticks.next().await {
+        calls.push((start.elapsed().as_millis() as u64, tick.lateness()));
+        if calls.len() == 4 {
+            break;
+        }
+        if calls.len() == 1 {
+            sleep(Duration::from_millis(25)).await;
+        }
+    }
+    let ms = Duration::from_millis;
+    assert_eq!(calls, [(10, ms(0)), (35, ms(15)), (35, ms(5)), (40, ms(0))]);
+}
+
+#[tokio::test]
+async fn calendar_ticks_follow_the_clock() {
+    let clock = ManualClock::new(Utc.with_ymd_and_hms(2023, 5, 8, 12, 0, 0).unwrap());
+    let mut ticks = Timer::every_day_in(Utc).clock(clock.clone()).stream();
+    clock.advance(DAY);
+    let tick = ticks.next().await.unwrap();
+    assert_eq!(
+        tick.scheduled(),
+        Utc.with_ymd_and_hms(2023, 5, 9, 0, 0, 0).unwrap()
+    );
+    assert_eq!(tick.lateness(), Duration::from_secs(12 * 60 * 60));
+}
+
+#[tokio::test]
+async fn streams_end_with_their_schedule() {
+    let clock = ManualClock::new(Utc.with_ymd_and_hms(2023, 5, 8, 12, 0, 0).unwrap());
+    let once = Once::at(Utc.with_ymd_and_hms(2023, 5, 8, 13, 0, 0).unwrap());
+    let ticks = Timer::schedule_in(once, Utc).clock(clock.clone()).stream();
+    clock.advance(Duration::from_secs(2 * 60 * 60));
+    assert_eq!(ticks.count().await, 1);
+}
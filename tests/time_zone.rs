@@ -0,0 +1,48 @@
+use chrono::{DateTime, Duration, DurationRound, Timelike, Utc};
+use chrono_tz::{
+    America::New_York,
+    Asia::{Kathmandu, Kolkata},
+    Europe::Berlin,
+};
+use zila::{
+    duration_to_next_cron_in, duration_to_next_day_in, duration_to_next_hour_in,
+    duration_to_next_minute_in, Cron,
+};
+
+/// Returns the instant `duration` from now, rounded to the second.
+fn after(duration: std::time::Duration) -> DateTime<Utc> {
+    let time = Utc::now() + Duration::from_std(duration).unwrap();
+    time.duration_round(Duration::seconds(1)).unwrap()
+}
+
+#[test]
+fn day_starts_at_midnight_in_the_time_zone() {
+    let next = after(duration_to_next_day_in(&Berlin)).with_timezone(&Berlin);
+    assert_eq!(next.time().num_seconds_from_midnight(), 0);
+
+    let next = after(duration_to_next_day_in(&New_York)).with_timezone(&New_York);
+    assert_eq!(next.time().num_seconds_from_midnight(), 0);
+}
+
+#[test]
+fn hours_follow_half_hour_offsets() {
+    let next = after(duration_to_next_hour_in(&Kolkata));
+    assert_eq!((next.minute(), next.second()), (30, 0));
+
+    let next = after(duration_to_next_hour_in(&Kathmandu));
+    assert_eq!((next.minute(), next.second()), (15, 0));
+}
+
+#[test]
+fn minutes_are_the_same_everywhere() {
+    let next = after(duration_to_next_minute_in(&Kolkata));
+    assert_eq!(next.second(), 0);
+}
+
+#[test]
+fn cron_matches_the_wall_time_of_the_time_zone() {
+    let cron = Cron::parse("0 9 * * *").unwrap();
+    let next = after(duration_to_next_cron_in(&cron, &New_York).unwrap());
+    let next = next.with_timezone(&New_York);
+    assert_eq!((next.hour(), next.minute(), next.second()), (9, 0, 0));
+}
@@ -0,0 +1,446 @@
+use chrono::Utc;
+use std::cell::Cell;
+use std::ops::ControlFlow;
+use std::rc::Rc;
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::{sleep, Instant};
+use zila::{
+    set_interval_mut, spawn_set_interval, spawn_set_timeout, try_set_interval, ErrorPolicy,
+    ManualClock, MissedTickBehavior, Once, OverlapPolicy, PanicPolicy, Timer,
+};
+
+/// Runs `timer` until its callback was called `count` times and returns the
+/// offset in milliseconds and the skipped ticks of every call. The callbacks
+/// take `delays[n]` milliseconds, or 0 if there are fewer delays.
+async fn ticks(timer: Timer, delays: &[u64], count: usize) -> Vec<(u64, u64)> {
+    let start = Instant::now();
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let handle = timer.spawn_async({
+        let calls = calls.clone();
+        let delays = delays.to_vec();
+        move |tick| {
+            let mut calls = calls.lock().unwrap();
+            let delay = delays.get(calls.len()).copied().unwrap_or(0);
+            calls.push((start.elapsed().as_millis() as u64, tick.skipped()));
+            sleep(Duration::from_millis(delay))
+        }
+    });
+    while calls.lock().unwrap().len() < count {
+        sleep(Duration::from_millis(1)).await;
+    }
+    handle.cancel();
+    let calls = calls.lock().unwrap();
+    calls[..count].to_vec()
+}
+
+fn every_10ms() -> Timer {
+    Timer::interval(Duration::from_millis(10))
+}
+
+#[tokio::test(start_paused = true)]
+async fn fixed_delay_adds_the_callback_time() {
+    let calls = ticks(every_10ms(), &[4, 4, 4], 4).await;
+    assert_eq!(calls, [(10, 0), (24, 0), (38, 0), (52, 0)]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn fixed_rate_does_not_drift() {
+    let calls = ticks(every_10ms().fixed_rate(), &[4, 4, 4], 4).await;
+    assert_eq!(calls, [(10, 0), (20, 0), (30, 0), (40, 0)]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn burst_calls_every_missed_tick() {
+    let calls = ticks(every_10ms().fixed_rate(), &[25], 5).await;
+    assert_eq!(calls, [(10, 0), (35, 0), (35, 0), (40, 0), (50, 0)]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn delay_restarts_the_period() {
+    let timer = every_10ms()
+        .fixed_rate()
+        .missed_tick_behavior(MissedTickBehavior::Delay);
+    let calls = ticks(timer, &[25], 4).await;
+    assert_eq!(calls, [(10, 0), (35, 1), (45, 0), (55, 0)]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn skip_keeps_the_schedule() {
+    let timer = every_10ms()
+        .fixed_rate()
+        .missed_tick_behavior(MissedTickBehavior::Skip);
+    let calls = ticks(timer, &[25], 4).await;
+    assert_eq!(calls, [(10, 0), (35, 1), (40, 0), (50, 0)]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn fixed_delay_never_misses_a_tick() {
+    let timer = every_10ms().missed_tick_behavior(MissedTickBehavior::Skip);
+    let calls = ticks(timer, &[25], 3).await;
+    assert_eq!(calls, [(10, 0), (45, 0), (55, 0)]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn concurrent_callbacks_keep_the_schedule() {
+    let timer = every_10ms()
+        .fixed_rate()
+        .overlap_policy(OverlapPolicy::Concurrent(4));
+    let calls = ticks(timer, &[35, 35, 35, 35], 5).await;
+    assert_eq!(calls, [(10, 0), (20, 0), (30, 0), (40, 0), (50, 0)]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn concurrent_callbacks_are_limited() {
+    let timer = every_10ms()
+        .fixed_rate()
+        .overlap_policy(OverlapPolicy::Concurrent(2));
+    let calls = ticks(timer, &[25, 25], 4).await;
+    assert_eq!(calls, [(10, 0), (20, 0), (40, 1), (50, 0)]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn skip_if_running_drops_ticks() {
+    let timer = every_10ms()
+        .fixed_rate()
+        .overlap_policy(OverlapPolicy::SkipIfRunning);
+    let calls = ticks(timer, &[25], 3).await;
+    assert_eq!(calls, [(10, 0), (40, 2), (50, 0)]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn queue_runs_ticks_back_to_back() {
+    let timer = every_10ms()
+        .fixed_rate()
+        .overlap_policy(OverlapPolicy::Queue(1));
+    let calls = ticks(timer, &[25], 4).await;
+    assert_eq!(calls, [(10, 0), (35, 0), (40, 1), (50, 0)]);
+}
+
+#[test]
+fn fixed_rate_on_a_thread() {
+    let calls = Arc::new(Mutex::new(0));
+    let handle = Timer::interval(Duration::from_millis(5))
+        .fixed_rate()
+        .spawn({
+            let calls = calls.clone();
+            move |_| *calls.lock().unwrap() += 1
+        });
+    std::thread::sleep(Duration::from_millis(50));
+    handle.cancel();
+    assert!(handle.join().unwrap_err().is_cancelled());
+    assert!(*calls.lock().unwrap() > 0);
+}
+
+#[test]
+fn background_timers_run_on_a_named_thread() {
+    let (sender, receiver) = mpsc::channel();
+    let handle = spawn_set_interval(
+        move || {
+            let thread = std::thread::current();
+            let _ = sender.send(thread.name().map(str::to_owned));
+        },
+        Duration::from_millis(1),
+    );
+    assert_eq!(handle.thread().name(), Some("zila-set-interval"));
+    let name = receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+    assert_eq!(name.as_deref(), Some("zila-set-interval"));
+    handle.cancel();
+    assert!(handle.is_cancelled());
+    assert!(handle.join().unwrap_err().is_cancelled());
+}
+
+#[test]
+fn background_timeouts_fire_once() {
+    let (sender, receiver) = mpsc::channel();
+    let handle = spawn_set_timeout(
+        move || {
+            let thread = std::thread::current();
+            sender.send(thread.name().map(str::to_owned)).unwrap();
+        },
+        Duration::from_millis(1),
+    );
+    let name = receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+    assert_eq!(name.as_deref(), Some("zila-set-timeout"));
+    assert!(handle.join().is_ok());
+    assert!(receiver.try_recv().is_err());
+}
+
+#[test]
+fn joining_a_background_timer_returns_its_value() {
+    let mut attempts = 0;
+    let handle = spawn_set_interval(
+        move || {
+            attempts += 1;
+            if attempts == 3 {
+                ControlFlow::Break(attempts)
+            } else {
+                ControlFlow::Continue(())
+            }
+        },
+        Duration::from_millis(1),
+    );
+    assert_eq!(handle.join().unwrap(), Some(3));
+}
+
+#[test]
+fn break_hands_the_value_back() {
+    let mut attempts = 0;
+    let value = set_interval_mut(
+        || {
+            attempts += 1;
+            if attempts == 3 {
+                ControlFlow::Break(attempts)
+            } else {
+                ControlFlow::Continue(())
+            }
+        },
+        Duration::from_millis(1),
+    );
+    assert_eq!(value, Some(3));
+    assert_eq!(attempts, 3);
+}
+
+#[test]
+fn cancellers_stop_a_blocking_timer() {
+    let timer = Timer::interval(Duration::from_millis(1));
+    let canceller = timer.canceller();
+    let cancelling = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(20));
+        canceller.cancel();
+    });
+    let mut calls = 0;
+    timer.run(|_| calls += 1);
+    cancelling.join().unwrap();
+    assert!(calls > 0);
+}
+
+#[test]
+fn timeouts_fire_once_on_their_clock() {
+    let clock = ManualClock::new(Utc::now());
+    let (sender, receiver) = mpsc::channel();
+    let handle = Timer::timeout(Duration::from_secs(60))
+        .clock(clock.clone())
+        .spawn(move |tick| sender.send(tick.sequence()).unwrap());
+    clock.advance(Duration::from_secs(59));
+    assert!(receiver.recv_timeout(Duration::from_millis(20)).is_err());
+    clock.advance(Duration::from_secs(1));
+    assert_eq!(receiver.recv_timeout(Duration::from_secs(5)), Ok(0));
+    assert!(handle.join().is_ok());
+    assert!(receiver.try_recv().is_err());
+}
+
+#[test]
+fn cancelled_timeouts_never_fire() {
+    let (sender, receiver) = mpsc::channel();
+    let handle = Timer::timeout(Duration::from_secs(60)).spawn(move |_| sender.send(()).unwrap());
+    handle.cancel();
+    assert!(handle.join().unwrap_err().is_cancelled());
+    assert!(receiver.try_recv().is_err());
+}
+
+#[test]
+fn cancellers_stop_a_blocking_timeout() {
+    let timer = Timer::timeout(Duration::from_secs(60));
+    let canceller = timer.canceller();
+    let cancelling = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(20));
+        canceller.cancel();
+    });
+    let mut calls = 0;
+    timer.run(|_| calls += 1);
+    cancelling.join().unwrap();
+    assert_eq!(calls, 0);
+}
+
+#[tokio::test(start_paused = true)]
+async fn async_timers_run_on_the_calling_task() {
+    let calls = Rc::new(Cell::new(0));
+    let value = every_10ms()
+        .run_async(|tick| {
+            let calls = calls.clone();
+            async move {
+                calls.set(calls.get() + 1);
+                match tick.sequence() {
+                    2 => ControlFlow::Break(tick.sequence()),
+                    _ => ControlFlow::Continue(()),
+                }
+            }
+        })
+        .await;
+    assert_eq!(value, Some(2));
+    assert_eq!(calls.get(), 3);
+}
+
+#[tokio::test(start_paused = true)]
+async fn break_ends_an_async_timer() {
+    let start = Instant::now();
+    let handle = every_10ms().spawn_async(move |tick| async move {
+        if tick.sequence() == 4 {
+            ControlFlow::Break(start.elapsed().as_millis())
+        } else {
+            ControlFlow::Continue(())
+        }
+    });
+    assert_eq!(handle.await.unwrap(), Some(50));
+}
+
+#[test]
+fn schedules_that_end_return_none() {
+    let once = Once::at(Utc::now() + chrono::Duration::milliseconds(5));
+    let handle = Timer::schedule(once).spawn(|_| ControlFlow::<()>::Continue(()));
+    assert_eq!(handle.join().unwrap(), None);
+}
+
+#[test]
+fn stop_returns_the_first_error() {
+    let mut calls = 0;
+    let result = try_set_interval(
+        move || {
+            calls += 1;
+            match calls {
+                3 => Err(calls),
+                _ => Ok(()),
+            }
+        },
+        Duration::from_millis(1),
+        ErrorPolicy::Stop,
+    );
+    assert_eq!(result, Err(3));
+}
+
+#[test]
+fn log_keeps_the_timer_running() {
+    let calls = Arc::new(Mutex::new(0));
+    let handle = Timer::interval(Duration::from_millis(1)).try_spawn(
+        {
+            let calls = calls.clone();
+            move |_| {
+                *calls.lock().unwrap() += 1;
+                Err("failed")
+            }
+        },
+        ErrorPolicy::Log,
+    );
+    while *calls.lock().unwrap() < 3 {
+        std::thread::sleep(Duration::from_millis(1));
+    }
+    handle.cancel();
+    assert!(handle.join().unwrap_err().is_cancelled());
+}
+
+#[tokio::test(start_paused = true)]
+async fn on_error_passes_every_error_to_the_hook() {
+    let errors = Arc::new(Mutex::new(Vec::new()));
+    let policy = ErrorPolicy::on_error({
+        let errors = errors.clone();
+        move |error: &u64| errors.lock().unwrap().push(*error)
+    });
+    let handle = every_10ms().try_spawn_async(
+        |tick| async move {
+            match tick.sequence() % 2 {
+                0 => Ok(()),
+                _ => Err(tick.sequence()),
+            }
+        },
+        policy,
+    );
+    while errors.lock().unwrap().len() < 3 {
+        sleep(Duration::from_millis(1)).await;
+    }
+    handle.cancel();
+    assert_eq!(*errors.lock().unwrap(), [1, 3, 5]);
+}
+
+#[test]
+fn restart_keeps_calling_a_panicking_callback() {
+    let panics = Arc::new(Mutex::new(Vec::new()));
+    let handle = Timer::interval(Duration::from_millis(1))
+        .panic_policy(PanicPolicy::Restart)
+        .on_panic({
+            let panics = panics.clone();
+            move |panic| {
+                panics
+                    .lock()
+                    .unwrap()
+                    .push(panic.message().unwrap().to_owned())
+            }
+        })
+        .spawn(|tick| match tick.sequence() {
+            0 | 2 => panic!("tick {}", tick.sequence()),
+            3 => ControlFlow::Break(()),
+            _ => ControlFlow::Continue(()),
+        });
+    assert_eq!(handle.join().unwrap(), Some(()));
+    assert_eq!(*panics.lock().unwrap(), ["tick 0", "tick 2"]);
+}
+
+#[test]
+fn propagate_hands_the_panic_to_join() {
+    let handle = Timer::interval(Duration::from_millis(1)).spawn(|_| -> () { panic!("oops") });
+    assert!(handle.join().unwrap_err().is_panic());
+}
+
+#[tokio::test(start_paused = true)]
+async fn stop_ends_an_async_timer() {
+    let stopped = Arc::new(Mutex::new(None));
+    let handle = every_10ms()
+        .panic_policy(PanicPolicy::Stop)
+        .on_panic({
+            let stopped = stopped.clone();
+            move |_| *stopped.lock().unwrap() = Some(Instant::now())
+        })
+        .spawn_async(|tick| async move {
+            sleep(Duration::from_millis(5)).await;
+            if tick.sequence() == 1 {
+                panic!("oops");
+            }
+        });
+    let start = Instant::now();
+    handle.await.unwrap();
+    let stopped = stopped.lock().unwrap().unwrap();
+    assert_eq!(stopped - start, Duration::from_millis(30));
+}
+
+#[tokio::test(start_paused = true)]
+async fn hung_callbacks_are_dropped() {
+    let timeouts = Arc::new(Mutex::new(Vec::new()));
+    let timer = every_10ms()
+        .fixed_rate()
+        .execution_timeout(Duration::from_millis(5))
+        .on_timeout({
+            let timeouts = timeouts.clone();
+            move |timed_out| {
+                let call = (timed_out.tick().sequence(), timed_out.limit());
+                timeouts.lock().unwrap().push(call);
+            }
+        });
+    let calls = ticks(timer, &[3, 1000, 3], 4).await;
+    assert_eq!(calls, [(10, 0), (20, 0), (30, 0), (40, 0)]);
+    assert_eq!(*timeouts.lock().unwrap(), [(1, Duration::from_millis(5))]);
+}
+
+#[test]
+fn overrunning_callbacks_are_cancelled() {
+    let clock = ManualClock::new(Utc::now());
+    let (started, calls) = mpsc::channel();
+    let (report, reports) = mpsc::channel();
+    let handle = Timer::interval(Duration::from_secs(10))
+        .fixed_rate()
+        .clock(clock.clone())
+        .execution_timeout(Duration::from_secs(5))
+        .on_timeout(move |timed_out| report.send(timed_out.tick().sequence()).unwrap())
+        .spawn(move |tick| {
+            started.send(()).unwrap();
+            while !tick.is_cancelled() {
+                std::thread::yield_now();
+            }
+            ControlFlow::Break(tick.sequence())
+        });
+    clock.advance(Duration::from_secs(10));
+    calls.recv().unwrap();
+    clock.advance(Duration::from_secs(5));
+    assert_eq!(reports.recv().unwrap(), 0);
+    assert_eq!(handle.join().unwrap(), Some(0));
+}